@@ -0,0 +1,422 @@
+use std::collections::HashMap;
+
+use crate::error::{Diagnostic, ParseError, ParseErrorKind, RawError};
+use crate::{
+    AreaInterchange, Branch, Bus, BusNum, FACTSDevice, FixedShunt, Generator, Load,
+    MultiSectionLineGroup, MultiTerminalDCLine, Network, Records, Substation, SwitchedShunt,
+    SystemSwitchingDevice, Transformer, TwoTerminalDCLine, VSCDCLine,
+};
+
+// A bus reference given by extended bus name is read as a placeholder number,
+// below any bus number, that locates the name by its distance from the end of
+// the input. Placeholders are resolved against the bus data once it has been read.
+const NAME_REF: BusNum = -1_000_000;
+
+// The placeholder for a bus name at `input`, or `None` if the input is too long.
+pub(crate) fn name_ref(input: &str) -> Option<BusNum> {
+    BusNum::try_from(input.len())
+        .ok()
+        .and_then(|len| NAME_REF.checked_sub(len))
+}
+
+// Whether `bus` is in the range of the placeholders, which bus numbers read
+// from a case must be above.
+pub(crate) fn is_name_ref(bus: BusNum) -> bool {
+    bus <= NAME_REF
+}
+
+// The input at the bus name of a placeholder, or `None` for a bus number.
+pub(crate) fn name_at(input: &str, bus: BusNum) -> Option<&str> {
+    if !is_name_ref(bus) {
+        return None;
+    }
+    let len = (NAME_REF - bus) as usize;
+    input
+        .len()
+        .checked_sub(len)
+        .and_then(|start| input.get(start..))
+}
+
+// The text of the quoted name at `input`.
+pub(crate) fn quoted(input: &str) -> &str {
+    let name = input.strip_prefix(['\'', '"']).unwrap_or(input);
+    name.split(['\'', '"']).next().unwrap_or_default()
+}
+
+/// Buses by name, to resolve extended bus names.
+///
+/// An extended bus name is the bus name followed by its base voltage in kV,
+/// e.g. `'STBC        161.00'`. The base voltage may be left out.
+#[derive(Default)]
+pub(crate) struct BusNames {
+    buses: HashMap<String, Vec<(f64, BusNum)>>,
+}
+
+impl BusNames {
+    pub(crate) fn new(buses: &[Bus]) -> Self {
+        let mut names = Self::default();
+        buses.iter().for_each(|bus| names.add_bus(bus));
+        names
+    }
+
+    pub(crate) fn add_bus(&mut self, bus: &Bus) {
+        self.buses
+            .entry(bus.name.trim().to_string())
+            .or_default()
+            .push((bus.basekv, bus.i));
+    }
+
+    /// The number of the bus with extended name `name`.
+    pub(crate) fn find(&self, name: &str) -> Result<BusNum, ParseErrorKind> {
+        let name = name.trim();
+
+        // The base voltage is the last word of the name, if it is a number.
+        let with_basekv = name.rsplit_once(' ').and_then(|(name, basekv)| {
+            let basekv = basekv.parse::<f64>().ok()?;
+            let buses = self.buses.get(name.trim_end())?;
+            let matching: Vec<_> = buses
+                .iter()
+                .filter(|(kv, _)| (kv - basekv).abs() <= 0.005)
+                .collect();
+            (!matching.is_empty()).then_some(matching)
+        });
+        let matching = match with_basekv {
+            Some(matching) => matching,
+            None => self
+                .buses
+                .get(name)
+                .map(|buses| buses.iter().collect())
+                .unwrap_or_default(),
+        };
+
+        match matching.as_slice() {
+            [(_, i)] => Ok(*i),
+            [] => Err(ParseErrorKind::UnknownBus),
+            _ => Err(ParseErrorKind::AmbiguousBus),
+        }
+    }
+
+    // Replaces the bus name placeholders of `record`, read from `input`.
+    pub(crate) fn resolve<T: BusRefs>(
+        &self,
+        input: &str,
+        section: &'static str,
+        record: &mut T,
+    ) -> Result<(), ParseError> {
+        for (field, bus) in record.bus_refs() {
+            let Some(at) = name_at(input, *bus) else {
+                continue;
+            };
+            // A minus sign before the name designates the metered end of a branch.
+            let name = quoted(at);
+            let found = match name.strip_prefix('-') {
+                Some(name) => self.find(name).map(|i| -i),
+                None => self.find(name),
+            };
+            match found {
+                Ok(i) => *bus = i,
+                Err(kind) => {
+                    let mut err =
+                        ParseError::new(input, RawError::new(at, nom::error::ErrorKind::Verify));
+                    err.kind = kind;
+                    err.section = Some(section);
+                    err.field = Some(field);
+                    err.text = name.to_string();
+                    return Err(err);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Resolves the placeholders of each record. With `diagnostics`, in lenient
+    // mode, a record with a name that cannot be resolved is dropped instead.
+    fn resolve_all<T: BusRefs>(
+        &self,
+        input: &str,
+        section: &'static str,
+        records: &mut Vec<T>,
+        diagnostics: &mut Option<&mut Vec<Diagnostic>>,
+    ) -> Result<(), ParseError> {
+        let Some(diagnostics) = diagnostics else {
+            return records
+                .iter_mut()
+                .try_for_each(|record| self.resolve(input, section, record));
+        };
+        records.retain_mut(|record| match self.resolve(input, section, record) {
+            Ok(()) => true,
+            Err(err) => {
+                diagnostics.push(Diagnostic::skipped(&err));
+                false
+            }
+        });
+        Ok(())
+    }
+}
+
+// Replaces the bus name placeholders in a network read from `input`, failing
+// on a name that cannot be resolved unless `diagnostics` are collected.
+pub(crate) fn resolve_bus_names(
+    network: &mut Network,
+    input: &str,
+    mut diagnostics: Option<&mut Vec<Diagnostic>>,
+) -> Result<(), ParseError> {
+    let names = BusNames::new(&network.buses);
+    names.resolve_all(input, "LOAD DATA", &mut network.loads, &mut diagnostics)?;
+    names.resolve_all(
+        input,
+        "FIXED SHUNT DATA",
+        &mut network.fixed_shunts,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "GENERATOR DATA",
+        &mut network.generators,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "BRANCH DATA",
+        &mut network.branches,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "SYSTEM SWITCHING DEVICE DATA",
+        &mut network.system_switching_devices,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "TRANSFORMER DATA",
+        &mut network.transformers,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "AREA DATA",
+        &mut network.area_interchanges,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "TWO-TERMINAL DC DATA",
+        &mut network.two_terminal_dc,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "VSC DC LINE DATA",
+        &mut network.vsc_dc,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "MULTI-TERMINAL DC DATA",
+        &mut network.multi_terminal_dc,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "MULTI-SECTION LINE DATA",
+        &mut network.multi_section_lines,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "FACTS DEVICE DATA",
+        &mut network.facts,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "SWITCHED SHUNT DATA",
+        &mut network.switched_shunts,
+        &mut diagnostics,
+    )?;
+    names.resolve_all(
+        input,
+        "SUBSTATION DATA",
+        &mut network.substations,
+        &mut diagnostics,
+    )?;
+    Ok(())
+}
+
+/// Records with fields that refer to AC buses, which may be given by extended bus name.
+pub(crate) trait BusRefs {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)>;
+}
+
+impl BusRefs for Load {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![("i", &mut self.i)]
+    }
+}
+
+impl BusRefs for FixedShunt {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![("i", &mut self.i)]
+    }
+}
+
+impl BusRefs for Generator {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![("i", &mut self.i), ("ireg", &mut self.ireg)]
+    }
+}
+
+impl BusRefs for Branch {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![("i", &mut self.i), ("j", &mut self.j)]
+    }
+}
+
+impl BusRefs for SystemSwitchingDevice {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![("i", &mut self.i), ("j", &mut self.j)]
+    }
+}
+
+impl BusRefs for Transformer {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        let mut refs = vec![
+            ("i", &mut self.i),
+            ("j", &mut self.j),
+            ("k", &mut self.k),
+            ("cont1", &mut self.cont1),
+        ];
+        refs.extend(self.cont2.as_mut().map(|bus| ("cont2", bus)));
+        refs.extend(self.cont3.as_mut().map(|bus| ("cont3", bus)));
+        refs
+    }
+}
+
+impl BusRefs for AreaInterchange {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![("isw", &mut self.isw)]
+    }
+}
+
+impl BusRefs for TwoTerminalDCLine {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![
+            ("ipr", &mut self.ipr),
+            ("icr", &mut self.icr),
+            ("ifr", &mut self.ifr),
+            ("itr", &mut self.itr),
+            ("ipi", &mut self.ipi),
+            ("ici", &mut self.ici),
+            ("ifi", &mut self.ifi),
+            ("iti", &mut self.iti),
+        ]
+    }
+}
+
+impl BusRefs for VSCDCLine {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![
+            ("ibus1", &mut self.ibus1),
+            ("remot1", &mut self.remot1),
+            ("ibus2", &mut self.ibus2),
+            ("remot2", &mut self.remot2),
+        ]
+    }
+}
+
+impl BusRefs for MultiTerminalDCLine {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        let mut refs = vec![
+            ("vconv", &mut self.line_id.vconv),
+            ("vconvn", &mut self.line_id.vconvn),
+        ];
+        refs.extend(self.converters.iter_mut().map(|conv| ("ib", &mut conv.ib)));
+        refs.extend(self.buses.iter_mut().map(|bus| ("ib", &mut bus.ib)));
+        refs
+    }
+}
+
+impl BusRefs for MultiSectionLineGroup {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        let mut refs = vec![
+            ("i", &mut self.i),
+            ("j", &mut self.j),
+            ("dum1", &mut self.dum1),
+        ];
+        let dums = [
+            ("dum2", &mut self.dum2),
+            ("dum3", &mut self.dum3),
+            ("dum4", &mut self.dum4),
+            ("dum5", &mut self.dum5),
+            ("dum6", &mut self.dum6),
+            ("dum7", &mut self.dum7),
+            ("dum8", &mut self.dum8),
+            ("dum9", &mut self.dum9),
+        ];
+        refs.extend(
+            dums.into_iter()
+                .filter_map(|(field, dum)| dum.as_mut().map(|bus| (field, bus))),
+        );
+        refs
+    }
+}
+
+impl BusRefs for FACTSDevice {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![
+            ("i", &mut self.i),
+            ("j", &mut self.j),
+            ("remot", &mut self.remot),
+        ]
+    }
+}
+
+impl BusRefs for SwitchedShunt {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        vec![("i", &mut self.i), ("swrem", &mut self.swrem)]
+    }
+}
+
+impl BusRefs for Substation {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        let mut refs: Vec<_> = self
+            .nodes
+            .iter_mut()
+            .map(|node| ("i", &mut node.i))
+            .collect();
+        for terminal in &mut self.terminals {
+            refs.push(("i", &mut terminal.i));
+            refs.push(("j", &mut terminal.j));
+            refs.push(("k", &mut terminal.k));
+        }
+        refs
+    }
+}
+
+impl BusRefs for Records {
+    fn bus_refs(&mut self) -> Vec<(&'static str, &mut BusNum)> {
+        match self {
+            Records::Load(r) => r.bus_refs(),
+            Records::FixedShunt(r) => r.bus_refs(),
+            Records::Generator(r) => r.bus_refs(),
+            Records::Branch(r) => r.bus_refs(),
+            Records::SystemSwitchingDevice(r) => r.bus_refs(),
+            Records::Transformer(r) => r.bus_refs(),
+            Records::AreaInterchange(r) => r.bus_refs(),
+            Records::TwoTerminalDCLine(r) => r.bus_refs(),
+            Records::VSCDCLine(r) => r.bus_refs(),
+            Records::MultiTerminalDCLine(r) => r.bus_refs(),
+            Records::MultiSectionLineGroup(r) => r.bus_refs(),
+            Records::FACTSDevice(r) => r.bus_refs(),
+            Records::SwitchedShunt(r) => r.bus_refs(),
+            Records::Substation(r) => r.bus_refs(),
+            Records::Bus(_)
+            | Records::ImpedanceCorrection(_)
+            | Records::Zone(_)
+            | Records::InterAreaTransfer(_)
+            | Records::Owner(_)
+            | Records::SystemWide(_) => Vec::new(),
+        }
+    }
+}
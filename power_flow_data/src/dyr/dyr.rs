@@ -1,18 +1,685 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use arrayvec::ArrayString;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_while, take_while1};
+use nom::character::complete::{char, line_ending, multispace0, multispace1, not_line_ending};
+use nom::combinator::{cut, eof, opt, recognize};
+use nom::error::context;
+use nom::multi::many0;
+use nom::sequence::{delimited, pair, preceded, terminated};
+
 use crate::dyr::exc::*;
 use crate::dyr::gov::*;
+use crate::dyr::load::*;
 use crate::dyr::pss::*;
+use crate::dyr::ren::*;
 use crate::dyr::sym::*;
+use crate::error::{IResult, ParseError, RawError};
+use crate::writing::{non_finite_error, Field, ID_WIDTH};
+use crate::{BusNum, Network};
 
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Record {
     GENCLS(GENCLS),
     GENROU(GENROU),
+    GENSAL(GENSAL),
+    GENTPJ(GENTPJ),
 
     SEXS(SEXS),
+    IEEET1(IEEET1),
+    ESST1A(ESST1A),
+    ESAC1A(ESAC1A),
+    ESDC1A(ESDC1A),
+    EXST1(EXST1),
+    SCRX(SCRX),
 
     TGOV1(TGOV1),
     GAST(GAST),
     HYGOV(HYGOV),
+    IEEEG1(IEEEG1),
+    GGOV1(GGOV1),
+    HYGOV4(HYGOV4),
+    WSIEG1(WSIEG1),
+    DEGOV1(DEGOV1),
 
     IEEEST(IEEEST),
     ST2CUT(ST2CUT),
+    PSS2A(PSS2A),
+    PSS2B(PSS2B),
+    STAB1(STAB1),
+
+    REGC_A(REGC_A),
+    REEC_A(REEC_A),
+    REEC_B(REEC_B),
+    REPC_A(REPC_A),
+    WTGT_A(WTGT_A),
+    WTGAR_A(WTGAR_A),
+
+    CMLD(Box<CMLD>),
+    CLOD(CLOD),
+
+    /// A model that is not (yet) read into its own type.
+    Generic(GenericModel),
+}
+
+/// The record of a model without its own type, with its constants as written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenericModel {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Model name, e.g. `"REGCA1"`.
+    pub model: String,
+
+    /// Machine or device identifier.
+    pub id: String,
+
+    /// Integer, real and string constants, in order. Strings keep their quotes.
+    pub cons: Vec<String>,
+}
+
+impl Record {
+    /// The bus of the machine or device the model belongs to.
+    pub fn bus(&self) -> BusNum {
+        match self {
+            Record::GENCLS(GENCLS { i, .. })
+            | Record::GENROU(GENROU { i, .. })
+            | Record::GENSAL(GENSAL { i, .. })
+            | Record::GENTPJ(GENTPJ { i, .. })
+            | Record::SEXS(SEXS { i, .. })
+            | Record::IEEET1(IEEET1 { i, .. })
+            | Record::ESST1A(ESST1A { i, .. })
+            | Record::ESAC1A(ESAC1A { i, .. })
+            | Record::ESDC1A(ESDC1A { i, .. })
+            | Record::EXST1(EXST1 { i, .. })
+            | Record::SCRX(SCRX { i, .. })
+            | Record::TGOV1(TGOV1 { i, .. })
+            | Record::GAST(GAST { i, .. })
+            | Record::HYGOV(HYGOV { i, .. })
+            | Record::IEEEG1(IEEEG1 { i, .. })
+            | Record::GGOV1(GGOV1 { i, .. })
+            | Record::HYGOV4(HYGOV4 { i, .. })
+            | Record::WSIEG1(WSIEG1 { i, .. })
+            | Record::DEGOV1(DEGOV1 { i, .. })
+            | Record::IEEEST(IEEEST { i, .. })
+            | Record::ST2CUT(ST2CUT { i, .. })
+            | Record::PSS2A(PSS2A { i, .. })
+            | Record::PSS2B(PSS2B { i, .. })
+            | Record::STAB1(STAB1 { i, .. })
+            | Record::REGC_A(REGC_A { i, .. })
+            | Record::REEC_A(REEC_A { i, .. })
+            | Record::REEC_B(REEC_B { i, .. })
+            | Record::REPC_A(REPC_A { i, .. })
+            | Record::WTGT_A(WTGT_A { i, .. })
+            | Record::WTGAR_A(WTGAR_A { i, .. })
+            | Record::CLOD(CLOD { i, .. })
+            | Record::Generic(GenericModel { i, .. }) => *i,
+            Record::CMLD(cmld) => cmld.i,
+        }
+    }
+
+    /// The identifier of the machine or device the model belongs to.
+    pub fn id(&self) -> &str {
+        match self {
+            Record::GENCLS(GENCLS { id, .. })
+            | Record::GENROU(GENROU { id, .. })
+            | Record::GENSAL(GENSAL { id, .. })
+            | Record::GENTPJ(GENTPJ { id, .. })
+            | Record::SEXS(SEXS { id, .. })
+            | Record::IEEET1(IEEET1 { id, .. })
+            | Record::ESST1A(ESST1A { id, .. })
+            | Record::ESAC1A(ESAC1A { id, .. })
+            | Record::ESDC1A(ESDC1A { id, .. })
+            | Record::EXST1(EXST1 { id, .. })
+            | Record::SCRX(SCRX { id, .. })
+            | Record::TGOV1(TGOV1 { id, .. })
+            | Record::GAST(GAST { id, .. })
+            | Record::HYGOV(HYGOV { id, .. })
+            | Record::IEEEG1(IEEEG1 { id, .. })
+            | Record::GGOV1(GGOV1 { id, .. })
+            | Record::HYGOV4(HYGOV4 { id, .. })
+            | Record::WSIEG1(WSIEG1 { id, .. })
+            | Record::DEGOV1(DEGOV1 { id, .. })
+            | Record::IEEEST(IEEEST { id, .. })
+            | Record::ST2CUT(ST2CUT { id, .. })
+            | Record::PSS2A(PSS2A { id, .. })
+            | Record::PSS2B(PSS2B { id, .. })
+            | Record::STAB1(STAB1 { id, .. })
+            | Record::REGC_A(REGC_A { id, .. })
+            | Record::REEC_A(REEC_A { id, .. })
+            | Record::REEC_B(REEC_B { id, .. })
+            | Record::REPC_A(REPC_A { id, .. })
+            | Record::WTGT_A(WTGT_A { id, .. })
+            | Record::WTGAR_A(WTGAR_A { id, .. })
+            | Record::CLOD(CLOD { id, .. }) => id,
+            Record::CMLD(cmld) => &cmld.id,
+            Record::Generic(GenericModel { id, .. }) => id,
+        }
+    }
+
+    /// Whether this is a generator model: a machine, or a control of one.
+    ///
+    /// Generic records are not, since their model may belong to a load or any
+    /// other device, except for the machine models in [MACHINE_MODELS].
+    pub fn is_generator_model(&self) -> bool {
+        match self {
+            Record::CMLD(_) | Record::CLOD(_) => false,
+            Record::Generic(generic) => MACHINE_MODELS.contains(&generic.model.as_str()),
+            _ => true,
+        }
+    }
+
+    /// Whether this is a machine model, which every generator needs one of.
+    pub fn is_machine_model(&self) -> bool {
+        match self {
+            Record::GENCLS(_)
+            | Record::GENROU(_)
+            | Record::GENSAL(_)
+            | Record::GENTPJ(_)
+            | Record::REGC_A(_) => true,
+            Record::Generic(generic) => MACHINE_MODELS.contains(&generic.model.as_str()),
+            _ => false,
+        }
+    }
+}
+
+/// Machine models, including renewable generator/converter models, that are
+/// read as [GenericModel] records.
+pub const MACHINE_MODELS: &[&str] = &[
+    "GENSAE", "GENTPF", "GENTRA", "GENDCO", "CGEN1", "CIMTR1", "CIMTR2", "CIMTR3", "CIMTR4",
+    "REGCAU1", "REGCB1", "REGCBU1", "REGCC1", "WT1G1", "WT2G1", "WT3G1", "WT3G2", "WT4G1", "WT4G2",
+    "PVGU1",
+];
+
+/// The models of a DYR file joined to the generators of a case by bus number
+/// and machine ID.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GeneratorModels {
+    /// Pairs of indices into the records and into `Network::generators`, in
+    /// record order.
+    pub linked: Vec<(usize, usize)>,
+
+    /// Indices of generators without a machine model.
+    pub without_machine: Vec<usize>,
+
+    /// Indices of generator models whose generator is not in the case.
+    pub without_generator: Vec<usize>,
+}
+
+/// Joins the `records` of a DYR file to the generators of `network` by bus
+/// number and machine ID, compared without padding.
+///
+/// Generic records of models that may not belong to a generator are linked
+/// when a generator matches, and otherwise ignored.
+pub fn link_generators(records: &[Record], network: &Network) -> GeneratorModels {
+    let mut generators = HashMap::new();
+    for (k, generator) in network.generators.iter().enumerate() {
+        generators
+            .entry((generator.i, generator.id.trim()))
+            .or_insert(k);
+    }
+
+    let mut links = GeneratorModels::default();
+    let mut has_machine = vec![false; network.generators.len()];
+    for (r, record) in records.iter().enumerate() {
+        match generators.get(&(record.bus(), record.id().trim())) {
+            Some(&k) => {
+                links.linked.push((r, k));
+                has_machine[k] |= record.is_machine_model();
+            }
+            None if record.is_generator_model() => links.without_generator.push(r),
+            None => {}
+        }
+    }
+    links.without_machine = (0..has_machine.len())
+        .filter(|&k| !has_machine[k])
+        .collect();
+    links
+}
+
+/// A model read from, and written to, the constants of its DYR record.
+trait DyrModel: Sized {
+    /// The model name PSS/E writes.
+    const NAME: &'static str;
+
+    fn from_cons<'a>(i: BusNum, id: ArrayString<3>, cons: &mut Cons<'a, '_>)
+        -> ConResult<'a, Self>;
+
+    fn to_cons(&self, cons: &mut Vec<String>);
+}
+
+/// A value read from, and written to, one or more constants of a DYR record,
+/// in order.
+trait DyrCons: Sized {
+    fn from_cons<'a>(cons: &mut Cons<'a, '_>) -> ConResult<'a, Self>;
+
+    fn to_cons(&self, cons: &mut Vec<String>);
+}
+
+// The constants of a record, each along with the input at it.
+type Cons<'a, 'b> = std::slice::Iter<'b, (&'a str, &'a str)>;
+
+// Fails with the input at an invalid constant, or with `None` if there are too
+// few constants.
+type ConResult<'a, T> = Result<T, Option<&'a str>>;
+
+impl DyrCons for f64 {
+    fn from_cons<'a>(cons: &mut Cons<'a, '_>) -> ConResult<'a, Self> {
+        let (at, con) = cons.next().ok_or(None)?;
+        con.parse().map_err(|_| Some(*at))
+    }
+
+    fn to_cons(&self, cons: &mut Vec<String>) {
+        cons.push(self.to_field());
+    }
+}
+
+// Integer constants, such as ICONs and flags, may be written as reals, e.g. `1.0`.
+impl DyrCons for i32 {
+    fn from_cons<'a>(cons: &mut Cons<'a, '_>) -> ConResult<'a, Self> {
+        let (at, con) = cons.next().ok_or(None)?;
+        match con.parse::<f64>() {
+            Ok(value) if value.fract() == 0.0 => Ok(value as i32),
+            _ => Err(Some(*at)),
+        }
+    }
+
+    fn to_cons(&self, cons: &mut Vec<String>) {
+        cons.push(self.to_field());
+    }
+}
+
+impl<const N: usize> DyrCons for ArrayString<N> {
+    fn from_cons<'a>(cons: &mut Cons<'a, '_>) -> ConResult<'a, Self> {
+        let (at, con) = cons.next().ok_or(None)?;
+        ArrayString::from(unquote(con)).map_err(|_| Some(*at))
+    }
+
+    fn to_cons(&self, cons: &mut Vec<String>) {
+        cons.push(self.to_field());
+    }
+}
+
+// Reads and writes the constants of a model, in order, from and to the listed
+// fields. Other fields, such as `stat`, take their default values when read.
+// Models that PSS/E names differently give that name after `as`.
+macro_rules! dyr_model {
+    ($($model:ident $(as $name:literal)? { $($field:ident),* $(,)? })*) => {
+        $(impl DyrModel for $model {
+            const NAME: &'static str = model_name!($model $(, $name)?);
+
+            fn from_cons<'a>(
+                i: BusNum,
+                id: ArrayString<3>,
+                cons: &mut Cons<'a, '_>,
+            ) -> ConResult<'a, Self> {
+                Ok($model {
+                    i,
+                    id,
+                    $($field: DyrCons::from_cons(cons)?,)*
+                    ..Default::default()
+                })
+            }
+
+            fn to_cons(&self, cons: &mut Vec<String>) {
+                $(self.$field.to_cons(cons);)*
+            }
+        })*
+    };
+}
+
+macro_rules! model_name {
+    ($model:ident) => {
+        stringify!($model)
+    };
+    ($model:ident, $name:literal) => {
+        $name
+    };
+}
+
+// Reads and writes the constants of a group of parameters within a model, in
+// order.
+macro_rules! dyr_params {
+    ($($params:ident { $($field:ident),* $(,)? })*) => {
+        $(impl DyrCons for $params {
+            fn from_cons<'a>(cons: &mut Cons<'a, '_>) -> ConResult<'a, Self> {
+                Ok($params {
+                    $($field: DyrCons::from_cons(cons)?,)*
+                })
+            }
+
+            fn to_cons(&self, cons: &mut Vec<String>) {
+                $(self.$field.to_cons(cons);)*
+            }
+        })*
+    };
+}
+
+dyr_model! {
+    GENCLS { h, d }
+    GENROU { tdo_p, tdo_pp, tqo_p, tqo_pp, h, d, xd, xq, xd_p, xq_p, xd_pp, xl, s1_0, s1_2 }
+
+    GENSAL { tdo_p, tdo_pp, tqo_pp, h, d, xd, xq, xd_p, xd_pp, xl, s1_0, s1_2 }
+    GENTPJ as "GENTPJU1" {
+        tdo_p, tdo_pp, tqo_p, tqo_pp, h, d, xd, xq, xd_p, xq_p, xd_pp, xl, s1_0, s1_2, kis,
+    }
+
+    SEXS { ta_tb, tb, k, te, emin, emax }
+    IEEET1 { tr, ka, ta, vrmax, vrmin, ke, te, kf, tf, switch, e1, se_e1, e2, se_e2 }
+    ESST1A {
+        uel, vos, tr, vimax, vimin, tc, tb, tc1, tb1, ka, ta, vamax, vamin, vrmax, vrmin, kc,
+        kf, tf, klr, ilr,
+    }
+    ESAC1A {
+        tr, tb, tc, ka, ta, vamax, vamin, te, kf, tf, kc, kd, ke, e1, se_e1, e2, se_e2, vrmax,
+        vrmin,
+    }
+    ESDC1A { tr, ka, ta, tb, tc, vrmax, vrmin, ke, te, kf, tf1, switch, e1, se_e1, e2, se_e2 }
+    EXST1 { tr, vimax, vimin, tc, tb, ka, ta, vrmax, vrmin, kc, kf, tf }
+    SCRX { ta_tb, tb, k, te, emin, emax, cswitch, rc_rfd }
+
+    TGOV1 { r, t1, v_max, v_min, t2, t3, dt }
+    GAST { r, t1, t2, t3, at, kt, vmax, vmin, dturb }
+    HYGOV { r, r_temp, tr, tf, tg, velm, gmax, gmin, tw, at, dturb, q_nl }
+    IEEEG1 {
+        jbus, m, k, t1, t2, t3, uo, uc, pmax, pmin, t4, k1, k2, t5, k3, k4, t6, k5, k6, t7, k7, k8,
+    }
+    GGOV1 {
+        rselect, flag, r, tpelec, maxerr, minerr, kpgov, kigov, kdgov, tdgov, vmax, vmin, tact,
+        kturb, wfnl, tb, tc, teng, tfload, kpload, kiload, ldref, dm, ropen, rclose, kimw, aset,
+        ka, ta, trate, db, tsa, tsb, rup, rdown,
+    }
+    HYGOV4 {
+        db1, err, db2, r, r_temp, tr, tf, tg, uo, uc, pmax, pmin, tw, at, dturb, hdam, q_nl,
+        gv0, pgv0, gv1, pgv1, gv2, pgv2, gv3, pgv3, gv4, pgv4, gv5, pgv5,
+    }
+    WSIEG1 {
+        jbus, m, k, t1, t2, t3, uo, uc, pmax, pmin, t4, k1, k2, t5, k3, k4, t6, k5, k6, t7, k7, k8,
+        db1, err, db2, gv1, pgv1, gv2, pgv2, gv3, pgv3, gv4, pgv4, gv5, pgv5, iblock,
+    }
+    DEGOV1 { m0, t1, t2, t3, k, t4, t5, t6, td, tmax, tmin, droop, te }
+
+    IEEEST { mode, busr, a1, a2, a3, a4, a5, a6, t1, t2, t3, t4, t5, t6, k, vmax, vmin, vcu, vcl }
+    ST2CUT {
+        mode1, busr1, mode2, busr2, k1, k2, t1, t2, t3, t4, t5, t6, t7, t8, t9, t10,
+        lsmax, lsmin, vcu, vcl,
+    }
+    PSS2A {
+        mode1, busr1, mode2, busr2, m, n, tw1, tw2, t6, tw3, tw4, t7, ks2, ks3, t8, t9, ks1, t1,
+        t2, t3, t4, vstmax, vstmin,
+    }
+    PSS2B {
+        mode1, busr1, mode2, busr2, m, n, tw1, tw2, t6, tw3, tw4, t7, ks2, ks3, t8, t9, ks1, t1,
+        t2, t3, t4, vstmax, vstmin, vs1max, vs1min, vs2max, vs2min, t10, t11,
+    }
+    STAB1 { k_t, t, t1_t3, t3, t2_t4, t4, hlim }
+
+    REGC_A as "REGCA1" {
+        lvplsw, tg, rrpwr, brkpt, zerox, lvpl1, volim, lvpnt1, lvpnt0, iolim, tfltr, khv, iqrmax,
+        iqrmin, accel,
+    }
+    REEC_A as "REECA1" {
+        ireg, pfflag, vflag, qflag, pflag, pqflag, vdip, vup, trv, dbd1, dbd2, kqv, iqh1, iql1,
+        vref0, iqfrz, thld, thld2, tp, qmax, qmin, vmax, vmin, kqp, kqi, kvp, kvi, vbias, tiq,
+        dpmax, dpmin, pmax, pmin, imax, tpord, vq1, iq1, vq2, iq2, vq3, iq3, vq4, iq4, vp1, ip1,
+        vp2, ip2, vp3, ip3, vp4, ip4,
+    }
+    REEC_B as "REECB1" {
+        ireg, pfflag, vflag, qflag, pqflag, vdip, vup, trv, dbd1, dbd2, kqv, iqh1, iql1, vref0, tp,
+        qmax, qmin, vmax, vmin, kqp, kqi, kvp, kvi, tiq, dpmax, dpmin, pmax, pmin, imax, tpord,
+    }
+    REPC_A as "REPCA1" {
+        ireg, from_bus, to_bus, ckt, vcflag, refflag, fflag, tfltr, kp, ki, tft, tfv, vfrz, rc, xc,
+        kc, emax, emin, dbd1, dbd2, qmax, qmin, kpg, kig, tp, fdbd1, fdbd2, femax, femin, pmax,
+        pmin, tlag, ddn, dup,
+    }
+    WTGT_A as "WTDTA1" { h, damp, htfrac, freq1, dshaft }
+    WTGAR_A as "WTARA1" { ka, theta0 }
+
+    CLOD as "CLODBL" { lm, ls, tx, fmd, pel, kp, r, x }
+    CMLD {
+        mva, bss, rfdr, xfdr, fb, xxf, tfixhs, tfixls, ltc, tmin, tmax, step, vmin, vmax, tdel,
+        ttap, rcomp, xcomp, fma, fmb, fmc, fmd, fel, pfel, vd1, vd2, frcel, pfs, p1e, p1c, p2e,
+        p2c, pfreq, q1e, q1c, q2e, q2c, qfreq, mtypa, mtypb, mtypc, mtypd, motor_a, motor_b,
+        motor_c, motor_d,
+    }
+}
+
+dyr_params! {
+    ThreePhaseMotor {
+        lfm, rs, ls, lp, lpp, tpo, tppo, h, etrq, vtr1, ttr1, ftr1, vrc1, trc1, vtr2, ttr2, ftr2,
+        vrc2, trc2,
+    }
+    SinglePhaseMotor {
+        lfm, comp_pf, vstall, rstall, xstall, tstall, frst, vrst, trst, fuvr, vtr1, ttr1, vtr2,
+        ttr2, vc1off, vc2off, vc1on, vc2on, tth, th1t, th2t, tv,
+    }
+}
+
+/// Parses a PSS/E dynamic data (DYR) file.
+///
+/// Each record is written `IBUS 'MODEL' ID con1 con2 ... /` and may span
+/// lines. Text after the closing `/` is a comment. Records of models without
+/// their own type are returned as [GenericModel] records.
+///
+/// A record that cannot be read, or that has the wrong number of constants for
+/// its model, is reported with its line and column in `input`.
+pub fn parse_dyr(input: &str) -> Result<Vec<Record>, ParseError> {
+    let (_, records) = terminated(
+        many0(preceded(parse_blank_lines, parse_dyr_record)),
+        pair(parse_blank_lines, eof),
+    )(input)
+    .map_err(|err| ParseError::from_nom(input, err))?;
+    Ok(records)
+}
+
+/// Writes DYR `records`, in order, as PSS/E dynamic data.
+///
+/// Each record is written `IBUS 'MODEL' 'ID' con1 con2 ... /`, with its
+/// constants in the documented order of its model, a few to a line. Models
+/// are written with their PSS/E names, e.g. `REGCA1` for [REGC_A], and generic
+/// records with their constants as they were read.
+pub fn write_dyr(records: &[Record], mut w: impl Write) -> io::Result<()> {
+    for record in records {
+        let (model, cons) = match record {
+            Record::GENCLS(model) => model_cons(model),
+            Record::GENROU(model) => model_cons(model),
+            Record::GENSAL(model) => model_cons(model),
+            Record::GENTPJ(model) => model_cons(model),
+            Record::SEXS(model) => model_cons(model),
+            Record::IEEET1(model) => model_cons(model),
+            Record::ESST1A(model) => model_cons(model),
+            Record::ESAC1A(model) => model_cons(model),
+            Record::ESDC1A(model) => model_cons(model),
+            Record::EXST1(model) => model_cons(model),
+            Record::SCRX(model) => model_cons(model),
+            Record::TGOV1(model) => model_cons(model),
+            Record::GAST(model) => model_cons(model),
+            Record::HYGOV(model) => model_cons(model),
+            Record::IEEEG1(model) => model_cons(model),
+            Record::GGOV1(model) => model_cons(model),
+            Record::HYGOV4(model) => model_cons(model),
+            Record::WSIEG1(model) => model_cons(model),
+            Record::DEGOV1(model) => model_cons(model),
+            Record::IEEEST(model) => model_cons(model),
+            Record::ST2CUT(model) => model_cons(model),
+            Record::PSS2A(model) => model_cons(model),
+            Record::PSS2B(model) => model_cons(model),
+            Record::STAB1(model) => model_cons(model),
+            Record::REGC_A(model) => model_cons(model),
+            Record::REEC_A(model) => model_cons(model),
+            Record::REEC_B(model) => model_cons(model),
+            Record::REPC_A(model) => model_cons(model),
+            Record::WTGT_A(model) => model_cons(model),
+            Record::WTGAR_A(model) => model_cons(model),
+            Record::CMLD(model) => model_cons(model.as_ref()),
+            Record::CLOD(model) => model_cons(model),
+            Record::Generic(generic) => (generic.model.as_str(), generic.cons.clone()),
+        };
+        write_dyr_record(&mut w, record.bus(), record.id(), model, &cons)?;
+    }
+    Ok(())
+}
+
+fn model_cons<M: DyrModel>(model: &M) -> (&'static str, Vec<String>) {
+    let mut cons = Vec::new();
+    model.to_cons(&mut cons);
+    (M::NAME, cons)
+}
+
+// Constants are written after the ID on the first line, and on indented lines
+// that follow.
+const CONS_PER_LINE: usize = 6;
+
+fn write_dyr_record<W: Write>(
+    w: &mut W,
+    i: BusNum,
+    id: &str,
+    model: &str,
+    cons: &[String],
+) -> io::Result<()> {
+    // Strings are quoted, and all other constants must read back as finite reals.
+    let finite =
+        |con: &String| con.starts_with('\'') || con.parse::<f64>().is_ok_and(f64::is_finite);
+    if !cons.iter().all(finite) {
+        return Err(non_finite_error());
+    }
+    write!(w, "{:>6} '{}' '{:<ID_WIDTH$}'", i, model, id)?;
+    for (k, con) in cons.iter().enumerate() {
+        if k > 0 && k % CONS_PER_LINE == 0 {
+            write!(w, "\n      ")?;
+        }
+        write!(w, " {}", con)?;
+    }
+    writeln!(w, " /")
+}
+
+// Skips whitespace, blank lines and lines that are only comments.
+fn parse_blank_lines(input: &str) -> IResult<&str, ()> {
+    let (input, _) = many0(alt((
+        multispace1,
+        recognize(pair(alt((tag("/"), tag("@!"))), not_line_ending)),
+    )))(input)?;
+    Ok((input, ()))
+}
+
+//  101 'GENROU' 1   7.0000  0.30000E-01  0.70000  0.50000E-01   4.0000 0.0000
+//       1.8000   1.7000  0.30000  0.55000  0.25000  0.15000  0.10000  0.30000  /
+fn parse_dyr_record(input: &str) -> IResult<&str, Record> {
+    let (input, i) = context("ibus", parse_bus)(input)?;
+    let (input, _) = parse_sep(input)?;
+    let at_model = input;
+    let (input, model) = cut(context("model", parse_token))(input)?;
+    let (input, _) = cut(parse_sep)(input)?;
+    let at_id = input;
+    let (input, id) = cut(context("id", parse_token))(input)?;
+    let (input, cons) = many0(preceded(parse_sep, parse_con))(input)?;
+    let (input, _) = cut(context("cons", preceded(multispace0, char('/'))))(input)?;
+    let (input, _) = opt(not_line_ending)(input)?;
+    let (input, _) = opt(line_ending)(input)?;
+
+    let model = unquote(model).to_uppercase();
+    let id = (at_id, id);
+    let record = match model.as_str() {
+        "GENCLS" => read_cons(at_model, i, id, &cons).map(Record::GENCLS),
+        "GENROU" => read_cons(at_model, i, id, &cons).map(Record::GENROU),
+        "GENSAL" => read_cons(at_model, i, id, &cons).map(Record::GENSAL),
+        "GENTPJ" | "GENTPJU1" => read_cons(at_model, i, id, &cons).map(Record::GENTPJ),
+        "SEXS" => read_cons(at_model, i, id, &cons).map(Record::SEXS),
+        "IEEET1" => read_cons(at_model, i, id, &cons).map(Record::IEEET1),
+        "ESST1A" => read_cons(at_model, i, id, &cons).map(Record::ESST1A),
+        "ESAC1A" => read_cons(at_model, i, id, &cons).map(Record::ESAC1A),
+        "ESDC1A" => read_cons(at_model, i, id, &cons).map(Record::ESDC1A),
+        "EXST1" => read_cons(at_model, i, id, &cons).map(Record::EXST1),
+        "SCRX" => read_cons(at_model, i, id, &cons).map(Record::SCRX),
+        "TGOV1" => read_cons(at_model, i, id, &cons).map(Record::TGOV1),
+        "GAST" => read_cons(at_model, i, id, &cons).map(Record::GAST),
+        "HYGOV" => read_cons(at_model, i, id, &cons).map(Record::HYGOV),
+        "IEEEG1" => read_cons(at_model, i, id, &cons).map(Record::IEEEG1),
+        "GGOV1" => read_cons(at_model, i, id, &cons).map(Record::GGOV1),
+        "HYGOV4" => read_cons(at_model, i, id, &cons).map(Record::HYGOV4),
+        "WSIEG1" => read_cons(at_model, i, id, &cons).map(Record::WSIEG1),
+        "DEGOV1" => read_cons(at_model, i, id, &cons).map(Record::DEGOV1),
+        "IEEEST" => read_cons(at_model, i, id, &cons).map(Record::IEEEST),
+        "ST2CUT" => read_cons(at_model, i, id, &cons).map(Record::ST2CUT),
+        "PSS2A" => read_cons(at_model, i, id, &cons).map(Record::PSS2A),
+        "PSS2B" => read_cons(at_model, i, id, &cons).map(Record::PSS2B),
+        "STAB1" => read_cons(at_model, i, id, &cons).map(Record::STAB1),
+        "REGC_A" | "REGCA1" => read_cons(at_model, i, id, &cons).map(Record::REGC_A),
+        "REEC_A" | "REECA1" => read_cons(at_model, i, id, &cons).map(Record::REEC_A),
+        "REEC_B" | "REECB1" => read_cons(at_model, i, id, &cons).map(Record::REEC_B),
+        "REPC_A" | "REPCA1" => read_cons(at_model, i, id, &cons).map(Record::REPC_A),
+        "WTGT_A" | "WTDTA1" => read_cons(at_model, i, id, &cons).map(Record::WTGT_A),
+        "WTGAR_A" | "WTARA1" => read_cons(at_model, i, id, &cons).map(Record::WTGAR_A),
+        "CMLD" | "CMPLDW" => read_cons(at_model, i, id, &cons).map(|m| Record::CMLD(Box::new(m))),
+        "CLOD" | "CLODBL" => read_cons(at_model, i, id, &cons).map(Record::CLOD),
+        _ => Ok(Record::Generic(GenericModel {
+            i,
+            model,
+            id: unquote(id.1).to_string(),
+            cons: cons.into_iter().map(|(_, con)| con.to_string()).collect(),
+        })),
+    }?;
+
+    Ok((input, record))
+}
+
+// Reads the ID and constants of a known model, failing at an ID that is too
+// long, at the first constant that is not valid for its field, at the model
+// name if there are too few constants, or at the first one too many.
+fn read_cons<'a, M: DyrModel>(
+    at_model: &'a str,
+    i: BusNum,
+    (at_id, id): (&'a str, &str),
+    cons: &[(&'a str, &'a str)],
+) -> Result<M, nom::Err<RawError<&'a str>>> {
+    let id = ArrayString::from(unquote(id)).map_err(|_| field_error(at_id, "id"))?;
+    let mut cons = cons.iter();
+    let model =
+        M::from_cons(i, id, &mut cons).map_err(|at| field_error(at.unwrap_or(at_model), "cons"))?;
+    match cons.next() {
+        Some((at, _)) => Err(field_error(at, "cons")),
+        None => Ok(model),
+    }
+}
+
+fn field_error<'a>(input: &'a str, field: &'static str) -> nom::Err<RawError<&'a str>> {
+    let mut err = RawError::new(input, nom::error::ErrorKind::Verify);
+    err.field = Some(field);
+    nom::Err::Failure(err)
+}
+
+fn parse_bus(input: &str) -> IResult<&str, BusNum> {
+    nom::character::complete::i32(input)
+}
+
+// A value: a quoted string, or text up to whitespace, a comma or the closing `/`.
+fn parse_token(input: &str) -> IResult<&str, &str> {
+    alt((
+        recognize(delimited(char('\''), take_while(|c| c != '\''), char('\''))),
+        recognize(delimited(char('"'), take_while(|c| c != '"'), char('"'))),
+        take_while1(|c: char| !c.is_whitespace() && c != ',' && c != '/'),
+    ))(input)
+}
+
+// A constant, along with the input at it to locate errors.
+fn parse_con(input: &str) -> IResult<&str, (&str, &str)> {
+    let (rest, con) = parse_token(input)?;
+    Ok((rest, (input, con)))
+}
+
+// Values are separated by whitespace, including line breaks, or commas.
+fn parse_sep(input: &str) -> IResult<&str, ()> {
+    let (input, _) = alt((
+        recognize(pair(multispace0, pair(char(','), multispace0))),
+        multispace1,
+    ))(input)?;
+    Ok((input, ()))
+}
+
+fn unquote(token: &str) -> &str {
+    token.trim_matches(['\'', '"']).trim()
 }
@@ -1,11 +1,16 @@
+use arrayvec::ArrayString;
+
 use crate::BusNum;
 
 /// Simplified Excitation System
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SEXS {
     /// Buses number.
     pub i: BusNum,
 
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
     /// Model status is either one for in-service or zero for out-of-service.
     pub stat: i8,
 
@@ -32,6 +37,7 @@ impl Default for SEXS {
     fn default() -> Self {
         SEXS {
             i: Default::default(),
+            id: Default::default(),
             stat: 1,
             ta_tb: 0.1, // Typical range: 0 to 1
             tb: 10.0,   // Typical range: 1 to 20 seconds
@@ -42,3 +48,465 @@ impl Default for SEXS {
         }
     }
 }
+
+/// IEEE Type 1 Excitation System (1968)
+#[derive(Debug, Clone, PartialEq)]
+pub struct IEEET1 {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Voltage transducer time constant `Tr`.
+    pub tr: f64,
+
+    /// Voltage regulator gain `Ka`.
+    pub ka: f64,
+    /// Voltage regulator time constant `Ta`.
+    pub ta: f64,
+
+    /// Maximum voltage regulator output `Vrmax`.
+    pub vrmax: f64,
+    /// Minimum voltage regulator output `Vrmin`.
+    pub vrmin: f64,
+
+    /// Exciter constant `Ke`, 0 to have it computed for the initial condition.
+    pub ke: f64,
+    /// Exciter time constant `Te`.
+    pub te: f64,
+
+    /// Rate feedback gain `Kf`.
+    pub kf: f64,
+    /// Rate feedback time constant `Tf`.
+    pub tf: f64,
+
+    /// Switch, 0 for a voltage regulator powered from the terminals of the
+    /// machine.
+    pub switch: i32,
+
+    /// Exciter output voltage `E1` for the first saturation point.
+    pub e1: f64,
+    /// Saturation `SE(E1)` at `E1`.
+    pub se_e1: f64,
+    /// Exciter output voltage `E2` for the second saturation point.
+    pub e2: f64,
+    /// Saturation `SE(E2)` at `E2`.
+    pub se_e2: f64,
+}
+
+impl Default for IEEET1 {
+    fn default() -> Self {
+        IEEET1 {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            tr: 0.0,    // Often 0, up to 0.06 seconds
+            ka: 50.0,   // Typical range: 20 to 400
+            ta: 0.04,   // Typical range: 0.02 to 0.2 seconds
+            vrmax: 1.0, // Typical range: 1 to 10 pu
+            vrmin: -1.0,
+            ke: 1.0,  // Typical range: -0.05 to 1
+            te: 0.8,  // Typical range: 0.3 to 1 second
+            kf: 0.03, // Typical range: 0.02 to 0.1
+            tf: 1.0,  // Typical range: 0.35 to 1.5 seconds
+            switch: 0,
+            e1: 2.8,
+            se_e1: 0.04,
+            e2: 3.73,
+            se_e2: 0.33,
+        }
+    }
+}
+
+/// IEEE Type ST1A Excitation System (static, potential-source)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ESST1A {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Under-excitation limiter input location.
+    ///  * 1 - Summing point of the voltage error
+    ///  * 2 - High value gate after the lead-lag blocks
+    ///  * 3 - High value gate before the lead-lag blocks
+    pub uel: i32,
+
+    /// Stabilizer input location, 1 at the voltage error or 2 at the
+    /// regulator output.
+    pub vos: i32,
+
+    /// Voltage transducer time constant `Tr`.
+    pub tr: f64,
+
+    /// Maximum voltage error `Vimax`.
+    pub vimax: f64,
+    /// Minimum voltage error `Vimin`.
+    pub vimin: f64,
+
+    /// Lead time constant `Tc`.
+    pub tc: f64,
+    /// Lag time constant `Tb`.
+    pub tb: f64,
+    /// Second lead time constant `Tc1`.
+    pub tc1: f64,
+    /// Second lag time constant `Tb1`.
+    pub tb1: f64,
+
+    /// Voltage regulator gain `Ka`.
+    pub ka: f64,
+    /// Voltage regulator time constant `Ta`.
+    pub ta: f64,
+
+    /// Maximum voltage regulator output `Vamax`.
+    pub vamax: f64,
+    /// Minimum voltage regulator output `Vamin`.
+    pub vamin: f64,
+
+    /// Maximum exciter output `Vrmax`.
+    pub vrmax: f64,
+    /// Minimum exciter output `Vrmin`.
+    pub vrmin: f64,
+
+    /// Rectifier loading factor `Kc`.
+    pub kc: f64,
+
+    /// Rate feedback gain `Kf`.
+    pub kf: f64,
+    /// Rate feedback time constant `Tf`.
+    pub tf: f64,
+
+    /// Field current limiter gain `Klr`.
+    pub klr: f64,
+    /// Field current limit `Ilr`.
+    pub ilr: f64,
+}
+
+impl Default for ESST1A {
+    fn default() -> Self {
+        ESST1A {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            uel: 1,
+            vos: 1,
+            tr: 0.0,       // Often 0, up to 0.02 seconds
+            vimax: 999.0,  // Often not limited
+            vimin: -999.0, // Often not limited
+            tc: 1.0,       // Typical range: 0 to 2 seconds
+            tb: 10.0,      // Typical range: 1 to 20 seconds
+            tc1: 0.0,
+            tb1: 0.0,
+            ka: 200.0,     // Typical range: 100 to 500
+            ta: 0.02,      // Typical range: 0.01 to 0.1 seconds
+            vamax: 999.0,  // Often not limited
+            vamin: -999.0, // Often not limited
+            vrmax: 7.0,    // Typical range: 5 to 8 pu
+            vrmin: -6.0,   // Typical range: -6 to 0 pu
+            kc: 0.04,      // Typical range: 0 to 0.2
+            kf: 0.0,       // Rate feedback is rarely used
+            tf: 1.0,
+            klr: 0.0, // 0 to disable the field current limiter
+            ilr: 0.0,
+        }
+    }
+}
+
+/// IEEE Type AC1A Excitation System (alternator-rectifier)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ESAC1A {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Voltage transducer time constant `Tr`.
+    pub tr: f64,
+
+    /// Lag time constant `Tb`.
+    pub tb: f64,
+    /// Lead time constant `Tc`.
+    pub tc: f64,
+
+    /// Voltage regulator gain `Ka`.
+    pub ka: f64,
+    /// Voltage regulator time constant `Ta`.
+    pub ta: f64,
+
+    /// Maximum voltage regulator output `Vamax`.
+    pub vamax: f64,
+    /// Minimum voltage regulator output `Vamin`.
+    pub vamin: f64,
+
+    /// Exciter time constant `Te`.
+    pub te: f64,
+
+    /// Rate feedback gain `Kf`.
+    pub kf: f64,
+    /// Rate feedback time constant `Tf`.
+    pub tf: f64,
+
+    /// Rectifier loading factor `Kc`.
+    pub kc: f64,
+    /// Demagnetizing factor `Kd`.
+    pub kd: f64,
+    /// Exciter constant `Ke`.
+    pub ke: f64,
+
+    /// Exciter output voltage `E1` for the first saturation point.
+    pub e1: f64,
+    /// Saturation `SE(E1)` at `E1`.
+    pub se_e1: f64,
+    /// Exciter output voltage `E2` for the second saturation point.
+    pub e2: f64,
+    /// Saturation `SE(E2)` at `E2`.
+    pub se_e2: f64,
+
+    /// Maximum exciter field voltage `Vrmax`.
+    pub vrmax: f64,
+    /// Minimum exciter field voltage `Vrmin`.
+    pub vrmin: f64,
+}
+
+impl Default for ESAC1A {
+    fn default() -> Self {
+        ESAC1A {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            tr: 0.0, // Often 0, up to 0.02 seconds
+            tb: 0.0, // Often 0 to disable the lead-lag
+            tc: 0.0,
+            ka: 400.0,   // Typical range: 200 to 1000
+            ta: 0.02,    // Typical range: 0.01 to 0.1 seconds
+            vamax: 14.5, // Typical range: 5 to 20 pu
+            vamin: -14.5,
+            te: 0.8,  // Typical range: 0.5 to 1.5 seconds
+            kf: 0.03, // Typical range: 0.01 to 0.1
+            tf: 1.0,  // Typical range: 0.5 to 1.5 seconds
+            kc: 0.2,  // Typical range: 0.1 to 0.4
+            kd: 0.38, // Typical range: 0.3 to 0.6
+            ke: 1.0,
+            e1: 4.18,
+            se_e1: 0.1,
+            e2: 3.13,
+            se_e2: 0.03,
+            vrmax: 6.03, // Typical range: 5 to 10 pu
+            vrmin: -5.43,
+        }
+    }
+}
+
+/// IEEE Type DC1A Excitation System (DC commutator exciter)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ESDC1A {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Voltage transducer time constant `Tr`.
+    pub tr: f64,
+
+    /// Voltage regulator gain `Ka`.
+    pub ka: f64,
+    /// Voltage regulator time constant `Ta`.
+    pub ta: f64,
+
+    /// Lag time constant `Tb`.
+    pub tb: f64,
+    /// Lead time constant `Tc`.
+    pub tc: f64,
+
+    /// Maximum voltage regulator output `Vrmax`.
+    pub vrmax: f64,
+    /// Minimum voltage regulator output `Vrmin`.
+    pub vrmin: f64,
+
+    /// Exciter constant `Ke`, 0 to have it computed for the initial condition.
+    pub ke: f64,
+    /// Exciter time constant `Te`.
+    pub te: f64,
+
+    /// Rate feedback gain `Kf`.
+    pub kf: f64,
+    /// Rate feedback time constant `Tf1`.
+    pub tf1: f64,
+
+    /// Switch, 0 for a voltage regulator powered from the terminals of the
+    /// machine.
+    pub switch: i32,
+
+    /// Exciter output voltage `E1` for the first saturation point.
+    pub e1: f64,
+    /// Saturation `SE(E1)` at `E1`.
+    pub se_e1: f64,
+    /// Exciter output voltage `E2` for the second saturation point.
+    pub e2: f64,
+    /// Saturation `SE(E2)` at `E2`.
+    pub se_e2: f64,
+}
+
+impl Default for ESDC1A {
+    fn default() -> Self {
+        ESDC1A {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            tr: 0.0,  // Often 0, up to 0.06 seconds
+            ka: 46.0, // Typical range: 20 to 400
+            ta: 0.06, // Typical range: 0.02 to 0.2 seconds
+            tb: 0.0,  // Often 0 to disable the lead-lag
+            tc: 0.0,
+            vrmax: 1.0, // Typical range: 1 to 10 pu
+            vrmin: -0.9,
+            ke: 0.0,  // Computed for the initial condition
+            te: 0.46, // Typical range: 0.3 to 1 second
+            kf: 0.1,  // Typical range: 0.02 to 0.1
+            tf1: 1.0, // Typical range: 0.35 to 1.5 seconds
+            switch: 0,
+            e1: 3.1,
+            se_e1: 0.33,
+            e2: 2.3,
+            se_e2: 0.1,
+        }
+    }
+}
+
+/// IEEE Type ST1 Excitation System (1981)
+#[derive(Debug, Clone, PartialEq)]
+pub struct EXST1 {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Voltage transducer time constant `Tr`.
+    pub tr: f64,
+
+    /// Maximum voltage error `Vimax`.
+    pub vimax: f64,
+    /// Minimum voltage error `Vimin`.
+    pub vimin: f64,
+
+    /// Lead time constant `Tc`.
+    pub tc: f64,
+    /// Lag time constant `Tb`.
+    pub tb: f64,
+
+    /// Voltage regulator gain `Ka`.
+    pub ka: f64,
+    /// Voltage regulator time constant `Ta`.
+    pub ta: f64,
+
+    /// Maximum voltage regulator output `Vrmax`.
+    pub vrmax: f64,
+    /// Minimum voltage regulator output `Vrmin`.
+    pub vrmin: f64,
+
+    /// Rectifier loading factor `Kc`.
+    pub kc: f64,
+
+    /// Rate feedback gain `Kf`.
+    pub kf: f64,
+    /// Rate feedback time constant `Tf`.
+    pub tf: f64,
+}
+
+impl Default for EXST1 {
+    fn default() -> Self {
+        EXST1 {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            tr: 0.02,   // Typical range: 0 to 0.02 seconds
+            vimax: 0.2, // Typical range: 0.1 to 999 pu
+            vimin: -0.2,
+            tc: 1.0,     // Typical range: 0 to 2 seconds
+            tb: 10.0,    // Typical range: 1 to 20 seconds
+            ka: 200.0,   // Typical range: 100 to 500
+            ta: 0.02,    // Typical range: 0.01 to 0.1 seconds
+            vrmax: 5.0,  // Typical range: 5 to 8 pu
+            vrmin: -4.0, // Typical range: -6 to 0 pu
+            kc: 0.05,    // Typical range: 0 to 0.2
+            kf: 0.0,     // Rate feedback is rarely used
+            tf: 1.0,
+        }
+    }
+}
+
+/// Bus Fed or Solid Fed Static Exciter
+#[derive(Debug, Clone, PartialEq)]
+pub struct SCRX {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Time constant ratio `Ta/Tb`.
+    pub ta_tb: f64,
+
+    /// Denominator time constant `Tb`.
+    pub tb: f64,
+
+    /// Gain `K`.
+    pub k: f64,
+
+    /// Exciter time constant `Te`.
+    pub te: f64,
+
+    /// Minimum exciter output `Emin`.
+    pub emin: f64,
+
+    /// Maximum exciter output `Emax`.
+    pub emax: f64,
+
+    /// Power source switch, 0 for bus fed or 1 for solid fed.
+    pub cswitch: i32,
+
+    /// Ratio `rc/rfd` of the field discharge resistance to the field
+    /// resistance, 0 for an exciter that can supply negative field current.
+    pub rc_rfd: f64,
+}
+
+impl Default for SCRX {
+    fn default() -> Self {
+        SCRX {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            ta_tb: 0.1, // Typical range: 0 to 1
+            tb: 10.0,   // Typical range: 1 to 20 seconds
+            k: 200.0,   // Typical range: 10 to 500
+            te: 0.05,   // Typical range: 0.01 to 1 second
+            emin: -5.0, // Typical range: -5 to 0
+            emax: 5.0,  // Typical range: 3 to 7
+            cswitch: 0, // Bus fed
+            rc_rfd: 10.0,
+        }
+    }
+}
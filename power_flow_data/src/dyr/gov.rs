@@ -1,11 +1,16 @@
+use arrayvec::ArrayString;
+
 use crate::BusNum;
 
 /// Simple Steam Turbine Governor
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TGOV1 {
     /// Buses number.
     pub i: BusNum,
 
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
     /// Model status is either one for in-service or zero for out-of-service.
     pub stat: i8,
 
@@ -35,6 +40,7 @@ impl Default for TGOV1 {
     fn default() -> Self {
         TGOV1 {
             i: Default::default(),
+            id: Default::default(),
             stat: 1,
             r: 0.05,    // Typical range: 0.03 to 0.05 (3% to 5%)
             t1: 0.1,    // Typical range: 0.1 to 0.5 seconds
@@ -48,11 +54,14 @@ impl Default for TGOV1 {
 }
 
 /// Gas Turbine Governor
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GAST {
     /// Buses number.
     pub i: BusNum,
 
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
     /// Model status is either one for in-service or zero for out-of-service.
     pub stat: i8,
 
@@ -88,6 +97,7 @@ impl Default for GAST {
     fn default() -> Self {
         GAST {
             i: Default::default(),
+            id: Default::default(),
             stat: 1,
             r: 0.05,     // Typical range: 0.03 to 0.05 (3% to 5%)
             t1: 0.4,     // Typical range: 0.1 to 0.5 seconds
@@ -102,11 +112,14 @@ impl Default for GAST {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct HYGOV {
     /// Buses number.
     pub i: BusNum,
 
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
     /// Model status is either one for in-service or zero for out-of-service.
     pub stat: i8,
 
@@ -151,6 +164,7 @@ impl Default for HYGOV {
     fn default() -> Self {
         HYGOV {
             i: Default::default(),
+            id: Default::default(),
             stat: 1,
             r: 0.05,     // Typical range: 0.03 to 0.06 (3% to 6%)
             r_temp: 0.3, // Typical range: 0.2 to 0.5
@@ -167,3 +181,602 @@ impl Default for HYGOV {
         }
     }
 }
+
+/// IEEE Type 1 Speed-Governing Model (steam turbine with up to four stages,
+/// optionally shared with a low pressure unit)
+#[derive(Debug, Clone, PartialEq)]
+pub struct IEEEG1 {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Bus of the low pressure unit, 0 if there is none.
+    pub jbus: BusNum,
+    /// Machine identifier of the low pressure unit, 0 if there is none.
+    pub m: i32,
+
+    /// Governor gain `K`, the reciprocal of the droop.
+    pub k: f64,
+
+    /// Governor lag time constant `T1`.
+    pub t1: f64,
+    /// Governor lead time constant `T2`.
+    pub t2: f64,
+    /// Valve positioner time constant `T3`.
+    pub t3: f64,
+
+    /// Maximum valve opening velocity `Uo`.
+    pub uo: f64,
+    /// Maximum valve closing velocity `Uc`, negative.
+    pub uc: f64,
+
+    /// Maximum valve opening `Pmax`.
+    pub pmax: f64,
+    /// Minimum valve opening `Pmin`.
+    pub pmin: f64,
+
+    /// Inlet piping/steam bowl time constant `T4`.
+    pub t4: f64,
+    /// Fraction `K1` of high pressure turbine power.
+    pub k1: f64,
+    /// Fraction `K2` of low pressure turbine power.
+    pub k2: f64,
+
+    /// Second stage time constant `T5`.
+    pub t5: f64,
+    /// Fraction `K3` of high pressure turbine power.
+    pub k3: f64,
+    /// Fraction `K4` of low pressure turbine power.
+    pub k4: f64,
+
+    /// Third stage time constant `T6`.
+    pub t6: f64,
+    /// Fraction `K5` of high pressure turbine power.
+    pub k5: f64,
+    /// Fraction `K6` of low pressure turbine power.
+    pub k6: f64,
+
+    /// Fourth stage time constant `T7`.
+    pub t7: f64,
+    /// Fraction `K7` of high pressure turbine power.
+    pub k7: f64,
+    /// Fraction `K8` of low pressure turbine power.
+    pub k8: f64,
+}
+
+impl Default for IEEEG1 {
+    fn default() -> Self {
+        IEEEG1 {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            jbus: 0,
+            m: 0,
+            k: 20.0,   // Typical range: 16.7 to 33.3 (3% to 6% droop)
+            t1: 0.0,   // Often 0
+            t2: 0.0,   // Often 0
+            t3: 0.1,   // Typical range: 0.04 to 1 second
+            uo: 0.5,   // Typical range: 0.1 to 1 pu/sec
+            uc: -0.5,  // Typical range: -1 to -0.1 pu/sec
+            pmax: 1.0, // Typical value: 1.0 pu
+            pmin: 0.0, // Typical value: 0.0 pu
+            t4: 0.3,   // Typical range: 0.1 to 0.4 seconds
+            k1: 0.3,
+            k2: 0.0,
+            t5: 7.0, // Typical range: 4 to 11 seconds for a reheater
+            k3: 0.4,
+            k4: 0.0,
+            t6: 0.5,
+            k5: 0.3,
+            k6: 0.0,
+            t7: 0.0,
+            k7: 0.0,
+            k8: 0.0,
+        }
+    }
+}
+
+/// WSCC Double-Reheat Turbine Governor, the [IEEEG1] model with governor
+/// deadbands and a nonlinear valve characteristic
+#[derive(Debug, Clone, PartialEq)]
+pub struct WSIEG1 {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Bus of the low pressure unit, 0 if there is none.
+    pub jbus: BusNum,
+    /// Machine identifier of the low pressure unit, 0 if there is none.
+    pub m: i32,
+
+    /// Governor gain `K`, the reciprocal of the droop.
+    pub k: f64,
+
+    /// Governor lag time constant `T1`.
+    pub t1: f64,
+    /// Governor lead time constant `T2`.
+    pub t2: f64,
+    /// Valve positioner time constant `T3`.
+    pub t3: f64,
+
+    /// Maximum valve opening velocity `Uo`.
+    pub uo: f64,
+    /// Maximum valve closing velocity `Uc`, negative.
+    pub uc: f64,
+
+    /// Maximum valve opening `Pmax`.
+    pub pmax: f64,
+    /// Minimum valve opening `Pmin`.
+    pub pmin: f64,
+
+    /// Inlet piping/steam bowl time constant `T4`.
+    pub t4: f64,
+    /// Fraction `K1` of high pressure turbine power.
+    pub k1: f64,
+    /// Fraction `K2` of low pressure turbine power.
+    pub k2: f64,
+
+    /// Second stage time constant `T5`.
+    pub t5: f64,
+    /// Fraction `K3` of high pressure turbine power.
+    pub k3: f64,
+    /// Fraction `K4` of low pressure turbine power.
+    pub k4: f64,
+
+    /// Third stage time constant `T6`.
+    pub t6: f64,
+    /// Fraction `K5` of high pressure turbine power.
+    pub k5: f64,
+    /// Fraction `K6` of low pressure turbine power.
+    pub k6: f64,
+
+    /// Fourth stage time constant `T7`.
+    pub t7: f64,
+    /// Fraction `K7` of high pressure turbine power.
+    pub k7: f64,
+    /// Fraction `K8` of low pressure turbine power.
+    pub k8: f64,
+
+    /// Intentional deadband width `DB1`.
+    pub db1: f64,
+    /// Intentional deadband hysteresis `ERR`.
+    pub err: f64,
+    /// Unintentional deadband `DB2`.
+    pub db2: f64,
+
+    /// Valve position `GV1` of the valve characteristic.
+    pub gv1: f64,
+    /// Power `PGV1` at `GV1`.
+    pub pgv1: f64,
+
+    /// Valve position `GV2` of the valve characteristic.
+    pub gv2: f64,
+    /// Power `PGV2` at `GV2`.
+    pub pgv2: f64,
+
+    /// Valve position `GV3` of the valve characteristic.
+    pub gv3: f64,
+    /// Power `PGV3` at `GV3`.
+    pub pgv3: f64,
+
+    /// Valve position `GV4` of the valve characteristic.
+    pub gv4: f64,
+    /// Power `PGV4` at `GV4`.
+    pub pgv4: f64,
+
+    /// Valve position `GV5` of the valve characteristic.
+    pub gv5: f64,
+    /// Power `PGV5` at `GV5`.
+    pub pgv5: f64,
+
+    /// Whether the valve position is blocked, 0 for none, 1 for the minimum,
+    /// 2 for the maximum or 3 for both.
+    pub iblock: i32,
+}
+
+impl Default for WSIEG1 {
+    fn default() -> Self {
+        WSIEG1 {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            jbus: 0,
+            m: 0,
+            k: 20.0,   // Typical range: 16.7 to 33.3 (3% to 6% droop)
+            t1: 0.0,   // Often 0
+            t2: 0.0,   // Often 0
+            t3: 0.1,   // Typical range: 0.04 to 1 second
+            uo: 0.5,   // Typical range: 0.1 to 1 pu/sec
+            uc: -0.5,  // Typical range: -1 to -0.1 pu/sec
+            pmax: 1.0, // Typical value: 1.0 pu
+            pmin: 0.0, // Typical value: 0.0 pu
+            t4: 0.3,   // Typical range: 0.1 to 0.4 seconds
+            k1: 0.3,
+            k2: 0.0,
+            t5: 7.0, // Typical range: 4 to 11 seconds for a reheater
+            k3: 0.4,
+            k4: 0.0,
+            t6: 0.5,
+            k5: 0.3,
+            k6: 0.0,
+            t7: 0.0,
+            k7: 0.0,
+            k8: 0.0,
+            db1: 0.0,
+            err: 0.0,
+            db2: 0.0,
+            gv1: 0.0, // All zero for a linear valve characteristic
+            pgv1: 0.0,
+            gv2: 0.0,
+            pgv2: 0.0,
+            gv3: 0.0,
+            pgv3: 0.0,
+            gv4: 0.0,
+            pgv4: 0.0,
+            gv5: 0.0,
+            pgv5: 0.0,
+            iblock: 0,
+        }
+    }
+}
+
+/// Hydro Turbine-Governor with a nonlinear gate characteristic
+#[derive(Debug, Clone, PartialEq)]
+pub struct HYGOV4 {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Intentional deadband width `DB1`.
+    pub db1: f64,
+    /// Intentional deadband hysteresis `ERR`.
+    pub err: f64,
+    /// Unintentional deadband `DB2`.
+    pub db2: f64,
+
+    /// Permanent droop `R`.
+    pub r: f64,
+
+    /// Temporary droop `r`.
+    pub r_temp: f64,
+
+    /// Governor time constant `Tr`.
+    pub tr: f64,
+
+    /// Filter time constant `Tf`.
+    pub tf: f64,
+
+    /// Gate servo time constant `Tg`.
+    pub tg: f64,
+
+    /// Maximum gate opening velocity `Uo`.
+    pub uo: f64,
+    /// Maximum gate closing velocity `Uc`, negative.
+    pub uc: f64,
+
+    /// Maximum gate opening `Pmax`.
+    pub pmax: f64,
+    /// Minimum gate opening `Pmin`.
+    pub pmin: f64,
+
+    /// Water time constant `Tw`.
+    pub tw: f64,
+
+    /// Turbine gain `At`.
+    pub at: f64,
+
+    /// Turbine damping factor `Dturb`.
+    pub dturb: f64,
+
+    /// Head available at the dam `Hdam`.
+    pub hdam: f64,
+
+    /// No-load flow at nominal head.
+    pub q_nl: f64,
+
+    /// Gate position `GV0` of the gate characteristic.
+    pub gv0: f64,
+    /// Flow `PGV0` at `GV0`.
+    pub pgv0: f64,
+
+    /// Gate position `GV1` of the gate characteristic.
+    pub gv1: f64,
+    /// Flow `PGV1` at `GV1`.
+    pub pgv1: f64,
+
+    /// Gate position `GV2` of the gate characteristic.
+    pub gv2: f64,
+    /// Flow `PGV2` at `GV2`.
+    pub pgv2: f64,
+
+    /// Gate position `GV3` of the gate characteristic.
+    pub gv3: f64,
+    /// Flow `PGV3` at `GV3`.
+    pub pgv3: f64,
+
+    /// Gate position `GV4` of the gate characteristic.
+    pub gv4: f64,
+    /// Flow `PGV4` at `GV4`.
+    pub pgv4: f64,
+
+    /// Gate position `GV5` of the gate characteristic.
+    pub gv5: f64,
+    /// Flow `PGV5` at `GV5`.
+    pub pgv5: f64,
+}
+
+impl Default for HYGOV4 {
+    fn default() -> Self {
+        HYGOV4 {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            db1: 0.0,
+            err: 0.0,
+            db2: 0.0,
+            r: 0.05,     // Typical range: 0.03 to 0.06 (3% to 6%)
+            r_temp: 0.3, // Typical range: 0.2 to 0.5
+            tr: 5.0,     // Typical range: 2.5 to 5.0 seconds
+            tf: 0.05,    // Typical range: 0.02 to 0.1 seconds
+            tg: 0.5,     // Typical range: 0.2 to 0.5 seconds
+            uo: 0.2,     // Typical range: 0.1 to 0.3 pu/sec
+            uc: -0.2,    // Typical range: -0.3 to -0.1 pu/sec
+            pmax: 1.0,   // Typical value: 1.0 pu
+            pmin: 0.0,   // Typical value: 0.0 pu
+            tw: 1.0,     // Typical range: 0.5 to 5.0 seconds
+            at: 1.2,     // Typical range: 1.0 to 1.5
+            dturb: 0.5,  // Typical range: 0.2 to 0.7
+            hdam: 1.0,   // Typical value: 1.0 pu
+            q_nl: 0.08,  // Typical range: 0.05 to 0.1 pu
+            gv0: 0.0,
+            pgv0: 0.0,
+            gv1: 0.1,
+            pgv1: 0.08,
+            gv2: 0.3,
+            pgv2: 0.3,
+            gv3: 0.5,
+            pgv3: 0.55,
+            gv4: 0.7,
+            pgv4: 0.8,
+            gv5: 1.0,
+            pgv5: 1.0,
+        }
+    }
+}
+
+/// Woodward Diesel Governor
+#[derive(Debug, Clone, PartialEq)]
+pub struct DEGOV1 {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Droop feedback signal, 0 for the throttle position or 1 for the
+    /// electrical power.
+    pub m0: i32,
+
+    /// Electric control box time constant `T1`.
+    pub t1: f64,
+    /// Electric control box time constant `T2`.
+    pub t2: f64,
+    /// Electric control box time constant `T3`.
+    pub t3: f64,
+
+    /// Actuator gain `K`.
+    pub k: f64,
+
+    /// Actuator time constant `T4`.
+    pub t4: f64,
+    /// Actuator time constant `T5`.
+    pub t5: f64,
+    /// Actuator time constant `T6`.
+    pub t6: f64,
+
+    /// Engine time delay `Td`.
+    pub td: f64,
+
+    /// Maximum throttle position `Tmax`.
+    pub tmax: f64,
+    /// Minimum throttle position `Tmin`.
+    pub tmin: f64,
+
+    /// Droop.
+    pub droop: f64,
+
+    /// Droop filter time constant `Te`.
+    pub te: f64,
+}
+
+impl Default for DEGOV1 {
+    fn default() -> Self {
+        DEGOV1 {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            m0: 0,     // Throttle feedback
+            t1: 0.2,   // Typical range: 0.01 to 0.5 seconds
+            t2: 0.3,   // Typical range: 0.01 to 0.5 seconds
+            t3: 0.5,   // Typical range: 0.1 to 1 second
+            k: 20.0,   // Typical range: 10 to 40
+            t4: 1.0,   // Typical range: 0.5 to 2 seconds
+            t5: 0.1,   // Typical range: 0.05 to 0.2 seconds
+            t6: 0.2,   // Typical range: 0.1 to 0.5 seconds
+            td: 0.02,  // Typical range: 0.01 to 0.1 seconds
+            tmax: 1.1, // Typical value: 1.1 pu
+            tmin: 0.0, // Typical value: 0.0 pu
+            droop: 0.05,
+            te: 0.0,
+        }
+    }
+}
+
+/// GE General Governor/Turbine Model
+#[derive(Debug, Clone, PartialEq)]
+pub struct GGOV1 {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Feedback signal for the droop.
+    ///  * 1 - Electrical power
+    ///  * 0 - None (isochronous governor)
+    ///  * -1 - Governor output (requested stroke)
+    ///  * -2 - Valve stroke
+    pub rselect: i32,
+
+    /// Switch for the fuel source characteristic, 0 to multiply the fuel flow
+    /// by speed or 1 not to.
+    pub flag: i32,
+
+    /// Permanent droop `R`.
+    pub r: f64,
+    /// Electrical power transducer time constant `Tpelec`.
+    pub tpelec: f64,
+
+    /// Maximum value for the speed error signal.
+    pub maxerr: f64,
+    /// Minimum value for the speed error signal.
+    pub minerr: f64,
+
+    /// Governor proportional gain `Kpgov`.
+    pub kpgov: f64,
+    /// Governor integral gain `Kigov`.
+    pub kigov: f64,
+    /// Governor derivative gain `Kdgov`.
+    pub kdgov: f64,
+    /// Governor derivative controller time constant `Tdgov`.
+    pub tdgov: f64,
+
+    /// Maximum valve position `Vmax`.
+    pub vmax: f64,
+    /// Minimum valve position `Vmin`.
+    pub vmin: f64,
+
+    /// Actuator time constant `Tact`.
+    pub tact: f64,
+
+    /// Turbine gain `Kturb`.
+    pub kturb: f64,
+    /// No-load fuel flow `Wfnl`.
+    pub wfnl: f64,
+    /// Turbine lag time constant `Tb`.
+    pub tb: f64,
+    /// Turbine lead time constant `Tc`.
+    pub tc: f64,
+
+    /// Transport lag time constant for the diesel engine `Teng`.
+    pub teng: f64,
+
+    /// Load limiter time constant `Tfload`.
+    pub tfload: f64,
+    /// Load limiter proportional gain `Kpload`.
+    pub kpload: f64,
+    /// Load limiter integral gain `Kiload`.
+    pub kiload: f64,
+    /// Load limiter reference value `Ldref`.
+    pub ldref: f64,
+
+    /// Speed sensitivity coefficient `Dm`.
+    pub dm: f64,
+
+    /// Maximum valve opening rate `Ropen`.
+    pub ropen: f64,
+    /// Maximum valve closing rate `Rclose`.
+    pub rclose: f64,
+
+    /// Power controller (reset) gain `Kimw`.
+    pub kimw: f64,
+
+    /// Acceleration limiter setpoint `Aset`.
+    pub aset: f64,
+    /// Acceleration limiter gain `Ka`.
+    pub ka: f64,
+    /// Acceleration limiter time constant `Ta`.
+    pub ta: f64,
+
+    /// Turbine rating `Trate` in MW, 0 for the machine base.
+    pub trate: f64,
+
+    /// Speed governor deadband `db`.
+    pub db: f64,
+
+    /// Temperature detection lead time constant `Tsa`.
+    pub tsa: f64,
+    /// Temperature detection lag time constant `Tsb`.
+    pub tsb: f64,
+
+    /// Maximum rate of load limit increase `Rup`.
+    pub rup: f64,
+    /// Maximum rate of load limit decrease `Rdown`.
+    pub rdown: f64,
+}
+
+impl Default for GGOV1 {
+    fn default() -> Self {
+        GGOV1 {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            rselect: 1, // Electrical power feedback
+            flag: 1,
+            r: 0.04,       // Typical range: 0.03 to 0.05 (3% to 5%)
+            tpelec: 1.0,   // Typical range: 0.5 to 2 seconds
+            maxerr: 0.05,  // Typical value: 0.05 pu
+            minerr: -0.05, // Typical value: -0.05 pu
+            kpgov: 10.0,   // Typical range: 5 to 20
+            kigov: 2.0,    // Typical range: 1 to 5
+            kdgov: 0.0,    // Often 0
+            tdgov: 1.0,
+            vmax: 1.0,    // Typical value: 1.0 pu
+            vmin: 0.15,   // Typical range: 0.1 to 0.2 pu
+            tact: 0.5,    // Typical range: 0.1 to 1 second
+            kturb: 1.5,   // Typical range: 1.2 to 2
+            wfnl: 0.2,    // Typical range: 0.15 to 0.25 pu
+            tb: 0.1,      // Typical range: 0.05 to 0.5 seconds
+            tc: 0.0,      // Often 0
+            teng: 0.0,    // 0 except for diesel engines
+            tfload: 3.0,  // Typical range: 2 to 5 seconds
+            kpload: 2.0,  // Typical range: 1 to 4
+            kiload: 0.67, // Typical range: 0.5 to 1
+            ldref: 1.0,   // Typical value: 1.0 pu
+            dm: 0.0,      // Often 0
+            ropen: 0.1,   // Typical range: 0.05 to 0.2 pu/sec
+            rclose: -0.1, // Typical range: -0.2 to -0.05 pu/sec
+            kimw: 0.0,    // 0 to disable the power controller
+            aset: 0.01,   // Typical range: 0.01 to 0.1 pu/sec
+            ka: 10.0,     // Typical range: 5 to 20
+            ta: 0.1,      // Typical range: 0.05 to 0.2 seconds
+            trate: 0.0,   // Machine base
+            db: 0.0,
+            tsa: 4.0,     // Typical value: 4 seconds
+            tsb: 5.0,     // Typical value: 5 seconds
+            rup: 99.0,    // Often not limited
+            rdown: -99.0, // Often not limited
+        }
+    }
+}
@@ -0,0 +1,406 @@
+use arrayvec::ArrayString;
+
+use crate::BusNum;
+
+/// Complex Load Model, for the loads at a bus (`CLODBL` in PSS/E).
+///
+/// The load is split into large and small motors, transformer exciting
+/// current, discharge lighting, constant power and the remaining load, which
+/// varies with voltage by `kp`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CLOD {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Load identifier, `*` for all the loads at the bus.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Large motor load `LM` in percent.
+    pub lm: f64,
+    /// Small motor load `LS` in percent.
+    pub ls: f64,
+    /// Transformer exciting current `TX` in percent.
+    pub tx: f64,
+    /// Discharge lighting load `FMD` in percent.
+    pub fmd: f64,
+    /// Constant power load `PEL` in percent.
+    pub pel: f64,
+
+    /// Voltage exponent `K` for the active power of the remaining load.
+    pub kp: f64,
+
+    /// Feeder resistance `R`.
+    pub r: f64,
+    /// Feeder reactance `X`.
+    pub x: f64,
+}
+
+impl Default for CLOD {
+    fn default() -> Self {
+        CLOD {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            lm: 30.0,  // Typical for industrial load
+            ls: 40.0,  // Typical for residential load
+            tx: 2.0,   // Typical range: 1 to 5 percent
+            fmd: 10.0, // Typical range: 0 to 20 percent
+            pel: 5.0,  // Typical range: 0 to 20 percent
+            kp: 2.0,   // Constant impedance
+            r: 0.0,
+            x: 0.0,
+        }
+    }
+}
+
+/// WECC Composite Load Model, with its parameters in the order of the WECC
+/// `cmpldw` specification.
+///
+/// The load is served through a substation shunt, transformer and feeder,
+/// and split into three-phase motors A, B and C, single-phase air
+/// conditioner motor D, power electronic load and static load.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CMLD {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Load identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Load base `MVA`, or if negative the ratio of the load MW to the base.
+    pub mva: f64,
+
+    /// Substation shunt susceptance `Bss`.
+    pub bss: f64,
+
+    /// Feeder resistance `Rfdr`.
+    pub rfdr: f64,
+    /// Feeder reactance `Xfdr`.
+    pub xfdr: f64,
+    /// Fraction `Fb` of the feeder susceptance at the substation end.
+    pub fb: f64,
+
+    /// Transformer reactance `Xxf`.
+    pub xxf: f64,
+    /// Transformer fixed tap at the high side `TfixHS`.
+    pub tfixhs: f64,
+    /// Transformer fixed tap at the low side `TfixLS`.
+    pub tfixls: f64,
+
+    /// Tap changer control flag `LTC`, 1 to enable or 0 to disable.
+    pub ltc: i32,
+    /// Minimum tap `Tmin`.
+    pub tmin: f64,
+    /// Maximum tap `Tmax`.
+    pub tmax: f64,
+    /// Tap step `step`.
+    pub step: f64,
+    /// Minimum controlled voltage `Vmin`.
+    pub vmin: f64,
+    /// Maximum controlled voltage `Vmax`.
+    pub vmax: f64,
+    /// Tap control time delay `Tdel`.
+    pub tdel: f64,
+    /// Tap adjustment time delay `Ttap`.
+    pub ttap: f64,
+    /// Tap control compensating resistance `Rcomp`.
+    pub rcomp: f64,
+    /// Tap control compensating reactance `Xcomp`.
+    pub xcomp: f64,
+
+    /// Fraction of the load that is motor A `FmA`.
+    pub fma: f64,
+    /// Fraction of the load that is motor B `FmB`.
+    pub fmb: f64,
+    /// Fraction of the load that is motor C `FmC`.
+    pub fmc: f64,
+    /// Fraction of the load that is motor D `FmD`.
+    pub fmd: f64,
+    /// Fraction of the load that is power electronic `Fel`.
+    pub fel: f64,
+
+    /// Power factor of the power electronic load `PFel`.
+    pub pfel: f64,
+    /// Voltage `Vd1` below which the power electronic load starts to trip.
+    pub vd1: f64,
+    /// Voltage `Vd2` below which all the power electronic load is tripped.
+    pub vd2: f64,
+    /// Fraction of the tripped power electronic load that reconnects `Frcel`.
+    pub frcel: f64,
+
+    /// Power factor of the static load `PFs`.
+    pub pfs: f64,
+    /// Voltage exponent `P1e` of the first active power term.
+    pub p1e: f64,
+    /// Coefficient `P1c` of the first active power term.
+    pub p1c: f64,
+    /// Voltage exponent `P2e` of the second active power term.
+    pub p2e: f64,
+    /// Coefficient `P2c` of the second active power term.
+    pub p2c: f64,
+    /// Frequency sensitivity `Pfreq` of the active power.
+    pub pfreq: f64,
+    /// Voltage exponent `Q1e` of the first reactive power term.
+    pub q1e: f64,
+    /// Coefficient `Q1c` of the first reactive power term.
+    pub q1c: f64,
+    /// Voltage exponent `Q2e` of the second reactive power term.
+    pub q2e: f64,
+    /// Coefficient `Q2c` of the second reactive power term.
+    pub q2c: f64,
+    /// Frequency sensitivity `Qfreq` of the reactive power.
+    pub qfreq: f64,
+
+    /// Type of motor A, 3 for three-phase.
+    pub mtypa: i32,
+    /// Type of motor B, 3 for three-phase.
+    pub mtypb: i32,
+    /// Type of motor C, 3 for three-phase.
+    pub mtypc: i32,
+    /// Type of motor D, 1 for single-phase.
+    pub mtypd: i32,
+
+    /// Three-phase motor A, typically commercial air conditioner compressors.
+    pub motor_a: ThreePhaseMotor,
+    /// Three-phase motor B, typically fans with a high inertia.
+    pub motor_b: ThreePhaseMotor,
+    /// Three-phase motor C, typically pumps with a low inertia.
+    pub motor_c: ThreePhaseMotor,
+
+    /// Single-phase motor D, residential air conditioner compressors.
+    pub motor_d: SinglePhaseMotor,
+}
+
+impl Default for CMLD {
+    fn default() -> Self {
+        CMLD {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            mva: -1.25, // Load MW is 80% of the base
+            bss: 0.0,
+            rfdr: 0.04,
+            xfdr: 0.04,
+            fb: 0.75,
+            xxf: 0.08,
+            tfixhs: 1.0,
+            tfixls: 1.0,
+            ltc: 1,
+            tmin: 0.9,
+            tmax: 1.1,
+            step: 0.00625,
+            vmin: 1.025,
+            vmax: 1.04,
+            tdel: 30.0,
+            ttap: 5.0,
+            rcomp: 0.0,
+            xcomp: 0.0,
+            fma: 0.15,
+            fmb: 0.15,
+            fmc: 0.1,
+            fmd: 0.2,
+            fel: 0.1,
+            pfel: 1.0,
+            vd1: 0.7,
+            vd2: 0.5,
+            frcel: 0.8,
+            pfs: 0.95,
+            p1e: 2.0, // Constant impedance
+            p1c: 0.5,
+            p2e: 1.0, // Constant current
+            p2c: 0.5,
+            pfreq: 0.0,
+            q1e: 2.0,
+            q1c: 0.5,
+            q2e: 1.0,
+            q2c: 0.5,
+            qfreq: -1.0,
+            mtypa: 3,
+            mtypb: 3,
+            mtypc: 3,
+            mtypd: 1,
+            motor_a: ThreePhaseMotor {
+                h: 0.05, // Constant torque compressors
+                etrq: 0.0,
+                ..Default::default()
+            },
+            motor_b: ThreePhaseMotor {
+                h: 1.0, // Fans
+                etrq: 2.0,
+                ..Default::default()
+            },
+            motor_c: ThreePhaseMotor {
+                h: 0.1, // Pumps
+                etrq: 2.0,
+                ..Default::default()
+            },
+            motor_d: Default::default(),
+        }
+    }
+}
+
+/// Parameters of a three-phase induction motor of the [CMLD] model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThreePhaseMotor {
+    /// Loading factor `LFm`, the ratio of the load to the motor rating.
+    pub lfm: f64,
+
+    /// Stator resistance `Rs`.
+    pub rs: f64,
+    /// Synchronous reactance `Ls`.
+    pub ls: f64,
+    /// Transient reactance `Lp`.
+    pub lp: f64,
+    /// Subtransient reactance `Lpp`.
+    pub lpp: f64,
+
+    /// Transient open-circuit time constant `Tpo`.
+    pub tpo: f64,
+    /// Subtransient open-circuit time constant `Tppo`.
+    pub tppo: f64,
+
+    /// Inertia constant `H`.
+    pub h: f64,
+
+    /// Speed exponent `etrq` of the mechanical torque.
+    pub etrq: f64,
+
+    /// Voltage `Vtr1` of the first undervoltage trip.
+    pub vtr1: f64,
+    /// Delay `Ttr1` of the first undervoltage trip.
+    pub ttr1: f64,
+    /// Fraction `Ftr1` of the motor tripped by the first undervoltage trip.
+    pub ftr1: f64,
+    /// Voltage `Vrc1` at which the first tripped fraction reconnects.
+    pub vrc1: f64,
+    /// Delay `Trc1` of the first reconnection.
+    pub trc1: f64,
+
+    /// Voltage `Vtr2` of the second undervoltage trip.
+    pub vtr2: f64,
+    /// Delay `Ttr2` of the second undervoltage trip.
+    pub ttr2: f64,
+    /// Fraction `Ftr2` of the motor tripped by the second undervoltage trip.
+    pub ftr2: f64,
+    /// Voltage `Vrc2` at which the second tripped fraction reconnects.
+    pub vrc2: f64,
+    /// Delay `Trc2` of the second reconnection.
+    pub trc2: f64,
+}
+
+impl Default for ThreePhaseMotor {
+    fn default() -> Self {
+        ThreePhaseMotor {
+            lfm: 0.75,
+            rs: 0.04,
+            ls: 1.8,
+            lp: 0.12,
+            lpp: 0.104,
+            tpo: 0.095,
+            tppo: 0.0021,
+            h: 0.1,
+            etrq: 0.0,
+            vtr1: 0.7,
+            ttr1: 0.02,
+            ftr1: 0.2,
+            vrc1: 1.0,
+            trc1: 99999.0, // No reconnection
+            vtr2: 0.5,
+            ttr2: 0.02,
+            ftr2: 0.7,
+            vrc2: 0.7,
+            trc2: 0.1,
+        }
+    }
+}
+
+/// Parameters of the single-phase air conditioner motor of the [CMLD] model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SinglePhaseMotor {
+    /// Loading factor `LFm`, the ratio of the load to the motor rating.
+    pub lfm: f64,
+
+    /// Power factor `CompPF` of the compressor.
+    pub comp_pf: f64,
+
+    /// Voltage `Vstall` below which the compressor stalls.
+    pub vstall: f64,
+    /// Stalled resistance `Rstall`.
+    pub rstall: f64,
+    /// Stalled reactance `Xstall`.
+    pub xstall: f64,
+    /// Stall delay `Tstall`.
+    pub tstall: f64,
+
+    /// Fraction `Frst` of stalled compressors that can restart.
+    pub frst: f64,
+    /// Voltage `Vrst` at which compressors restart.
+    pub vrst: f64,
+    /// Restart delay `Trst`.
+    pub trst: f64,
+
+    /// Fraction `fuvr` of compressors with undervoltage relays.
+    pub fuvr: f64,
+    /// Voltage `vtr1` of the first undervoltage trip.
+    pub vtr1: f64,
+    /// Delay `ttr1` of the first undervoltage trip.
+    pub ttr1: f64,
+    /// Voltage `vtr2` of the second undervoltage trip.
+    pub vtr2: f64,
+    /// Delay `ttr2` of the second undervoltage trip.
+    pub ttr2: f64,
+
+    /// Voltage `Vc1off` at which contactors start to drop out.
+    pub vc1off: f64,
+    /// Voltage `Vc2off` at which all contactors have dropped out.
+    pub vc2off: f64,
+    /// Voltage `Vc1on` at which contactors start to reclose.
+    pub vc1on: f64,
+    /// Voltage `Vc2on` at which all contactors have reclosed.
+    pub vc2on: f64,
+
+    /// Thermal protection time constant `Tth`.
+    pub tth: f64,
+    /// Temperature `Th1t` at which compressors start to trip.
+    pub th1t: f64,
+    /// Temperature `Th2t` at which all compressors have tripped.
+    pub th2t: f64,
+
+    /// Voltage measurement lag `Tv`.
+    pub tv: f64,
+}
+
+impl Default for SinglePhaseMotor {
+    fn default() -> Self {
+        SinglePhaseMotor {
+            lfm: 1.0,
+            comp_pf: 0.98,
+            vstall: 0.6,
+            rstall: 0.1,
+            xstall: 0.1,
+            tstall: 0.033,
+            frst: 0.2,
+            vrst: 0.95,
+            trst: 0.3,
+            fuvr: 0.1,
+            vtr1: 0.6,
+            ttr1: 0.02,
+            vtr2: 0.0,
+            ttr2: 9999.0, // Disabled
+            vc1off: 0.5,
+            vc2off: 0.4,
+            vc1on: 0.6,
+            vc2on: 0.5,
+            tth: 15.0,
+            th1t: 0.7,
+            th2t: 1.9,
+            tv: 0.025,
+        }
+    }
+}
@@ -1,7 +1,11 @@
 mod dyr;
 pub mod exc;
 pub mod gov;
+pub mod load;
 pub mod pss;
+pub mod ren;
 pub mod sym;
 
-pub use dyr::Record;
+pub use dyr::{
+    link_generators, parse_dyr, write_dyr, GeneratorModels, GenericModel, Record, MACHINE_MODELS,
+};
@@ -1,11 +1,16 @@
+use arrayvec::ArrayString;
+
 use crate::BusNum;
 
 /// Represents the parameters for the IEEEST (IEEE Stabilizing Model) in PSS/E rev 33
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IEEEST {
     /// Buses number.
     pub i: BusNum,
 
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
     /// Model status is either one for in-service or zero for out-of-service.
     pub stat: i8,
 
@@ -54,12 +59,18 @@ pub struct IEEEST {
     pub vmax: f64,
     /// Minimum stabilizer output
     pub vmin: f64,
+
+    /// Upper cutoff voltage `Vcu`, 0 to disable.
+    pub vcu: f64,
+    /// Lower cutoff voltage `Vcl`, 0 to disable.
+    pub vcl: f64,
 }
 
 impl Default for IEEEST {
     fn default() -> Self {
         IEEEST {
-            i: 0,    // Default bus number, should be set by user
+            i: 0, // Default bus number, should be set by user
+            id: Default::default(),
             stat: 1, // 1 for in-service
             mode: 1, // 1 for rotor speed deviation
             busr: 0, // Default reference bus, should be set by user
@@ -78,16 +89,21 @@ impl Default for IEEEST {
             k: 10.0,    // Typical stabilizer gain
             vmax: 0.1,  // Typical maximum output
             vmin: -0.1, // Typical minimum output
+            vcu: 0.0,
+            vcl: 0.0,
         }
     }
 }
 
 /// Parameters for the ST2CUT model (Dual-input stabilizing model) in PSS/E.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ST2CUT {
     /// Buses number.
     pub i: BusNum,
 
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
     /// Model status is either one for in-service or zero for out-of-service.
     pub stat: i8,
 
@@ -160,6 +176,7 @@ impl Default for ST2CUT {
     fn default() -> Self {
         ST2CUT {
             i: Default::default(),
+            id: Default::default(),
             stat: 1,  // 1 for in-service
             mode1: 1, // 1 for rotor speed deviation (input 1)
             busr1: 0,
@@ -184,3 +201,293 @@ impl Default for ST2CUT {
         }
     }
 }
+
+/// IEEE Dual-Input Stabilizer Model (IEEE 421.5 type PSS2A)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PSS2A {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Input signal 1.
+    ///  * 1 - Rotor speed deviation
+    ///  * 2 - Bus frequency deviation
+    ///  * 3 - Generator electrical power
+    ///  * 4 - Generator accelerating power
+    ///  * 5 - Bus voltage
+    ///  * 6 - Derivative of bus voltage
+    pub mode1: i32,
+
+    /// Remote bus for input signal 1, 0 for the terminals of the machine.
+    pub busr1: BusNum,
+
+    /// Input signal 2, with the same codes as `mode1`.
+    pub mode2: i32,
+
+    /// Remote bus for input signal 2, 0 for the terminals of the machine.
+    pub busr2: BusNum,
+
+    /// Order `M` of the ramp tracking filter.
+    pub m: i32,
+    /// Order `N` of the ramp tracking filter.
+    pub n: i32,
+
+    /// First washout time constant `Tw1` of input 1.
+    pub tw1: f64,
+    /// Second washout time constant `Tw2` of input 1.
+    pub tw2: f64,
+    /// Transducer time constant `T6` of input 1.
+    pub t6: f64,
+
+    /// First washout time constant `Tw3` of input 2.
+    pub tw3: f64,
+    /// Second washout time constant `Tw4` of input 2.
+    pub tw4: f64,
+    /// Transducer time constant `T7` of input 2.
+    pub t7: f64,
+
+    /// Input 2 gain `Ks2`.
+    pub ks2: f64,
+    /// Input 2 gain `Ks3` into the ramp tracking filter.
+    pub ks3: f64,
+
+    /// Ramp tracking filter lead time constant `T8`.
+    pub t8: f64,
+    /// Ramp tracking filter lag time constant `T9`.
+    pub t9: f64,
+
+    /// Stabilizer gain `Ks1`.
+    pub ks1: f64,
+
+    /// First lead time constant `T1`.
+    pub t1: f64,
+    /// First lag time constant `T2`.
+    pub t2: f64,
+    /// Second lead time constant `T3`.
+    pub t3: f64,
+    /// Second lag time constant `T4`.
+    pub t4: f64,
+
+    /// Maximum stabilizer output `Vstmax`.
+    pub vstmax: f64,
+    /// Minimum stabilizer output `Vstmin`.
+    pub vstmin: f64,
+}
+
+impl Default for PSS2A {
+    fn default() -> Self {
+        PSS2A {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            mode1: 1, // Rotor speed deviation
+            busr1: 0,
+            mode2: 3, // Generator electrical power
+            busr2: 0,
+            m: 5,
+            n: 1,
+            tw1: 2.0, // Typical range: 1 to 10 seconds
+            tw2: 2.0,
+            t6: 0.0,
+            tw3: 2.0,
+            tw4: 0.0,
+            t7: 2.0,  // Usually equal to Tw2
+            ks2: 0.2, // Usually T7 / (2 H)
+            ks3: 1.0,
+            t8: 0.5,
+            t9: 0.1,
+            ks1: 10.0, // Typical range: 5 to 30
+            t1: 0.15,  // Typical range: 0.1 to 0.3 seconds
+            t2: 0.03,  // Typical range: 0.02 to 0.05 seconds
+            t3: 0.15,
+            t4: 0.03,
+            vstmax: 0.1,  // Typical range: 0.05 to 0.2 pu
+            vstmin: -0.1, // Typical range: -0.2 to -0.05 pu
+        }
+    }
+}
+
+/// IEEE Dual-Input Stabilizer Model (IEEE 421.5 type PSS2B), the [PSS2A]
+/// model with input limits and a third lead/lag block
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PSS2B {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Input signal 1.
+    ///  * 1 - Rotor speed deviation
+    ///  * 2 - Bus frequency deviation
+    ///  * 3 - Generator electrical power
+    ///  * 4 - Generator accelerating power
+    ///  * 5 - Bus voltage
+    ///  * 6 - Derivative of bus voltage
+    pub mode1: i32,
+
+    /// Remote bus for input signal 1, 0 for the terminals of the machine.
+    pub busr1: BusNum,
+
+    /// Input signal 2, with the same codes as `mode1`.
+    pub mode2: i32,
+
+    /// Remote bus for input signal 2, 0 for the terminals of the machine.
+    pub busr2: BusNum,
+
+    /// Order `M` of the ramp tracking filter.
+    pub m: i32,
+    /// Order `N` of the ramp tracking filter.
+    pub n: i32,
+
+    /// First washout time constant `Tw1` of input 1.
+    pub tw1: f64,
+    /// Second washout time constant `Tw2` of input 1.
+    pub tw2: f64,
+    /// Transducer time constant `T6` of input 1.
+    pub t6: f64,
+
+    /// First washout time constant `Tw3` of input 2.
+    pub tw3: f64,
+    /// Second washout time constant `Tw4` of input 2.
+    pub tw4: f64,
+    /// Transducer time constant `T7` of input 2.
+    pub t7: f64,
+
+    /// Input 2 gain `Ks2`.
+    pub ks2: f64,
+    /// Input 2 gain `Ks3` into the ramp tracking filter.
+    pub ks3: f64,
+
+    /// Ramp tracking filter lead time constant `T8`.
+    pub t8: f64,
+    /// Ramp tracking filter lag time constant `T9`.
+    pub t9: f64,
+
+    /// Stabilizer gain `Ks1`.
+    pub ks1: f64,
+
+    /// First lead time constant `T1`.
+    pub t1: f64,
+    /// First lag time constant `T2`.
+    pub t2: f64,
+    /// Second lead time constant `T3`.
+    pub t3: f64,
+    /// Second lag time constant `T4`.
+    pub t4: f64,
+
+    /// Maximum stabilizer output `Vstmax`.
+    pub vstmax: f64,
+    /// Minimum stabilizer output `Vstmin`.
+    pub vstmin: f64,
+
+    /// Maximum input signal 1 `Vs1max`.
+    pub vs1max: f64,
+    /// Minimum input signal 1 `Vs1min`.
+    pub vs1min: f64,
+
+    /// Maximum input signal 2 `Vs2max`.
+    pub vs2max: f64,
+    /// Minimum input signal 2 `Vs2min`.
+    pub vs2min: f64,
+
+    /// Third lead time constant `T10`.
+    pub t10: f64,
+    /// Third lag time constant `T11`.
+    pub t11: f64,
+}
+
+impl Default for PSS2B {
+    fn default() -> Self {
+        PSS2B {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            mode1: 1, // Rotor speed deviation
+            busr1: 0,
+            mode2: 3, // Generator electrical power
+            busr2: 0,
+            m: 5,
+            n: 1,
+            tw1: 2.0, // Typical range: 1 to 10 seconds
+            tw2: 2.0,
+            t6: 0.0,
+            tw3: 2.0,
+            tw4: 0.0,
+            t7: 2.0,  // Usually equal to Tw2
+            ks2: 0.2, // Usually T7 / (2 H)
+            ks3: 1.0,
+            t8: 0.5,
+            t9: 0.1,
+            ks1: 10.0, // Typical range: 5 to 30
+            t1: 0.15,  // Typical range: 0.1 to 0.3 seconds
+            t2: 0.03,  // Typical range: 0.02 to 0.05 seconds
+            t3: 0.15,
+            t4: 0.03,
+            vstmax: 0.1,  // Typical range: 0.05 to 0.2 pu
+            vstmin: -0.1, // Typical range: -0.2 to -0.05 pu
+            vs1max: 2.0,
+            vs1min: -2.0,
+            vs2max: 2.0,
+            vs2min: -2.0,
+            t10: 0.0, // 0 to disable the third lead/lag
+            t11: 0.0,
+        }
+    }
+}
+
+/// Speed Sensitive Stabilizing Model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct STAB1 {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Gain over washout time constant `K/T`.
+    pub k_t: f64,
+    /// Washout time constant `T`.
+    pub t: f64,
+
+    /// First lead/lag time constant ratio `T1/T3`.
+    pub t1_t3: f64,
+    /// First lag time constant `T3`.
+    pub t3: f64,
+
+    /// Second lead/lag time constant ratio `T2/T4`.
+    pub t2_t4: f64,
+    /// Second lag time constant `T4`.
+    pub t4: f64,
+
+    /// Output limit `Hlim`.
+    pub hlim: f64,
+}
+
+impl Default for STAB1 {
+    fn default() -> Self {
+        STAB1 {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            k_t: 5.0,   // Typical range: 1 to 20
+            t: 2.0,     // Typical range: 1 to 10 seconds
+            t1_t3: 5.0, // Lead compensation
+            t3: 0.03,   // Typical range: 0.02 to 0.05 seconds
+            t2_t4: 5.0,
+            t4: 0.03,
+            hlim: 0.1, // Typical range: 0.05 to 0.2 pu
+        }
+    }
+}
@@ -0,0 +1,666 @@
+//! WECC second generation models of inverter-based resources, named as in
+//! the WECC model specifications.
+
+#![allow(non_camel_case_types)]
+
+use arrayvec::ArrayString;
+
+use crate::BusNum;
+
+/// Renewable Energy Generator/Converter Model, WECC second generation
+/// (`REGCA1` in PSS/E).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct REGC_A {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Low voltage power logic switch, 1 to enable or 0 to disable.
+    pub lvplsw: i32,
+
+    /// Converter time constant `Tg`.
+    pub tg: f64,
+
+    /// Active current up-ramp rate limit on voltage recovery `Rrpwr`.
+    pub rrpwr: f64,
+
+    /// Low voltage power logic breakpoint `Brkpt`.
+    pub brkpt: f64,
+    /// Low voltage power logic zero crossing `Zerox`.
+    pub zerox: f64,
+    /// Low voltage power logic active current limit `Lvpl1` at `Brkpt`.
+    pub lvpl1: f64,
+
+    /// Voltage limit for the high voltage reactive current management `Volim`.
+    pub volim: f64,
+
+    /// High voltage point for the low voltage active current management `Lvpnt1`.
+    pub lvpnt1: f64,
+    /// Low voltage point for the low voltage active current management `Lvpnt0`.
+    pub lvpnt0: f64,
+
+    /// Current limit for the high voltage reactive current management `Iolim`.
+    pub iolim: f64,
+
+    /// Voltage filter time constant `Tfltr` for the low voltage active current
+    /// management.
+    pub tfltr: f64,
+
+    /// Overvoltage compensation gain `Khv`.
+    pub khv: f64,
+
+    /// Maximum rate of change of reactive current `Iqrmax`.
+    pub iqrmax: f64,
+    /// Minimum rate of change of reactive current `Iqrmin`.
+    pub iqrmin: f64,
+
+    /// Acceleration factor `Accel`.
+    pub accel: f64,
+}
+
+impl Default for REGC_A {
+    fn default() -> Self {
+        REGC_A {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            lvplsw: 1,
+            tg: 0.02,     // Typical range: 0.017 to 0.05 seconds
+            rrpwr: 10.0,  // Typical range: 1 to 20 pu/sec
+            brkpt: 0.9,   // Typical value: 0.9 pu
+            zerox: 0.4,   // Typical value: 0.4 pu
+            lvpl1: 1.22,  // Typical value: 1.1 to 1.3 pu
+            volim: 1.2,   // Typical value: 1.2 pu
+            lvpnt1: 0.8,  // Typical value: 0.8 pu
+            lvpnt0: 0.4,  // Typical value: 0.4 pu
+            iolim: -1.3,  // Typical range: -1.5 to -1.0 pu
+            tfltr: 0.02,  // Typical value: 0.02 seconds
+            khv: 0.7,     // Typical value: 0.7
+            iqrmax: 99.0, // Often not limited
+            iqrmin: -99.0,
+            accel: 0.7, // Typical value: 0.7
+        }
+    }
+}
+
+/// Renewable Energy Electrical Control Model for wind and large-scale solar
+/// plants, WECC second generation (`REECA1` in PSS/E).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct REEC_A {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Bus of the regulated voltage, 0 for the terminals of the machine.
+    pub ireg: BusNum,
+
+    /// Power factor control flag, 1 for power factor or 0 for reactive power
+    /// control.
+    pub pfflag: i32,
+
+    /// Voltage control flag, 1 for voltage control or 0 for reactive power
+    /// control of the inner loop.
+    pub vflag: i32,
+
+    /// Reactive power control flag, 1 for voltage or reactive power control
+    /// or 0 for a constant power factor or reactive power.
+    pub qflag: i32,
+
+    /// Active power flag, 1 to multiply the active power reference by speed.
+    pub pflag: i32,
+
+    /// Current priority on limiting, 0 for reactive or 1 for active current.
+    pub pqflag: i32,
+
+    /// Low voltage threshold `Vdip` to activate the reactive current injection
+    /// logic.
+    pub vdip: f64,
+    /// High voltage threshold `Vup` to activate the reactive current injection
+    /// logic.
+    pub vup: f64,
+
+    /// Voltage filter time constant `Trv`.
+    pub trv: f64,
+
+    /// Lower voltage error deadband `dbd1`, negative.
+    pub dbd1: f64,
+    /// Upper voltage error deadband `dbd2`.
+    pub dbd2: f64,
+
+    /// Reactive current injection gain `Kqv` during a voltage dip or rise.
+    pub kqv: f64,
+
+    /// Maximum reactive current injection `Iqh1`.
+    pub iqh1: f64,
+    /// Minimum reactive current injection `Iql1`.
+    pub iql1: f64,
+
+    /// Reference voltage `Vref0` for the reactive current injection, 0 for the
+    /// initial voltage.
+    pub vref0: f64,
+
+    /// Reactive current injected after a voltage dip `Iqfrz`.
+    pub iqfrz: f64,
+
+    /// Time `Thld` for which `Iqfrz` is held after a voltage dip, negative to
+    /// hold the state of the reactive current instead.
+    pub thld: f64,
+    /// Time `Thld2` for which the active current limit is held after a
+    /// voltage dip.
+    pub thld2: f64,
+
+    /// Electrical power filter time constant `Tp`.
+    pub tp: f64,
+
+    /// Maximum reactive power `Qmax`.
+    pub qmax: f64,
+    /// Minimum reactive power `Qmin`.
+    pub qmin: f64,
+
+    /// Maximum voltage at the terminals `Vmax`.
+    pub vmax: f64,
+    /// Minimum voltage at the terminals `Vmin`.
+    pub vmin: f64,
+
+    /// Reactive power regulator proportional gain `Kqp`.
+    pub kqp: f64,
+    /// Reactive power regulator integral gain `Kqi`.
+    pub kqi: f64,
+
+    /// Voltage regulator proportional gain `Kvp`.
+    pub kvp: f64,
+    /// Voltage regulator integral gain `Kvi`.
+    pub kvi: f64,
+
+    /// User-defined bias `Vbias`, normally 1.
+    pub vbias: f64,
+
+    /// Reactive current regulator time constant `Tiq`.
+    pub tiq: f64,
+
+    /// Maximum rate of increase of the active power reference `dPmax`.
+    pub dpmax: f64,
+    /// Maximum rate of decrease of the active power reference `dPmin`.
+    pub dpmin: f64,
+
+    /// Maximum active power `Pmax`.
+    pub pmax: f64,
+    /// Minimum active power `Pmin`.
+    pub pmin: f64,
+
+    /// Maximum apparent current `Imax`.
+    pub imax: f64,
+
+    /// Active power order time constant `Tpord`.
+    pub tpord: f64,
+
+    /// Voltage `Vq1` of the first point of the reactive current limit.
+    pub vq1: f64,
+    /// Reactive current limit `Iq1` at `Vq1`.
+    pub iq1: f64,
+    /// Voltage `Vq2` of the second point of the reactive current limit.
+    pub vq2: f64,
+    /// Reactive current limit `Iq2` at `Vq2`.
+    pub iq2: f64,
+    /// Voltage `Vq3` of the third point of the reactive current limit.
+    pub vq3: f64,
+    /// Reactive current limit `Iq3` at `Vq3`.
+    pub iq3: f64,
+    /// Voltage `Vq4` of the fourth point of the reactive current limit.
+    pub vq4: f64,
+    /// Reactive current limit `Iq4` at `Vq4`.
+    pub iq4: f64,
+
+    /// Voltage `Vp1` of the first point of the active current limit.
+    pub vp1: f64,
+    /// Active current limit `Ip1` at `Vp1`.
+    pub ip1: f64,
+    /// Voltage `Vp2` of the second point of the active current limit.
+    pub vp2: f64,
+    /// Active current limit `Ip2` at `Vp2`.
+    pub ip2: f64,
+    /// Voltage `Vp3` of the third point of the active current limit.
+    pub vp3: f64,
+    /// Active current limit `Ip3` at `Vp3`.
+    pub ip3: f64,
+    /// Voltage `Vp4` of the fourth point of the active current limit.
+    pub vp4: f64,
+    /// Active current limit `Ip4` at `Vp4`.
+    pub ip4: f64,
+}
+
+impl Default for REEC_A {
+    fn default() -> Self {
+        REEC_A {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            ireg: 0,
+            pfflag: 0,
+            vflag: 0,
+            qflag: 0,
+            pflag: 0,
+            pqflag: 0,   // Reactive current priority
+            vdip: 0.9,   // Typical value: 0.9 pu
+            vup: 1.1,    // Typical value: 1.1 pu
+            trv: 0.02,   // Typical range: 0.01 to 0.02 seconds
+            dbd1: -0.05, // Typical range: -0.1 to 0
+            dbd2: 0.05,  // Typical range: 0 to 0.1
+            kqv: 2.0,    // Typical range: 0 to 10
+            iqh1: 1.05,  // Typical range: 1 to 1.1 pu
+            iql1: -1.05, // Typical range: -1.1 to -1 pu
+            vref0: 0.0,  // Initial voltage
+            iqfrz: 0.0,
+            thld: 0.0,
+            thld2: 0.0,
+            tp: 0.05,    // Typical range: 0.01 to 0.1 seconds
+            qmax: 0.436, // Typical value: 0.436 pu (0.9 power factor)
+            qmin: -0.436,
+            vmax: 1.1, // Typical value: 1.1 pu
+            vmin: 0.9, // Typical value: 0.9 pu
+            kqp: 0.0,
+            kqi: 0.1,
+            kvp: 0.0,
+            kvi: 40.0,
+            vbias: 1.0,
+            tiq: 0.02,   // Typical range: 0.01 to 0.02 seconds
+            dpmax: 99.0, // Often not limited
+            dpmin: -99.0,
+            pmax: 1.0,   // Typical value: 1.0 pu
+            pmin: 0.0,   // Typical value: 0.0 pu
+            imax: 1.82,  // Typical range: 1.1 to 1.82 pu
+            tpord: 0.02, // Typical range: 0.01 to 0.02 seconds
+            vq1: 0.2,
+            iq1: 0.75,
+            vq2: 0.5,
+            iq2: 0.75,
+            vq3: 0.9,
+            iq3: 1.1,
+            vq4: 1.1,
+            iq4: 1.1,
+            vp1: 0.2,
+            ip1: 0.75,
+            vp2: 0.5,
+            ip2: 0.75,
+            vp3: 0.9,
+            ip3: 1.1,
+            vp4: 1.1,
+            ip4: 1.1,
+        }
+    }
+}
+
+/// Renewable Energy Electrical Control Model for small-scale solar plants,
+/// WECC second generation (`REECB1` in PSS/E).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct REEC_B {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Bus of the regulated voltage, 0 for the terminals of the machine.
+    pub ireg: BusNum,
+
+    /// Power factor control flag, 1 for power factor or 0 for reactive power
+    /// control.
+    pub pfflag: i32,
+
+    /// Voltage control flag, 1 for voltage control or 0 for reactive power
+    /// control of the inner loop.
+    pub vflag: i32,
+
+    /// Reactive power control flag, 1 for voltage or reactive power control
+    /// or 0 for a constant power factor or reactive power.
+    pub qflag: i32,
+
+    /// Current priority on limiting, 0 for reactive or 1 for active current.
+    pub pqflag: i32,
+
+    /// Low voltage threshold `Vdip` to activate the reactive current injection
+    /// logic.
+    pub vdip: f64,
+    /// High voltage threshold `Vup` to activate the reactive current injection
+    /// logic.
+    pub vup: f64,
+
+    /// Voltage filter time constant `Trv`.
+    pub trv: f64,
+
+    /// Lower voltage error deadband `dbd1`, negative.
+    pub dbd1: f64,
+    /// Upper voltage error deadband `dbd2`.
+    pub dbd2: f64,
+
+    /// Reactive current injection gain `Kqv` during a voltage dip or rise.
+    pub kqv: f64,
+
+    /// Maximum reactive current injection `Iqh1`.
+    pub iqh1: f64,
+    /// Minimum reactive current injection `Iql1`.
+    pub iql1: f64,
+
+    /// Reference voltage `Vref0` for the reactive current injection, 0 for the
+    /// initial voltage.
+    pub vref0: f64,
+
+    /// Electrical power filter time constant `Tp`.
+    pub tp: f64,
+
+    /// Maximum reactive power `Qmax`.
+    pub qmax: f64,
+    /// Minimum reactive power `Qmin`.
+    pub qmin: f64,
+
+    /// Maximum voltage at the terminals `Vmax`.
+    pub vmax: f64,
+    /// Minimum voltage at the terminals `Vmin`.
+    pub vmin: f64,
+
+    /// Reactive power regulator proportional gain `Kqp`.
+    pub kqp: f64,
+    /// Reactive power regulator integral gain `Kqi`.
+    pub kqi: f64,
+
+    /// Voltage regulator proportional gain `Kvp`.
+    pub kvp: f64,
+    /// Voltage regulator integral gain `Kvi`.
+    pub kvi: f64,
+
+    /// Reactive current regulator time constant `Tiq`.
+    pub tiq: f64,
+
+    /// Maximum rate of increase of the active power reference `dPmax`.
+    pub dpmax: f64,
+    /// Maximum rate of decrease of the active power reference `dPmin`.
+    pub dpmin: f64,
+
+    /// Maximum active power `Pmax`.
+    pub pmax: f64,
+    /// Minimum active power `Pmin`.
+    pub pmin: f64,
+
+    /// Maximum apparent current `Imax`.
+    pub imax: f64,
+
+    /// Active power order time constant `Tpord`.
+    pub tpord: f64,
+}
+
+impl Default for REEC_B {
+    fn default() -> Self {
+        REEC_B {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            ireg: 0,
+            pfflag: 0,
+            vflag: 0,
+            qflag: 0,
+            pqflag: 0,   // Reactive current priority
+            vdip: 0.9,   // Typical value: 0.9 pu
+            vup: 1.1,    // Typical value: 1.1 pu
+            trv: 0.02,   // Typical range: 0.01 to 0.02 seconds
+            dbd1: -0.05, // Typical range: -0.1 to 0
+            dbd2: 0.05,  // Typical range: 0 to 0.1
+            kqv: 2.0,    // Typical range: 0 to 10
+            iqh1: 1.05,  // Typical range: 1 to 1.1 pu
+            iql1: -1.05, // Typical range: -1.1 to -1 pu
+            vref0: 0.0,  // Initial voltage
+            tp: 0.05,    // Typical range: 0.01 to 0.1 seconds
+            qmax: 0.436, // Typical value: 0.436 pu (0.9 power factor)
+            qmin: -0.436,
+            vmax: 1.1, // Typical value: 1.1 pu
+            vmin: 0.9, // Typical value: 0.9 pu
+            kqp: 0.0,
+            kqi: 0.1,
+            kvp: 0.0,
+            kvi: 40.0,
+            tiq: 0.02,   // Typical range: 0.01 to 0.02 seconds
+            dpmax: 99.0, // Often not limited
+            dpmin: -99.0,
+            pmax: 1.0,   // Typical value: 1.0 pu
+            pmin: 0.0,   // Typical value: 0.0 pu
+            imax: 1.82,  // Typical range: 1.1 to 1.82 pu
+            tpord: 0.02, // Typical range: 0.01 to 0.02 seconds
+        }
+    }
+}
+
+/// Renewable Energy Plant Controller Model, WECC second generation (`REPCA1`
+/// in PSS/E).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct REPC_A {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Bus of the regulated voltage, 0 for the terminals of the machine.
+    pub ireg: BusNum,
+
+    /// From bus of the branch whose flow is monitored, 0 for the terminals of
+    /// the machine.
+    pub from_bus: BusNum,
+    /// To bus of the branch whose flow is monitored.
+    pub to_bus: BusNum,
+    /// Circuit identifier of the branch whose flow is monitored.
+    pub ckt: ArrayString<3>,
+
+    /// Voltage control flag, 1 for line drop compensation or 0 for droop.
+    pub vcflag: i32,
+
+    /// Reference flag, 1 for voltage control or 0 for reactive power control.
+    pub refflag: i32,
+
+    /// Frequency control flag, 1 to enable or 0 to disable.
+    pub fflag: i32,
+
+    /// Voltage or reactive power filter time constant `Tfltr`.
+    pub tfltr: f64,
+
+    /// Reactive power regulator proportional gain `Kp`.
+    pub kp: f64,
+    /// Reactive power regulator integral gain `Ki`.
+    pub ki: f64,
+
+    /// Plant controller lead time constant `Tft`.
+    pub tft: f64,
+    /// Plant controller lag time constant `Tfv`.
+    pub tfv: f64,
+
+    /// Voltage below which the reactive power regulator is frozen `Vfrz`.
+    pub vfrz: f64,
+
+    /// Line drop compensation resistance `Rc`.
+    pub rc: f64,
+    /// Line drop compensation reactance `Xc`.
+    pub xc: f64,
+
+    /// Reactive current compensation gain `Kc`.
+    pub kc: f64,
+
+    /// Maximum voltage or reactive power error `Emax`.
+    pub emax: f64,
+    /// Minimum voltage or reactive power error `Emin`.
+    pub emin: f64,
+
+    /// Lower voltage or reactive power deadband `dbd1`, negative.
+    pub dbd1: f64,
+    /// Upper voltage or reactive power deadband `dbd2`.
+    pub dbd2: f64,
+
+    /// Maximum plant reactive power command `Qmax`.
+    pub qmax: f64,
+    /// Minimum plant reactive power command `Qmin`.
+    pub qmin: f64,
+
+    /// Active power regulator proportional gain `Kpg`.
+    pub kpg: f64,
+    /// Active power regulator integral gain `Kig`.
+    pub kig: f64,
+
+    /// Active power filter time constant `Tp`.
+    pub tp: f64,
+
+    /// Lower frequency deadband `fdbd1`, negative.
+    pub fdbd1: f64,
+    /// Upper frequency deadband `fdbd2`.
+    pub fdbd2: f64,
+
+    /// Maximum frequency error `femax`.
+    pub femax: f64,
+    /// Minimum frequency error `femin`.
+    pub femin: f64,
+
+    /// Maximum plant active power command `Pmax`.
+    pub pmax: f64,
+    /// Minimum plant active power command `Pmin`.
+    pub pmin: f64,
+
+    /// Plant controller active power output lag time constant `Tlag`.
+    pub tlag: f64,
+
+    /// Down regulation droop `Ddn`.
+    pub ddn: f64,
+    /// Up regulation droop `Dup`.
+    pub dup: f64,
+}
+
+impl Default for REPC_A {
+    fn default() -> Self {
+        REPC_A {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            ireg: 0,
+            from_bus: 0,
+            to_bus: 0,
+            ckt: Default::default(),
+            vcflag: 0,  // Droop
+            refflag: 1, // Voltage control
+            fflag: 0,
+            tfltr: 0.02, // Typical range: 0.01 to 0.02 seconds
+            kp: 18.0,    // Typical range: 0 to 20
+            ki: 5.0,     // Typical range: 0 to 20
+            tft: 0.0,
+            tfv: 0.075, // Typical range: 0.075 to 0.3 seconds
+            vfrz: 0.0,  // Typical range: 0 to 0.9 pu
+            rc: 0.0,
+            xc: 0.0,
+            kc: 0.02,  // Typical range: 0 to 0.05
+            emax: 0.1, // Typical value: 0.1 pu
+            emin: -0.1,
+            dbd1: 0.0,
+            dbd2: 0.0,
+            qmax: 0.436, // Typical value: 0.436 pu (0.9 power factor)
+            qmin: -0.436,
+            kpg: 0.1,
+            kig: 0.05,
+            tp: 0.25,       // Typical range: 0.1 to 0.5 seconds
+            fdbd1: -0.0006, // Typical value: -0.036 Hz on 60 Hz
+            fdbd2: 0.0006,
+            femax: 999.0, // Often not limited
+            femin: -999.0,
+            pmax: 1.0, // Typical value: 1.0 pu
+            pmin: 0.0, // Typical value: 0.0 pu
+            tlag: 0.1, // Typical range: 0.1 to 0.5 seconds
+            ddn: 20.0, // Typical value: 20 (5% droop)
+            dup: 0.0,  // No up regulation
+        }
+    }
+}
+
+/// Drive Train Model for type 3 and type 4 wind turbines, WECC second
+/// generation (`WTDTA1` in PSS/E).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WTGT_A {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Total inertia constant `H`.
+    pub h: f64,
+
+    /// Machine damping factor `DAMP`.
+    pub damp: f64,
+
+    /// Turbine fraction `Htfrac` of the total inertia.
+    pub htfrac: f64,
+
+    /// First shaft torsional resonant frequency `Freq1` in Hz.
+    pub freq1: f64,
+
+    /// Shaft damping factor `Dshaft`.
+    pub dshaft: f64,
+}
+
+impl Default for WTGT_A {
+    fn default() -> Self {
+        WTGT_A {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            h: 3.0,      // Typical range: 2 to 6 seconds
+            damp: 0.0,   // Often 0
+            htfrac: 0.9, // Typical range: 0.8 to 0.95
+            freq1: 1.8,  // Typical range: 1 to 3 Hz
+            dshaft: 1.5, // Typical range: 1 to 3
+        }
+    }
+}
+
+/// Simple Aerodynamic Model for type 3 wind turbines, WECC second generation
+/// (`WTARA1` in PSS/E).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WTGAR_A {
+    /// Buses number.
+    pub i: BusNum,
+
+    /// Machine identifier.
+    pub id: ArrayString<3>,
+
+    /// Model status is either one for in-service or zero for out-of-service.
+    pub stat: i8,
+
+    /// Aerodynamic gain factor `Ka`.
+    pub ka: f64,
+
+    /// Initial pitch angle `Theta0` in degrees.
+    pub theta0: f64,
+}
+
+impl Default for WTGAR_A {
+    fn default() -> Self {
+        WTGAR_A {
+            i: Default::default(),
+            id: Default::default(),
+            stat: 1,
+            ka: 0.007,   // Typical value: 0.007 pu/degree
+            theta0: 0.0, // Typical value: 0 degrees
+        }
+    }
+}
@@ -0,0 +1,446 @@
+use arrayvec::ArrayString;
+
+use crate::dyr::exc::{ESAC1A, ESDC1A, ESST1A, EXST1, IEEET1, SCRX, SEXS};
+use crate::dyr::gov::{DEGOV1, GAST, GGOV1, HYGOV, HYGOV4, IEEEG1, TGOV1, WSIEG1};
+use crate::dyr::load::{SinglePhaseMotor, CLOD, CMLD};
+use crate::dyr::pss::{IEEEST, PSS2A, PSS2B, ST2CUT, STAB1};
+use crate::dyr::ren::{REEC_A, REEC_B, REGC_A, REPC_A, WTGAR_A, WTGT_A};
+use crate::dyr::sym::{GENCLS, GENROU, GENSAL, GENTPJ};
+use crate::dyr::{link_generators, parse_dyr, write_dyr, GeneratorModels, GenericModel, Record};
+use crate::parsing::{parse_raw_case, ParseOptions};
+use crate::parsing_tests::CASE_V33;
+
+pub(crate) const DYR: &str = "/ Dynamic data for the test case
+  101 'GENROU' 1   7.0000  0.30000E-01  0.70000  0.50000E-01   4.0000  0.0000
+       1.8000   1.7000  0.30000  0.55000  0.25000  0.15000  0.10000  0.30000  /
+  101 'SEXS' 1  0.10000   10.000   100.00  0.50000E-01 -5.0000   5.0000  /  exciter
+  152 'GENCLS' '1 ' 3.5 0.0/
+  152, 'REGCB1', '1', 1, 0.02, 10.0, 0.9,
+       -1.3, 'ABC' /
+";
+
+#[test]
+fn test_parse_dyr() {
+    let records = parse_dyr(DYR).unwrap();
+    assert_eq!(records.len(), 4);
+
+    let Record::GENROU(genrou) = &records[0] else {
+        panic!("expected GENROU, got {:?}", records[0]);
+    };
+    assert_eq!(
+        *genrou,
+        GENROU {
+            i: 101,
+            id: ArrayString::from("1").unwrap(),
+            stat: 1,
+            tdo_p: 7.0,
+            tdo_pp: 0.03,
+            tqo_p: 0.7,
+            tqo_pp: 0.05,
+            h: 4.0,
+            d: 0.0,
+            xd: 1.8,
+            xq: 1.7,
+            xd_p: 0.3,
+            xq_p: 0.55,
+            xd_pp: 0.25,
+            xl: 0.15,
+            s1_0: 0.1,
+            s1_2: 0.3,
+        }
+    );
+    assert!(matches!(&records[1], Record::SEXS(sexs) if sexs.emin == -5.0 && sexs.k == 100.0));
+    assert_eq!(
+        records[2],
+        Record::GENCLS(GENCLS {
+            i: 152,
+            id: ArrayString::from("1").unwrap(),
+            stat: 1,
+            h: 3.5,
+            d: 0.0,
+        })
+    );
+    assert_eq!(
+        records[3],
+        Record::Generic(GenericModel {
+            i: 152,
+            model: "REGCB1".to_string(),
+            id: "1".to_string(),
+            cons: ["1", "0.02", "10.0", "0.9", "-1.3", "'ABC'"]
+                .map(str::to_string)
+                .to_vec(),
+        })
+    );
+}
+
+#[test]
+fn test_parse_dyr_error() {
+    let dyr = DYR.replace("   100.00  0.50000E-01", "  0.50000E-01");
+    let err = parse_dyr(&dyr).unwrap_err();
+    assert_eq!((err.line, err.column), (4, 7));
+    assert_eq!(err.field, Some("cons"));
+    assert_eq!(err.text, "'SEXS'");
+
+    let dyr = DYR.replace("4.0000  0.0000", "4.0000  0.0x00");
+    let err = parse_dyr(&dyr).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 72));
+    assert_eq!(err.text, "0.0x00");
+
+    let dyr = DYR.replace("3.5 0.0/", "3.5 0.0");
+    let err = parse_dyr(&dyr).unwrap_err();
+    assert_eq!(err.field, Some("cons"));
+    assert_eq!(err.line, 6);
+
+    let dyr = DYR.replace("'GENCLS' '1 '", "'GENCLS' 'G1A2'");
+    let err = parse_dyr(&dyr).unwrap_err();
+    assert_eq!((err.line, err.column), (5, 16));
+    assert_eq!(err.field, Some("id"));
+}
+
+#[test]
+fn test_link_generators() {
+    let network = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+
+    let records = parse_dyr(DYR).unwrap();
+    let links = link_generators(&records, &network);
+    assert_eq!(
+        links,
+        GeneratorModels {
+            linked: vec![(0, 0), (1, 0), (2, 1), (3, 1)],
+            without_machine: vec![],
+            without_generator: vec![],
+        }
+    );
+
+    // The GENCLS is moved to a bus without a generator, and the converter
+    // model is replaced by an electrical control model, which is not a machine.
+    let dyr = DYR
+        .replace("152 'GENCLS'", "153 'GENCLS'")
+        .replace("'REGCB1'", "'REECC1'")
+        + "  201 'TGOV1' 1 0.05 0.5 1.05 0.3 1.0 2.0 0.0 /\n"
+        + "  151 'CLODBL' 1 30 40 2 10 5 2 0 0 /\n";
+    let records = parse_dyr(&dyr).unwrap();
+    assert_eq!(records[0].id(), "1");
+    assert_eq!(records[4].bus(), 201);
+    let links = link_generators(&records, &network);
+    assert_eq!(
+        links,
+        GeneratorModels {
+            linked: vec![(0, 0), (1, 0), (3, 1)],
+            without_machine: vec![1],
+            without_generator: vec![2, 4],
+        }
+    );
+}
+
+#[test]
+fn test_parse_dyr_machines_and_exciters() {
+    let dyr = "\
+  101 'GENSAL' 1  5.0 0.05 0.1 3.0 0.0 1.0 0.6 0.3 0.2 0.15 0.1 0.3 /
+  102 'GENTPJU1' 1  7.0 0.03 0.7 0.05 4.0 0.0 1.8 1.7 0.3 0.55 0.25 0.15 0.1 0.3 0.02 /
+  101 'IEEET1' 1  0.0 50.0 0.04 1.0 -1.0 1.0 0.8 0.03 1.0 0 2.8 0.04 3.73 0.33 /
+  102 'ESST1A' 1  1 2 0.0 999.0 -999.0 1.0 10.0 0.0 0.0 200.0 0.02 999.0 -999.0
+      7.0 -6.0 0.04 0.0 1.0 0.0 0.0 /
+  103 'ESAC1A' 1  0.0 0.0 0.0 400.0 0.02 14.5 -14.5 0.8 0.03 1.0 0.2 0.38 1.0
+      4.18 0.1 3.13 0.03 6.03 -5.43 /
+  104 'ESDC1A' 1  0.0 46.0 0.06 0.0 0.0 1.0 -0.9 0.0 0.46 0.1 1.0 0 3.1 0.33 2.3 0.1 /
+  105 'EXST1' 1  0.02 0.2 -0.2 1.0 10.0 200.0 0.02 5.0 -4.0 0.05 0.0 1.0 /
+  106 'SCRX' 1  0.1 10.0 200.0 0.05 -5.0 5.0 1 10.0 /
+";
+    let records = parse_dyr(dyr).unwrap();
+    let id = ArrayString::from("1").unwrap();
+
+    assert_eq!(
+        records[0],
+        Record::GENSAL(GENSAL {
+            i: 101,
+            id,
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        records[1],
+        Record::GENTPJ(GENTPJ {
+            i: 102,
+            id,
+            kis: 0.02,
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        records[2],
+        Record::IEEET1(IEEET1 {
+            i: 101,
+            id,
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        records[3],
+        Record::ESST1A(ESST1A {
+            i: 102,
+            id,
+            vos: 2,
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        records[4],
+        Record::ESAC1A(ESAC1A {
+            i: 103,
+            id,
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        records[5],
+        Record::ESDC1A(ESDC1A {
+            i: 104,
+            id,
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        records[6],
+        Record::EXST1(EXST1 {
+            i: 105,
+            id,
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        records[7],
+        Record::SCRX(SCRX {
+            i: 106,
+            id,
+            cswitch: 1,
+            ..Default::default()
+        })
+    );
+    assert!(records[..2].iter().all(Record::is_machine_model));
+    assert!(!records[2..].iter().any(Record::is_machine_model));
+}
+
+#[test]
+fn test_parse_dyr_governors_and_stabilizers() {
+    let dyr = "\
+  101 'IEEEG1' 1  0 0 20.0 0.0 0.0 0.1 0.5 -0.5 1.0 0.0 0.3 0.3 0.0 7.0 0.4 0.0 0.5 0.3
+      0.0 0.0 0.0 0.1 /
+  102 'WSIEG1' 1  0 0 20.0 0.0 0.0 0.1 0.5 -0.5 1.0 0.0 0.3 0.3 0.0 7.0 0.4 0.0 0.5 0.3
+      0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 0.0 3 /
+  103 'HYGOV4' 1  0 0 0 0.05 0.3 5.0 0.05 0.5 0.2 -0.2 1.0 0.0 1.0 1.2 0.5 1.0 0.08
+      0.0 0.0 0.1 0.08 0.3 0.3 0.5 0.55 0.7 0.8 1.0 0.95 /
+  104 'DEGOV1' 1  0 0.2 0.3 0.5 20.0 1.0 0.1 0.2 0.02 1.1 0.0 0.05 0.1 /
+  105 'GGOV1' 1  1 1 0.04 1.0 0.05 -0.05 10.0 2.0 0.0 1.0 1.0 0.15 0.5 1.5 0.2 0.1 0.0 0.0
+      3.0 2.0 0.67 1.0 0.0 0.1 -0.1 0.0 0.01 10.0 0.1 0.0 0.0 4.0 5.0 99.0 -0.5 /
+  101 'PSS2A' 1  1 0 3 0 5 1 2.0 2.0 0.0 2.0 0.0 2.0 0.2 1.0 0.5 0.1 10.0 0.15 0.03 0.15
+      0.03 0.1 -0.05 /
+  102 'PSS2B' 1  1 0 3 0 5 1 2.0 2.0 0.0 2.0 0.0 2.0 0.2 1.0 0.5 0.1 10.0 0.15 0.03 0.15
+      0.03 0.1 -0.1 2.0 -2.0 2.0 -2.0 0.0 0.02 /
+  103 'STAB1' 1  5.0 2.0 5.0 0.03 5.0 0.03 0.2 /
+";
+    let records = parse_dyr(dyr).unwrap();
+    let id = ArrayString::from("1").unwrap();
+
+    assert_eq!(
+        records,
+        vec![
+            Record::IEEEG1(IEEEG1 {
+                i: 101,
+                id,
+                k8: 0.1,
+                ..Default::default()
+            }),
+            Record::WSIEG1(WSIEG1 {
+                i: 102,
+                id,
+                iblock: 3,
+                ..Default::default()
+            }),
+            Record::HYGOV4(HYGOV4 {
+                i: 103,
+                id,
+                pgv5: 0.95,
+                ..Default::default()
+            }),
+            Record::DEGOV1(DEGOV1 {
+                i: 104,
+                id,
+                te: 0.1,
+                ..Default::default()
+            }),
+            Record::GGOV1(GGOV1 {
+                i: 105,
+                id,
+                rdown: -0.5,
+                ..Default::default()
+            }),
+            Record::PSS2A(PSS2A {
+                i: 101,
+                id,
+                vstmin: -0.05,
+                ..Default::default()
+            }),
+            Record::PSS2B(PSS2B {
+                i: 102,
+                id,
+                t11: 0.02,
+                ..Default::default()
+            }),
+            Record::STAB1(STAB1 {
+                i: 103,
+                id,
+                hlim: 0.2,
+                ..Default::default()
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_dyr_wecc_models() {
+    let dyr = "\
+  101 'REGCA1' 1  1 0.02 10.0 0.9 0.4 1.22 1.2 0.8 0.4 -1.3 0.02 0.7 99.0 -99.0 0.7 /
+  101 'REECA1' 1  0 0 0 0 0 0 0.9 1.1 0.02 -0.05 0.05 2.0 1.05 -1.05 0.0 0.0 0.0 0.0 0.05 0.436 -0.436 1.1
+      0.9 0.0 0.1 0.0 40.0 1.0 0.02 99.0 -99.0 1.0 0.0 1.82 0.02 0.2 0.75 0.5 0.75 0.9
+      1.1 1.1 1.1 0.2 0.75 0.5 0.75 0.9 1.1 1.1 1.2 /
+  101 'REPCA1' 1  0 151 152 '1 ' 0 1 0 0.02 18.0 5.0 0.0 0.075 0.0 0.0 0.0 0.02 0.1 -0.1
+      0.0 0.0 0.436 -0.436 0.1 0.05 0.25 -0.0006 0.0006 999.0 -999.0 1.0 0.0 0.1 20.0 0.0 /
+  101 'WTDTA1' 1  3.0 0.0 0.9 1.8 1.5 /
+  101 'WTARA1' 1  0.007 0.0 /
+  152 'REECB1' 1  0 0 0 0 0 0.9 1.1 0.02 -0.05 0.05 2.0 1.05 -1.05 0.0 0.05 0.436 -0.436 1.1 0.9 0.0 0.1
+      0.0 40.0 0.02 99.0 -99.0 1.0 0.0 1.82 0.03 /
+  152 'CLODBL' '*'  30 40 2 10 5 2 0 0 /
+  152 'CMLD' 1  -1.25 0.0 0.04 0.04 0.75 0.08 1.0 1.0 1 0.9 1.1 0.00625 1.025 1.04 30.0 5.0 0.0 0.0 0.15
+      0.15 0.1 0.2 0.1 1.0 0.7 0.5 0.8 0.95 2.0 0.5 1.0 0.5 0.0 2.0 0.5 1.0 0.5 -1.0 3 3
+      3 1 0.75 0.04 1.8 0.12 0.104 0.095 0.0021 0.05 0.0 0.7 0.02 0.2 1.0 99999.0 0.5
+      0.02 0.7 0.7 0.1 0.75 0.04 1.8 0.12 0.104 0.095 0.0021 1.0 2.0 0.7 0.02 0.2 1.0
+      99999.0 0.5 0.02 0.7 0.7 0.1 0.75 0.04 1.8 0.12 0.104 0.095 0.0021 0.1 2.0 0.7 0.02
+      0.2 1.0 99999.0 0.5 0.02 0.7 0.7 0.1 1.0 0.98 0.6 0.1 0.1 0.033 0.2 0.95 0.3 0.1
+      0.6 0.02 0.0 9999.0 0.5 0.4 0.6 0.5 15.0 0.7 1.9 0.03 /
+";
+    let records = parse_dyr(dyr).unwrap();
+    let id = ArrayString::from("1").unwrap();
+
+    assert_eq!(
+        records,
+        vec![
+            Record::REGC_A(REGC_A {
+                i: 101,
+                id,
+                ..Default::default()
+            }),
+            Record::REEC_A(REEC_A {
+                i: 101,
+                id,
+                ip4: 1.2,
+                ..Default::default()
+            }),
+            Record::REPC_A(REPC_A {
+                i: 101,
+                id,
+                from_bus: 151,
+                to_bus: 152,
+                ckt: ArrayString::from("1").unwrap(),
+                ..Default::default()
+            }),
+            Record::WTGT_A(WTGT_A {
+                i: 101,
+                id,
+                ..Default::default()
+            }),
+            Record::WTGAR_A(WTGAR_A {
+                i: 101,
+                id,
+                ..Default::default()
+            }),
+            Record::REEC_B(REEC_B {
+                i: 152,
+                id,
+                tpord: 0.03,
+                ..Default::default()
+            }),
+            Record::CLOD(CLOD {
+                i: 152,
+                id: ArrayString::from("*").unwrap(),
+                ..Default::default()
+            }),
+            Record::CMLD(Box::new(CMLD {
+                i: 152,
+                id,
+                motor_d: SinglePhaseMotor {
+                    tv: 0.03,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })),
+        ]
+    );
+    assert!(records[0].is_machine_model());
+    assert!(!records[6].is_generator_model() && !records[7].is_generator_model());
+
+    let dyr = dyr.replace("'1 ' 0 1 0", "'1 ' 'X' 1 0");
+    let err = parse_dyr(&dyr).unwrap_err();
+    assert_eq!(err.field, Some("cons"));
+    assert_eq!(err.text, "'X'");
+}
+
+fn round_trip(records: &[Record]) {
+    let mut dyr = Vec::new();
+    write_dyr(records, &mut dyr).unwrap();
+    let dyr = String::from_utf8(dyr).unwrap();
+
+    let written = parse_dyr(&dyr).unwrap();
+    assert_eq!(written, records, "{}", dyr);
+}
+
+#[test]
+fn test_write_dyr() {
+    round_trip(&parse_dyr(DYR).unwrap());
+
+    let sexs = SEXS {
+        i: 101,
+        id: ArrayString::from("1").unwrap(),
+        k: 200.0,
+        ..Default::default()
+    };
+    let mut dyr = Vec::new();
+    write_dyr(&[Record::SEXS(sexs)], &mut dyr).unwrap();
+    assert_eq!(
+        String::from_utf8(dyr).unwrap(),
+        "   101 'SEXS' '1 ' 0.1 10.0 200.0 0.05 -5.0 5.0 /\n"
+    );
+
+    // Constants that are not finite would not read back.
+    let sexs = SEXS {
+        k: f64::NAN,
+        ..Default::default()
+    };
+    let err = write_dyr(&[Record::SEXS(sexs)], Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_write_dyr_models() {
+    let id = ArrayString::from("1").unwrap();
+    macro_rules! defaults {
+        ($($model:ident),*) => {
+            vec![$(Record::$model($model {
+                i: 101,
+                id,
+                ..Default::default()
+            })),*]
+        };
+    }
+    let mut records = defaults!(
+        GENCLS, GENROU, GENSAL, GENTPJ, SEXS, IEEET1, ESST1A, ESAC1A, ESDC1A, EXST1, SCRX, TGOV1,
+        GAST, HYGOV, IEEEG1, GGOV1, HYGOV4, WSIEG1, DEGOV1, IEEEST, ST2CUT, PSS2A, PSS2B, STAB1,
+        REGC_A, REEC_A, REEC_B, REPC_A, WTGT_A, WTGAR_A, CLOD
+    );
+    records.push(Record::CMLD(Box::new(CMLD {
+        i: 152,
+        id,
+        ..Default::default()
+    })));
+    round_trip(&records);
+}
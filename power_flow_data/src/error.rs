@@ -0,0 +1,274 @@
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+use std::fmt;
+use std::io;
+
+/// Result type of the RAW record parsers.
+pub(crate) type IResult<I, O> = nom::IResult<I, O, RawError<I>>;
+
+/// Error type of the RAW record parsers: the input at which parsing failed,
+/// along with the section and field being read.
+#[derive(Debug, PartialEq)]
+pub(crate) struct RawError<I> {
+    pub(crate) input: I,
+    pub(crate) kind: ErrorKind,
+    pub(crate) error: ParseErrorKind,
+    pub(crate) section: Option<&'static str>,
+    pub(crate) field: Option<&'static str>,
+}
+
+impl<I> RawError<I> {
+    pub(crate) fn new(input: I, kind: ErrorKind) -> Self {
+        Self {
+            input,
+            kind,
+            error: ParseErrorKind::Syntax,
+            section: None,
+            field: None,
+        }
+    }
+}
+
+impl<I> nom::error::ParseError<I> for RawError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self::new(input, kind)
+    }
+
+    // The innermost error gives the location.
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+// Fields are named with `nom::error::context`. The innermost name is kept.
+impl<I> ContextError<I> for RawError<I> {
+    fn add_context(_input: I, ctx: &'static str, mut other: Self) -> Self {
+        other.field.get_or_insert(ctx);
+        other
+    }
+}
+
+impl<I, E> FromExternalError<I, E> for RawError<I> {
+    fn from_external_error(input: I, kind: ErrorKind, _e: E) -> Self {
+        Self::new(input, kind)
+    }
+}
+
+/// The kinds of [ParseError].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The text is not a valid record.
+    Syntax,
+
+    /// An extended bus name matches no bus.
+    UnknownBus,
+
+    /// An extended bus name matches more than one bus.
+    AmbiguousBus,
+
+    /// A bus number is used by an earlier bus.
+    DuplicateBus,
+
+    /// A record refers to equipment that is not in the case.
+    UnknownEquipment,
+
+    /// The input could not be read.
+    Io,
+}
+
+/// An error reading a RAW case, located in the input.
+#[derive(Debug)]
+pub struct ParseError {
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+
+    /// Line number of the offending text, from 1.
+    pub line: usize,
+
+    /// Column number of the offending text, in characters from 1.
+    pub column: usize,
+
+    /// The data section being read, e.g. `"GENERATOR DATA"`.
+    pub section: Option<&'static str>,
+
+    /// The record field being read, when known.
+    pub field: Option<&'static str>,
+
+    /// The offending text, up to the end of the field.
+    pub text: String,
+
+    // Set when the case could not be read at all, with `line` and `column` of zero.
+    source: Option<io::Error>,
+}
+
+impl ParseError {
+    // Locates the error in `input`, the whole case.
+    pub(crate) fn new(input: &str, err: RawError<&str>) -> Self {
+        let offset = input.len() - err.input.len();
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |k| k + 1);
+
+        // The offending field: a quoted string, or text up to a separator.
+        let field = err.input.trim_start_matches([' ', '\t']);
+        let text = match field.chars().next() {
+            Some(quote @ ('\'' | '"')) => match field[1..].find(quote) {
+                Some(end) => &field[..end + 2],
+                None => field.lines().next().unwrap_or_default(),
+            },
+            _ => field
+                .split([',', ' ', '\t', '\r', '\n'])
+                .next()
+                .unwrap_or_default(),
+        };
+
+        Self {
+            kind: err.error,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            section: err.section,
+            field: err.field,
+            text: text.to_string(),
+            source: None,
+        }
+    }
+
+    pub(crate) fn from_nom(input: &str, err: nom::Err<RawError<&str>>) -> Self {
+        match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => Self::new(input, err),
+            nom::Err::Incomplete(_) => {
+                Self::new(input, RawError::new(&input[input.len()..], ErrorKind::Eof))
+            }
+        }
+    }
+
+    pub(crate) fn io(path: &str, err: io::Error) -> Self {
+        Self {
+            kind: ParseErrorKind::Io,
+            line: 0,
+            column: 0,
+            section: None,
+            field: None,
+            text: path.to_string(),
+            source: Some(err),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(err) = &self.source {
+            return write!(f, "cannot read {}: {}", self.text, err);
+        }
+
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            Problem(self)
+        )
+    }
+}
+
+// What is wrong, without the location.
+struct Problem<'e>(&'e ParseError);
+
+impl fmt::Display for Problem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let err = self.0;
+        let what = match err.kind {
+            ParseErrorKind::UnknownBus => "unknown bus name for",
+            ParseErrorKind::AmbiguousBus => "ambiguous bus name for",
+            ParseErrorKind::DuplicateBus => "duplicate bus",
+            ParseErrorKind::UnknownEquipment => "unknown equipment for",
+            ParseErrorKind::Syntax | ParseErrorKind::Io => "invalid",
+        };
+        match err.field {
+            Some(field) => write!(f, "{} `{}`", what, field)?,
+            None if err.kind == ParseErrorKind::UnknownEquipment => {
+                write!(f, "no equipment in the case for record")?
+            }
+            None => write!(f, "invalid record")?,
+        }
+        if let Some(section) = err.section {
+            write!(f, " in {}", section)?;
+        }
+        if err.text.is_empty() {
+            write!(f, " at end of line")
+        } else {
+            write!(f, " at \"{}\"", err.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|err| err as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// How serious a [Diagnostic] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The record was kept, with fields defaulted or ignored.
+    Warning,
+
+    /// The record was skipped.
+    Error,
+}
+
+/// A defect in a case read in lenient mode, and what was done about it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    /// Whether the record was kept.
+    pub severity: Severity,
+
+    /// Line number of the record, from 1.
+    pub line: usize,
+
+    /// The data section of the record, e.g. `"BUS DATA"`.
+    pub section: Option<&'static str>,
+
+    /// What was wrong and what was done about it.
+    pub message: String,
+}
+
+impl Diagnostic {
+    // Locates the text at `at` in `input`, the whole case.
+    pub(crate) fn new(
+        input: &str,
+        at: &str,
+        severity: Severity,
+        section: &'static str,
+        message: String,
+    ) -> Self {
+        let offset = input.len() - at.len();
+        Self {
+            severity,
+            line: input[..offset].matches('\n').count() + 1,
+            section: Some(section),
+            message,
+        }
+    }
+
+    // A record skipped for `err`.
+    pub(crate) fn skipped(err: &ParseError) -> Self {
+        Self {
+            severity: Severity::Error,
+            line: err.line,
+            section: err.section,
+            message: format!("{}; record skipped", Problem(err)),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "line {}: {}: {}", self.line, severity, self.message)
+    }
+}
@@ -1,5 +1,32 @@
+//! PSS/E GIC data (`.gic`): the substation grounding, transformer winding and
+//! earth resistivity data of a RAW case, for geomagnetically induced current
+//! studies.
+
+use std::io::{self, Write};
+
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::{char, space0};
+use nom::combinator::{cut, opt};
+use nom::error::context;
+use nom::sequence::{preceded, tuple};
+
+use crate::error::{IResult, ParseError};
+use crate::parsing::{
+    at_end_of_case, parse_data_headers, parse_end_of_line, parse_int, parse_q_line, parse_section,
+    parse_sep, skip_section,
+};
+use crate::traits::{RawField, RawRecord};
+use crate::writing::{Line, Sections};
 use crate::BusNum;
 
+mod earth;
+mod solve;
+
+pub use earth::{substation_fields, GeomagneticField, SpectralLine};
+pub use solve::{solve_gic, solve_gic_fields, GICSolution, GeoelectricField, TransformerGIC};
+
+/// Location and grounding resistance of a substation.
+#[derive(Clone, Debug, PartialEq, RawRecord)]
 pub struct Substation {
     pub i: i32,
     pub name: String,
@@ -10,16 +37,39 @@ pub struct Substation {
     pub lng: f64,
     /// Ohms
     pub rg: f64,
+    /// The name of the earth model under the substation, one of
+    /// [GIC::earth_models].
+    pub earthmdl: Option<String>,
+}
+
+// PSS/E takes a grounding resistance of 0.1 ohm when none is given.
+impl Default for Substation {
+    fn default() -> Self {
+        Self {
+            i: 0,
+            name: String::new(),
+            _n: 0,
+            lat: 0.0,
+            lng: 0.0,
+            rg: 0.1,
+            earthmdl: None,
+        }
+    }
 }
 
+/// The substation of a bus.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
 pub struct BusSubstation {
     pub i: i32,
     pub sub: i32,
 }
 
+/// DC winding and grounding data of a two- or three-winding transformer.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
 pub struct Transformer {
     pub i: i32,
     pub j: i32,
+    /// 0 for a two-winding transformer.
     pub k: i32,
     pub ckt: String,
     /// Ohm/ph
@@ -44,6 +94,8 @@ pub struct Transformer {
     pub tmodel: i32,
 }
 
+/// DC resistance of a fixed shunt.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
 pub struct FixedShunt {
     pub i: BusNum,
     pub id: String,
@@ -53,18 +105,27 @@ pub struct FixedShunt {
     pub rgrdfxsh: f64,
 }
 
+/// DC resistance of a non-transformer branch, with any induced voltage given
+/// in place of the one computed from the geoelectric field.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
 pub struct Branch {
     pub busi: BusNum,
     pub busj: BusNum,
     pub ckt: String,
     /// Ohm/ph
     pub rbrn: f64,
-    /// Volts
+    /// The voltage induced by a northward field of 1 V/km, in place of the
+    /// one computed from the substation locations when it or `indvq` is
+    /// nonzero. Volts
     pub indvp: f64,
-    /// Volts
+    /// The voltage induced by an eastward field of 1 V/km. Volts
     pub indvq: f64,
 }
 
+/// A user-defined layered earth model: the resistivity of each layer, from
+/// the surface down, and the thickness of all but the last, which extends
+/// down indefinitely. `r` has one more value than `t`.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct EarthModel {
     pub name: String,
     /// Beta factor.
@@ -76,6 +137,8 @@ pub struct EarthModel {
     pub t: Vec<f64>,
 }
 
+/// The GIC data of a case.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct GIC {
     pub substations: Vec<Substation>,
     pub bus_substations: Vec<BusSubstation>,
@@ -84,3 +147,184 @@ pub struct GIC {
     pub branches: Vec<Branch>,
     pub earth_models: Vec<EarthModel>,
 }
+
+/// Parses PSS/E GIC data.
+///
+/// The file may start with a `GICFILEVRSN=` record. It is followed by the
+/// substation, bus substation, transformer, fixed shunt, branch and user
+/// earth model sections, each ended by a `0 / END OF ...` record. Any
+/// sections after these are skipped.
+///
+/// Each earth model is a record of its name, beta factor and description,
+/// followed by one line per layer of its resistivity and thickness. The line
+/// of the last layer gives only its resistivity, and ends the model.
+///
+/// A record that cannot be read is reported with its line and column in `input`.
+pub fn parse_gic(input: &str) -> Result<GIC, ParseError> {
+    let (_, gic) = parse_gic_sections(input).map_err(|err| ParseError::from_nom(input, err))?;
+    Ok(gic)
+}
+
+fn parse_gic_sections(input: &str) -> IResult<&str, GIC> {
+    let (input, _) = parse_data_headers(input)?;
+    let (input, _) = opt(parse_version)(input)?;
+    let (input, substations) = parse_section(None, "SUBSTATION DATA", record)(input)?;
+    let (input, bus_substations) = parse_section(None, "BUS SUBSTATION DATA", record)(input)?;
+    let (input, transformers) = parse_section(None, "TRANSFORMER DATA", record)(input)?;
+    let (input, fixed_shunts) = parse_section(None, "FIXED SHUNT DATA", record)(input)?;
+    let (input, branches) = parse_section(None, "BRANCH DATA", record)(input)?;
+    let (input, earth_models) =
+        parse_section(None, "USER EARTH MODEL DATA", parse_earth_model)(input)?;
+
+    let mut input = input;
+    while !at_end_of_case(input) {
+        (input, _) = skip_section(input)?;
+    }
+    let (input, _) = opt(parse_q_line)(input)?;
+
+    let gic = GIC {
+        substations,
+        bus_substations,
+        transformers,
+        fixed_shunts,
+        branches,
+        earth_models,
+    };
+
+    Ok((input, gic))
+}
+
+// GICFILEVRSN=3
+fn parse_version(input: &str) -> IResult<&str, usize> {
+    let (input, _) = tuple((
+        space0,
+        tag_no_case("GICFILEVRSN"),
+        space0,
+        char('='),
+        space0,
+    ))(input)?;
+    let (input, version) = cut(parse_int)(input)?;
+    let (input, _) = parse_end_of_line(input)?;
+    Ok((input, version))
+}
+
+fn record<T: RawRecord>(input: &str) -> IResult<&str, T> {
+    T::parse_raw(input, 33)
+}
+
+// 'USGS_SE', 1.0, 'Southeast'
+// 100.0, 15.0
+// 1000.0
+fn parse_earth_model(input: &str) -> IResult<&str, EarthModel> {
+    let (input, _) = space0(input)?;
+    let (input, name) = context("name", String::parse_field)(input)?;
+    let (input, b) = context("b", preceded(parse_sep, f64::parse_field))(input)?;
+    let (input, desc) = opt(preceded(parse_sep, String::parse_field))(input)?;
+
+    let mut model = EarthModel {
+        name,
+        b,
+        desc: desc.unwrap_or_default(),
+        ..Default::default()
+    };
+    // The end of the line of the last layer is left to end the record.
+    let mut input = input;
+    loop {
+        let (rest, r) = preceded(
+            tuple((parse_end_of_line, space0)),
+            context("r", f64::parse_field),
+        )(input)?;
+        let (rest, t) = opt(preceded(parse_sep, f64::parse_field))(rest)?;
+        model.r.push(r);
+        input = rest;
+        match t {
+            Some(t) => model.t.push(t),
+            None => return Ok((input, model)),
+        }
+    }
+}
+
+/// Writes GIC data in the layout read by [parse_gic], as a version 3 file.
+pub fn write_gic(gic: &GIC, mut w: impl Write) -> io::Result<()> {
+    let w = &mut w;
+
+    writeln!(w, "GICFILEVRSN=3")?;
+
+    let mut sections = Sections::new(w);
+    sections.section("SUBSTATION", &gic.substations, write_gic_substation)?;
+    sections.section("BUS SUBSTATION", &gic.bus_substations, |w, bs| {
+        Line::default().field(bs.i).field(bs.sub).write(w)
+    })?;
+    sections.section("TRANSFORMER", &gic.transformers, write_gic_transformer)?;
+    sections.section("FIXED SHUNT", &gic.fixed_shunts, |w, shunt| {
+        let mut line = Line::default();
+        line.field(shunt.i)
+            .field(shunt.id.clone())
+            .field(shunt.rfxsh)
+            .field(shunt.rgrdfxsh);
+        line.write(w)
+    })?;
+    sections.section("BRANCH", &gic.branches, |w, branch| {
+        let mut line = Line::default();
+        line.field(branch.busi)
+            .field(branch.busj)
+            .field(branch.ckt.clone())
+            .field(branch.rbrn)
+            .field(branch.indvp)
+            .field(branch.indvq);
+        line.write(w)
+    })?;
+    sections.section("USER EARTH MODEL", &gic.earth_models, write_gic_earth_model)?;
+    sections.end()
+}
+
+fn write_gic_substation<W: Write>(w: &mut W, substation: &Substation) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(substation.i)
+        .field(substation.name.clone())
+        .field(substation._n)
+        .field(substation.lat)
+        .field(substation.lng)
+        .field(substation.rg)
+        .opt(substation.earthmdl.clone(), String::new());
+    line.write(w)
+}
+
+fn write_gic_transformer<W: Write>(w: &mut W, t: &Transformer) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(t.i)
+        .field(t.j)
+        .field(t.k)
+        .field(t.ckt.clone())
+        .field(t.wri)
+        .field(t.wrj)
+        .field(t.wrk)
+        .field(t.gicbdi)
+        .field(t.gicbdj)
+        .field(t.gicbdk)
+        .field(t.vecgrp.clone())
+        .field(t.core)
+        .field(t.kfactor)
+        .field(t.grdri)
+        .field(t.grdrj)
+        .field(t.grdrk)
+        .field(t.tmodel);
+    line.write(w)
+}
+
+fn write_gic_earth_model<W: Write>(w: &mut W, model: &EarthModel) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(model.name.clone())
+        .field(model.b)
+        .field(model.desc.clone());
+    line.write(w)?;
+    for (k, &r) in model.r.iter().enumerate() {
+        let mut line = Line::default();
+        line.field(r);
+        if let Some(&t) = model.t.get(k) {
+            line.field(t);
+        }
+        line.write(w)?;
+    }
+    Ok(())
+}
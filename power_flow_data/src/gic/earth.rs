@@ -0,0 +1,203 @@
+//! The plane-wave response of layered earth models: the surface impedance of
+//! an [EarthModel], and the geoelectric field that a geomagnetic disturbance
+//! induces over it.
+
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+use num_complex::Complex64;
+
+use super::{EarthModel, GeoelectricField, GIC};
+
+// Permeability of free space (H/m).
+const MU0: f64 = 4e-7 * PI;
+
+// Converts the product of a surface impedance (ohm) and a magnetic flux
+// density (nT) to an electric field (V/km).
+const V_PER_KM: f64 = 1e-6 / MU0;
+
+/// A geomagnetic disturbance, by the northward and eastward components of its
+/// magnetic flux density.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeomagneticField {
+    /// Samples every `dt` seconds. nT
+    TimeSeries { bn: Vec<f64>, be: Vec<f64>, dt: f64 },
+    /// Sinusoidal components, as of a benchmark spectrum, at each of `times`
+    /// in seconds.
+    Spectrum {
+        lines: Vec<SpectralLine>,
+        times: Vec<f64>,
+    },
+}
+
+/// A sinusoidal component of a geomagnetic field.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SpectralLine {
+    /// Hz
+    pub frequency: f64,
+    /// The amplitude and phase, at time zero, of the northward component. nT
+    pub bn: Complex64,
+    /// The amplitude and phase, at time zero, of the eastward component. nT
+    pub be: Complex64,
+}
+
+impl EarthModel {
+    /// The plane-wave surface impedance of the model at `frequency` in Hz, in
+    /// ohms: the ratio of the electric field to the magnetic field intensity
+    /// at the surface.
+    ///
+    /// The impedance of the half-space under the layers is carried up through
+    /// each layer in turn, from the bottom, as its reflection coefficient.
+    pub fn surface_impedance(&self, frequency: f64) -> Complex64 {
+        let Some((&bottom, layers)) = self.r.split_last() else {
+            return Complex64::default();
+        };
+        let iwu = Complex64::new(0.0, 2.0 * PI * frequency * MU0);
+        // The intrinsic impedance of a uniform earth of resistivity `r`.
+        let intrinsic = |r: f64| (iwu * r).sqrt();
+
+        let mut z = intrinsic(bottom);
+        for (k, &r) in layers.iter().enumerate().rev() {
+            let zn = intrinsic(r);
+            let depth = self.t.get(k).copied().unwrap_or_default() * 1e3;
+            // The decay of the field over the layer and back, exp(-2 k d).
+            let decay = (-2.0 * (iwu / r).sqrt() * depth).exp();
+            let reflection = (zn - z) / (zn + z);
+            z = zn * (1.0 - reflection * decay) / (1.0 + reflection * decay);
+        }
+        z
+    }
+
+    /// The northward and eastward geoelectric field induced over the model by
+    /// `b`, at each of its samples or times, scaled by the beta factor.
+    ///
+    /// A time series is transformed to the frequency domain with its mean
+    /// taken out, and is padded to at least twice its length, so that the
+    /// field near its ends is less reliable.
+    pub fn geoelectric_field(&self, b: &GeomagneticField) -> Vec<GeoelectricField> {
+        let fields = match b {
+            GeomagneticField::TimeSeries { bn, be, dt } => self.time_series_field(bn, be, *dt),
+            GeomagneticField::Spectrum { lines, times } => self.spectrum_field(lines, times),
+        };
+        fields
+            .into_iter()
+            .map(|(en, ee)| GeoelectricField::from_components(self.b * en, self.b * ee))
+            .collect()
+    }
+
+    // En = Z Be / mu0 and Ee = -Z Bn / mu0 for each frequency of the series.
+    fn time_series_field(&self, bn: &[f64], be: &[f64], dt: f64) -> Vec<(f64, f64)> {
+        let len = bn.len().min(be.len());
+        if len == 0 {
+            return Vec::new();
+        }
+        let n = (2 * len).next_power_of_two();
+        let transform = |b: &[f64]| {
+            let mean = b[..len].iter().sum::<f64>() / len as f64;
+            let mut x = vec![Complex64::default(); n];
+            for (x, &b) in x.iter_mut().zip(&b[..len]) {
+                *x = Complex64::from(b - mean);
+            }
+            fft(&mut x, false);
+            x
+        };
+        let (bn, be) = (transform(bn), transform(be));
+
+        let mut en = vec![Complex64::default(); n];
+        let mut ee = vec![Complex64::default(); n];
+        for k in 1..=n / 2 {
+            let z = self.surface_impedance(k as f64 / (n as f64 * dt)) * V_PER_KM;
+            en[k] = z * be[k];
+            ee[k] = -z * bn[k];
+            // The negative frequencies of a real field.
+            if k < n / 2 {
+                en[n - k] = z.conj() * be[n - k];
+                ee[n - k] = -z.conj() * bn[n - k];
+            }
+        }
+        fft(&mut en, true);
+        fft(&mut ee, true);
+
+        (0..len)
+            .map(|k| (en[k].re / n as f64, ee[k].re / n as f64))
+            .collect()
+    }
+
+    fn spectrum_field(&self, lines: &[SpectralLine], times: &[f64]) -> Vec<(f64, f64)> {
+        let lines: Vec<(f64, Complex64, Complex64)> = lines
+            .iter()
+            .map(|line| {
+                let z = self.surface_impedance(line.frequency) * V_PER_KM;
+                (2.0 * PI * line.frequency, z * line.be, -z * line.bn)
+            })
+            .collect();
+        times
+            .iter()
+            .map(|&t| {
+                lines.iter().fold((0.0, 0.0), |(en, ee), &(w, zbe, zbn)| {
+                    let phase = Complex64::from_polar(1.0, w * t);
+                    (en + (zbe * phase).re, ee + (zbn * phase).re)
+                })
+            })
+            .collect()
+    }
+}
+
+/// The geoelectric field that `b` induces at each substation, over the earth
+/// model named by its [super::Substation::earthmdl], as in
+/// [EarthModel::geoelectric_field].
+///
+/// Substations with no earth model, or one that is not in [GIC::earth_models],
+/// are left out.
+pub fn substation_fields(gic: &GIC, b: &GeomagneticField) -> HashMap<i32, Vec<GeoelectricField>> {
+    let mut models: HashMap<&str, Vec<GeoelectricField>> = HashMap::new();
+    let mut fields = HashMap::new();
+    for s in &gic.substations {
+        let Some(name) = s.earthmdl.as_deref().map(str::trim) else {
+            continue;
+        };
+        let Some(model) = gic.earth_models.iter().find(|m| m.name.trim() == name) else {
+            continue;
+        };
+        let field = models
+            .entry(name)
+            .or_insert_with(|| model.geoelectric_field(b));
+        fields.insert(s.i, field.clone());
+    }
+    fields
+}
+
+// An in-place radix-2 FFT of `x`, whose length must be a power of two, or the
+// inverse transform, without the 1/n scaling.
+fn fft(x: &mut [Complex64], inverse: bool) {
+    let n = x.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            x.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let w = Complex64::from_polar(1.0, sign * 2.0 * PI / len as f64);
+        for chunk in x.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut wk = Complex64::new(1.0, 0.0);
+            for (a, b) in lo.iter_mut().zip(hi) {
+                let t = *b * wk;
+                *b = *a - t;
+                *a += t;
+                wk *= w;
+            }
+        }
+        len <<= 1;
+    }
+}
@@ -0,0 +1,526 @@
+//! The quasi-DC network of a case, driven by the voltages that a geoelectric
+//! field induces along its lines, and the currents that flow in it.
+
+use std::collections::{HashMap, VecDeque};
+
+use super::{Substation, GIC};
+use crate::{BusNum, Network, Transformer, IN_SERVICE, ISOLATED};
+
+/// A geoelectric field, uniform over the area of a case.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GeoelectricField {
+    /// V/km
+    pub magnitude: f64,
+    /// The direction the field points in, in degrees clockwise from north.
+    pub direction: f64,
+}
+
+impl GeoelectricField {
+    // The field with northward and eastward components `en` and `ee`.
+    pub(crate) fn from_components(en: f64, ee: f64) -> Self {
+        Self {
+            magnitude: en.hypot(ee),
+            direction: ee.atan2(en).to_degrees(),
+        }
+    }
+
+    // The northward and eastward components of the field.
+    pub(crate) fn components(&self) -> (f64, f64) {
+        let theta = self.direction.to_radians();
+        (self.magnitude * theta.cos(), self.magnitude * theta.sin())
+    }
+}
+
+/// The DC voltages and transformer currents of a GIC study.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GICSolution {
+    /// Volts
+    pub bus_voltages: HashMap<BusNum, f64>,
+    /// The voltage of the grounding grid of each substation. Volts
+    pub substation_voltages: HashMap<i32, f64>,
+    /// The currents of each of [GIC::transformers], in the same order.
+    pub transformers: Vec<TransformerGIC>,
+}
+
+/// The GIC in the windings of a transformer.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransformerGIC {
+    /// The current in each winding, flowing from its bus towards the neutral.
+    /// For an autotransformer, the currents in the series and common windings.
+    /// Amps/ph
+    pub windings: [f64; 3],
+    /// The sum of the winding currents, each weighted by the base voltage of
+    /// its bus relative to the highest, as in NERC TPL-007. The series winding
+    /// of an autotransformer is weighted by the difference between the base
+    /// voltages of its two buses. Amps/ph
+    pub effective: f64,
+}
+
+/// Solves the GIC that a uniform geoelectric field drives through the DC
+/// network of `gic` and `network`.
+///
+/// The network is made of:
+///  * the in-service branches of `network`, with the resistance and induced
+///    voltage of a matching [super::Branch] when given. Otherwise the voltage
+///    is that of the field between the substations at the ends of the branch,
+///    over the distances of the NERC GIC application guide. Branches with
+///    negative reactance are taken to be series capacitors, and block DC.
+///    Resistances below 1e-4 ohm/ph, as of jumpers, are raised to it.
+///  * the grounded wye, and autotransformer, windings of each of
+///    [GIC::transformers], as given by its vector group. A blank vector group
+///    is read from the matching transformer of `network` if it has one, and
+///    is otherwise taken to be grounded wye on all windings. Windings with a
+///    GIC blocking device have no path to ground. The winding resistances of
+///    a two-winding transformer default to half its power flow resistance.
+///  * [GIC::fixed_shunts], between their bus and its neutral.
+///  * the grounding resistance of each substation, with which the neutrals of
+///    the windings and shunts at its buses are grounded. A bus with no
+///    substation, like a substation with no grounding resistance, is grounded
+///    directly.
+///
+/// Parts of the network with no path to ground are solved relative to one of
+/// their buses.
+pub fn solve_gic(gic: &GIC, network: &Network, field: GeoelectricField) -> GICSolution {
+    solve(gic, network, |_| field)
+}
+
+/// Solves the GIC driven by a geoelectric field that varies between
+/// substations, as from [super::substation_fields] at one instant, with the
+/// network of [solve_gic].
+///
+/// The voltage induced along a branch is that of the mean of the fields at the
+/// substations at its ends. Substations with no field in `fields` have none.
+pub fn solve_gic_fields(
+    gic: &GIC,
+    network: &Network,
+    fields: &HashMap<i32, GeoelectricField>,
+) -> GICSolution {
+    solve(gic, network, |s| {
+        s.and_then(|s| fields.get(&s.i))
+            .copied()
+            .unwrap_or_default()
+    })
+}
+
+// Solves for a field that may vary between substations, given a substation,
+// or the field to take where there is none.
+fn solve<F>(gic: &GIC, network: &Network, field: F) -> GICSolution
+where
+    F: Fn(Option<&Substation>) -> GeoelectricField,
+{
+    let mut dc = DCNetwork::default();
+
+    let substations: HashMap<i32, &Substation> = gic.substations.iter().map(|s| (s.i, s)).collect();
+    let bus_substations: HashMap<BusNum, &Substation> = gic
+        .bus_substations
+        .iter()
+        .filter_map(|bs| Some((bs.i, *substations.get(&bs.sub)?)))
+        .collect();
+    let basekv: HashMap<BusNum, f64> = network.buses.iter().map(|b| (b.i, b.basekv)).collect();
+    let kv = |i: BusNum| basekv.get(&i).copied().unwrap_or_default();
+
+    let grids: HashMap<i32, Node> = gic
+        .substations
+        .iter()
+        .map(|s| {
+            let grid = (s.rg > 0.0).then(|| dc.node());
+            dc.connect(grid, None, 1.0 / s.rg);
+            (s.i, grid)
+        })
+        .collect();
+    // The grounding grid that the neutrals at a bus are connected to.
+    let neutral = |i: BusNum| bus_substations.get(&i).and_then(|s| grids[&s.i]);
+
+    let buses: HashMap<BusNum, usize> = network
+        .buses
+        .iter()
+        .filter(|b| b.ide != ISOLATED)
+        .map(|b| (b.i, dc.node()))
+        .collect();
+
+    // The mean northward and eastward field along a branch.
+    let branch_field = |i: BusNum, j: BusNum| {
+        let ends: Vec<&Substation> = [i, j]
+            .iter()
+            .filter_map(|b| bus_substations.get(b).copied())
+            .collect();
+        if ends.is_empty() {
+            return field(None).components();
+        }
+        let n = ends.len() as f64;
+        ends.iter()
+            .map(|&s| field(Some(s)).components())
+            .fold((0.0, 0.0), |(en, ee), (dn, de)| (en + dn / n, ee + de / n))
+    };
+
+    let mut overrides = HashMap::new();
+    for b in &gic.branches {
+        overrides.insert((b.busi, b.busj, id(&b.ckt)), (b, 1.0));
+        overrides
+            .entry((b.busj, b.busi, id(&b.ckt)))
+            .or_insert((b, -1.0));
+    }
+    for branch in &network.branches {
+        if branch.st != IN_SERVICE || branch.x < 0.0 {
+            continue;
+        }
+        let (i, j) = (branch.i.abs(), branch.j.abs());
+        let (Some(&a), Some(&b)) = (buses.get(&i), buses.get(&j)) else {
+            continue;
+        };
+        let over = overrides.get(&(i, j, id(&branch.ckt)));
+        let r = match over {
+            Some((o, _)) if o.rbrn > 0.0 => o.rbrn,
+            _ => branch.r * kv(i) * kv(i) / network.caseid.sbase,
+        }
+        .max(MIN_BRANCH_RESISTANCE);
+        let (en, ee) = branch_field(i, j);
+        let v = match over {
+            Some((o, sign)) if o.indvp != 0.0 || o.indvq != 0.0 => {
+                sign * (en * o.indvp + ee * o.indvq)
+            }
+            _ => match (bus_substations.get(&i), bus_substations.get(&j)) {
+                (Some(si), Some(sj)) => {
+                    let (ln, le) = distances(si, sj);
+                    en * ln + ee * le
+                }
+                _ => 0.0,
+            },
+        };
+        dc.source(Some(a), Some(b), 3.0 / r, v);
+    }
+
+    let network_transformers: HashMap<_, _> = network
+        .transformers
+        .iter()
+        .map(|t| ((t.i.abs(), t.j.abs(), t.k.abs(), id(&t.ckt)), t))
+        .collect();
+    let mut paths = Vec::with_capacity(gic.transformers.len());
+    for t in &gic.transformers {
+        let nt = network_transformers
+            .get(&(t.i, t.j, t.k, id(&t.ckt)))
+            .copied();
+        let vecgrp = match nt.and_then(|nt| nt.vecgrp) {
+            Some(vecgrp) if t.vecgrp.trim().is_empty() => vecgrp.to_string(),
+            _ => t.vecgrp.clone(),
+        };
+        let connections = connections(&vecgrp);
+        let r = winding_resistances(t, nt, kv, network.caseid.sbase);
+        let wbus = [t.i, t.j, t.k];
+        let grdr = [t.grdri, t.grdrj, t.grdrk];
+        let gicbd = [t.gicbdi, t.gicbdj, t.gicbdk];
+
+        let mut windings = [None; 3];
+        for w in 0..if t.k == 0 { 2 } else { 3 } {
+            if !in_service(nt, w) {
+                continue;
+            }
+            let Some(&bus) = buses.get(&wbus[w]) else {
+                continue;
+            };
+            windings[w] = match connections[w] {
+                Connection::Grounded | Connection::Common if gicbd[w] == 0 => {
+                    Some((Some(bus), neutral(wbus[w]), r[w] / 3.0 + grdr[w]))
+                }
+                Connection::Series => buses.get(&t.j).map(|&j| (Some(bus), Some(j), r[w] / 3.0)),
+                _ => None,
+            };
+        }
+        for &(a, b, r) in windings.iter().flatten() {
+            dc.connect(a, b, 1.0 / r);
+        }
+        paths.push((windings, wbus, connections));
+    }
+
+    let shunts: HashMap<_, _> = network
+        .fixed_shunts
+        .iter()
+        .map(|s| ((s.i, id(&s.id)), s.status))
+        .collect();
+    for s in &gic.fixed_shunts {
+        let status = shunts.get(&(s.i, id(&s.id))).copied().unwrap_or(IN_SERVICE);
+        if status != IN_SERVICE || s.rfxsh <= 0.0 {
+            continue;
+        }
+        if let Some(&bus) = buses.get(&s.i) {
+            dc.connect(Some(bus), neutral(s.i), 1.0 / (s.rfxsh / 3.0 + s.rgrdfxsh));
+        }
+    }
+
+    let x = dc.solve();
+    let voltage = |node: Node| node.map_or(0.0, |k| x[k]);
+
+    let transformers = paths
+        .into_iter()
+        .map(|(windings, wbus, connections)| {
+            let mut currents = TransformerGIC::default();
+            let winding_kv = |w: usize| if kv(wbus[w]) > 0.0 { kv(wbus[w]) } else { 1.0 };
+            let mut kv_max: f64 = 0.0;
+            let mut weighted = 0.0;
+            for (w, winding) in windings.iter().enumerate() {
+                let Some((a, b, r)) = *winding else {
+                    continue;
+                };
+                if !valid(r) {
+                    continue;
+                }
+                currents.windings[w] = (voltage(a) - voltage(b)) / r / 3.0;
+                let kv = match connections[w] {
+                    Connection::Series => winding_kv(0) - winding_kv(1),
+                    _ => winding_kv(w),
+                };
+                kv_max = kv_max.max(winding_kv(w));
+                weighted += currents.windings[w] * kv;
+            }
+            if kv_max > 0.0 {
+                currents.effective = weighted.abs() / kv_max;
+            }
+            currents
+        })
+        .collect();
+
+    GICSolution {
+        bus_voltages: buses.iter().map(|(&i, &k)| (i, x[k])).collect(),
+        substation_voltages: grids.iter().map(|(&i, &node)| (i, voltage(node))).collect(),
+        transformers,
+    }
+}
+
+// The resistance that branches of less, such as jumpers, are given, so that
+// they join their buses without an infinite conductance. Ohm/ph
+const MIN_BRANCH_RESISTANCE: f64 = 1e-4;
+
+// The northward and eastward distances from substation `a` to `b` in km, as in
+// the NERC GIC application guide.
+fn distances(a: &Substation, b: &Substation) -> (f64, f64) {
+    let phi = ((a.lat + b.lat) / 2.0).to_radians();
+    let ln = (111.133 - 0.56 * (2.0 * phi).cos()) * (b.lat - a.lat);
+    let le = (111.5065 - 0.1872 * (2.0 * phi).cos()) * phi.cos() * (b.lng - a.lng);
+    (ln, le)
+}
+
+// Equipment IDs are compared without padding, e.g. `'1 '` matches `1`.
+fn id(id: &str) -> String {
+    id.trim().to_string()
+}
+
+// Whether winding `w` of a transformer is in service, from the status of the
+// power flow transformer: 2, 3 and 4 take the second, third and first windings
+// out of service.
+fn in_service(nt: Option<&Transformer>, w: usize) -> bool {
+    match nt.map_or(IN_SERVICE, |nt| nt.stat) {
+        0 => false,
+        2 => w != 1,
+        3 => w != 2,
+        4 => w != 0,
+        _ => true,
+    }
+}
+
+// Winding resistances in ohm per phase: those of the GIC data or, when none
+// are given for a two-winding transformer, half its power flow resistance
+// referred to each winding.
+fn winding_resistances(
+    t: &super::Transformer,
+    nt: Option<&Transformer>,
+    kv: impl Fn(BusNum) -> f64,
+    sbase: f64,
+) -> [f64; 3] {
+    let r = [t.wri, t.wrj, t.wrk];
+    let Some(nt) = nt else {
+        return r;
+    };
+    if t.k != 0 || t.wri > 0.0 || t.wrj > 0.0 {
+        return r;
+    }
+    let base = match nt.cz {
+        1 => sbase,
+        2 => nt.sbase1_2,
+        _ => return r,
+    };
+    let (kv_i, kv_j) = (kv(t.i), kv(t.j));
+    let half = nt.r1_2 * kv_i * kv_i / base / 2.0;
+    [half, half * (kv_j / kv_i).powi(2), 0.0]
+}
+
+// The DC path of a winding.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Connection {
+    /// A grounded wye winding, from its bus to the neutral.
+    Grounded,
+    /// A delta or ungrounded wye winding.
+    Blocked,
+    /// The series winding of an autotransformer, between its two buses.
+    Series,
+    /// The common winding of an autotransformer, from its bus to the neutral.
+    Common,
+}
+
+// The connections of the windings from a vector group such as `YNd1`,
+// `YNyn0d1` or, for an autotransformer, `YNa0`.
+fn connections(vecgrp: &str) -> [Connection; 3] {
+    let letters: Vec<char> = vecgrp
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if letters.is_empty() {
+        return [Connection::Grounded; 3];
+    }
+
+    let mut symbols = Vec::new();
+    let mut k = 0;
+    while k < letters.len() {
+        let grounded = matches!(letters[k], 'y' | 'z') && letters.get(k + 1) == Some(&'n');
+        symbols.push(if grounded { 'n' } else { letters[k] });
+        k += if grounded { 2 } else { 1 };
+    }
+
+    let mut connections = [Connection::Blocked; 3];
+    for (w, &symbol) in symbols.iter().take(3).enumerate() {
+        if symbol == 'n' {
+            connections[w] = Connection::Grounded;
+        }
+    }
+    if symbols.get(1) == Some(&'a') {
+        connections[0] = Connection::Series;
+        connections[1] = Connection::Common;
+    }
+    connections
+}
+
+// A node of the DC network, or `None` for remote earth.
+type Node = Option<usize>;
+
+fn valid(r: f64) -> bool {
+    r > 0.0 && r.is_finite()
+}
+
+// The conductances between the nodes of the DC network, in siemens, and the
+// currents injected into them by its voltage sources, in amps.
+#[derive(Default)]
+struct DCNetwork {
+    ground: Vec<f64>,
+    edges: Vec<(usize, usize, f64)>,
+    injections: Vec<f64>,
+}
+
+impl DCNetwork {
+    fn node(&mut self) -> usize {
+        self.ground.push(0.0);
+        self.injections.push(0.0);
+        self.ground.len() - 1
+    }
+
+    // Connects `a` and `b` with conductance `g`. Paths of no or infinite
+    // resistance are left out.
+    fn connect(&mut self, a: Node, b: Node, g: f64) {
+        if !valid(g) {
+            return;
+        }
+        match (a, b) {
+            (Some(a), Some(b)) if a != b => self.edges.push((a, b, g)),
+            (Some(n), None) | (None, Some(n)) => self.ground[n] += g,
+            _ => {}
+        }
+    }
+
+    // Connects `a` and `b` with a voltage source `v`, driving current from `a`
+    // to `b`, in series with conductance `g`.
+    fn source(&mut self, a: Node, b: Node, g: f64, v: f64) {
+        if !valid(g) {
+            return;
+        }
+        self.connect(a, b, g);
+        if let Some(a) = a {
+            self.injections[a] -= g * v;
+        }
+        if let Some(b) = b {
+            self.injections[b] += g * v;
+        }
+    }
+
+    // The node voltages, by the conjugate gradient method with a diagonal
+    // preconditioner.
+    fn solve(&self) -> Vec<f64> {
+        let n = self.ground.len();
+        let mut diag = self.ground.clone();
+        let mut adjacent = vec![Vec::new(); n];
+        for &(a, b, g) in &self.edges {
+            diag[a] += g;
+            diag[b] += g;
+            adjacent[a].push((b, g));
+            adjacent[b].push((a, g));
+        }
+
+        // The first node of each part of the network that has no path to
+        // ground is held at 0 V.
+        let mut pinned = vec![false; n];
+        let mut seen: Vec<bool> = self.ground.iter().map(|&g| g > 0.0).collect();
+        let mut queue: VecDeque<usize> = (0..n).filter(|&k| seen[k]).collect();
+        for start in 0..n {
+            if queue.is_empty() && !seen[start] {
+                seen[start] = true;
+                pinned[start] = true;
+                queue.push_back(start);
+            }
+            while let Some(k) = queue.pop_front() {
+                for &(m, _) in &adjacent[k] {
+                    if !seen[m] {
+                        seen[m] = true;
+                        queue.push_back(m);
+                    }
+                }
+            }
+        }
+
+        let multiply = |x: &[f64]| -> Vec<f64> {
+            (0..n)
+                .map(|k| {
+                    if pinned[k] {
+                        return 0.0;
+                    }
+                    let off: f64 = adjacent[k]
+                        .iter()
+                        .filter(|&&(m, _)| !pinned[m])
+                        .map(|&(m, g)| g * x[m])
+                        .sum();
+                    diag[k] * x[k] - off
+                })
+                .collect()
+        };
+        let precondition = |r: &[f64]| -> Vec<f64> {
+            (0..n)
+                .map(|k| if pinned[k] { 0.0 } else { r[k] / diag[k] })
+                .collect()
+        };
+        let dot = |a: &[f64], b: &[f64]| -> f64 { a.iter().zip(b).map(|(a, b)| a * b).sum() };
+
+        let mut x = vec![0.0; n];
+        let mut r: Vec<f64> = (0..n)
+            .map(|k| if pinned[k] { 0.0 } else { self.injections[k] })
+            .collect();
+        let tolerance = 1e-12 * dot(&r, &r).sqrt();
+        let mut z = precondition(&r);
+        let mut p = z.clone();
+        let mut rz = dot(&r, &z);
+        for _ in 0..2 * n {
+            if dot(&r, &r).sqrt() <= tolerance {
+                break;
+            }
+            let ap = multiply(&p);
+            let alpha = rz / dot(&p, &ap);
+            for k in 0..n {
+                x[k] += alpha * p[k];
+                r[k] -= alpha * ap[k];
+            }
+            z = precondition(&r);
+            let rz_next = dot(&r, &z);
+            for k in 0..n {
+                p[k] = z[k] + rz_next / rz * p[k];
+            }
+            rz = rz_next;
+        }
+        x
+    }
+}
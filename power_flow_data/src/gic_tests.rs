@@ -0,0 +1,407 @@
+use crate::gic::{
+    parse_gic, solve_gic, solve_gic_fields, substation_fields, write_gic, Branch, BusSubstation,
+    EarthModel, GeoelectricField, GeomagneticField, SpectralLine, Substation, Transformer, GIC,
+};
+use crate::{Bus, CaseID, Network};
+use num_complex::Complex64;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+const GIC_V3: &str = "GICFILEVRSN=3
+1,'NUCPANT',0,  40.0000,  -89.0000,   0.1000
+2,'MINE',0,  41.0000,  -88.5000,   0.2000,'SHIELD'
+0 / End of Substation data, Begin Bus Substation data
+  101,1
+  151,1
+  152,2
+0 / End of Bus Substation data, Begin Transformer data
+  101,  151,    0,'T1',  0.0250,  0.0500,  0.0000,0,0,0,'YNd1',1,   1.2,  0.0000,  0.0000,  0.0000,1
+0 / End of Transformer data, Begin Fixed Shunt data
+  151,'1 ',   0.2000,   0.0000
+0 / End of Fixed Shunt data, Begin Branch data
+  151,  152,'1 ',   1.8000,  100.000,   0.000
+0 / End of Branch data, Begin User Earth Model data
+'SHIELD', 1.0, 'Canadian shield'
+  500.0, 15.0
+  150.0, 10.0
+  1000.0
+'MARINE', 0.5
+  5.0
+0 / End of User Earth Model data, Begin Switched Shunt data
+  152, 0.5
+0 / End of Switched Shunt data
+Q
+";
+
+#[test]
+fn test_parse_gic() {
+    let gic = parse_gic(GIC_V3).unwrap();
+
+    assert_eq!(
+        gic.substations[1],
+        Substation {
+            i: 2,
+            name: "MINE".to_string(),
+            _n: 0,
+            lat: 41.0,
+            lng: -88.5,
+            rg: 0.2,
+            earthmdl: Some("SHIELD".to_string()),
+        }
+    );
+    assert_eq!(gic.bus_substations.len(), 3);
+    assert_eq!(gic.bus_substations[2].sub, 2);
+    assert_eq!(
+        gic.transformers[0],
+        Transformer {
+            i: 101,
+            j: 151,
+            ckt: "T1".to_string(),
+            wri: 0.025,
+            wrj: 0.05,
+            vecgrp: "YNd1".to_string(),
+            core: 1,
+            kfactor: 1.2,
+            tmodel: 1,
+            ..Default::default()
+        }
+    );
+    assert_eq!(gic.fixed_shunts[0].rfxsh, 0.2);
+    assert_eq!(
+        gic.branches[0],
+        Branch {
+            busi: 151,
+            busj: 152,
+            ckt: "1 ".to_string(),
+            rbrn: 1.8,
+            indvp: 100.0,
+            indvq: 0.0,
+        }
+    );
+    assert_eq!(
+        gic.earth_models,
+        vec![
+            EarthModel {
+                name: "SHIELD".to_string(),
+                b: 1.0,
+                desc: "Canadian shield".to_string(),
+                r: vec![500.0, 150.0, 1000.0],
+                t: vec![15.0, 10.0],
+            },
+            EarthModel {
+                name: "MARINE".to_string(),
+                b: 0.5,
+                desc: String::new(),
+                r: vec![5.0],
+                t: vec![],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_gic_error() {
+    let gic = GIC_V3.replace("  150.0, 10.0", "  150.0, 10.0, 3.0");
+
+    let err = parse_gic(&gic).unwrap_err();
+    assert_eq!((err.line, err.column), (17, 14));
+    assert_eq!(err.section, Some("USER EARTH MODEL DATA"));
+}
+
+#[test]
+fn test_write_gic() {
+    let gic = parse_gic(GIC_V3).unwrap();
+
+    let mut out = Vec::new();
+    write_gic(&gic, &mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+
+    assert!(text.starts_with("GICFILEVRSN=3\n1,'NUCPANT',0,40.0,-89.0,0.1\n"));
+    assert!(text.contains("0 / END OF BRANCH DATA, BEGIN USER EARTH MODEL DATA\n"));
+    assert!(text.contains("'MARINE',0.5,''\n5.0\n0 / END OF USER EARTH MODEL DATA\nQ\n"));
+    assert_eq!(parse_gic(&text).unwrap(), gic);
+}
+
+// Two substations a degree of latitude apart, each with a grounded wye-delta
+// transformer, joined by a 100 kV line of 3 ohm/ph.
+fn two_substations() -> (GIC, Network) {
+    let substation = |i, lat| Substation {
+        i,
+        lat,
+        lng: -88.0,
+        rg: 0.4,
+        ..Default::default()
+    };
+    let transformer = |i, j| Transformer {
+        i,
+        j,
+        ckt: "1".to_string(),
+        wri: 0.6,
+        vecgrp: "YNd1".to_string(),
+        ..Default::default()
+    };
+    let gic = GIC {
+        substations: vec![substation(1, 40.0), substation(2, 41.0)],
+        bus_substations: [(1, 1), (11, 1), (2, 2), (12, 2)]
+            .iter()
+            .map(|&(i, sub)| BusSubstation { i, sub })
+            .collect(),
+        transformers: vec![transformer(1, 11), transformer(2, 12)],
+        ..Default::default()
+    };
+
+    let bus = |i, basekv| Bus {
+        i,
+        basekv,
+        ..Default::default()
+    };
+    let network = Network {
+        caseid: CaseID {
+            sbase: 100.0,
+            ..Default::default()
+        },
+        buses: vec![bus(1, 100.0), bus(2, 100.0), bus(11, 20.0), bus(12, 20.0)],
+        branches: vec![crate::Branch {
+            i: 1,
+            j: 2,
+            ckt: arrayvec::ArrayString::from("1").unwrap(),
+            r: 0.03,
+            x: 0.3,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    (gic, network)
+}
+
+#[test]
+fn test_solve_gic() {
+    let (gic, network) = two_substations();
+    let north = GeoelectricField {
+        magnitude: 1.0,
+        direction: 0.0,
+    };
+
+    let solution = solve_gic(&gic, &network, north);
+
+    // The line, the two windings and the two grounding grids in series.
+    let phi = 40.5_f64.to_radians();
+    let v = 111.133 - 0.56 * (2.0 * phi).cos();
+    let i = v / (1.0 + 2.0 * (0.2 + 0.4));
+    assert!((solution.transformers[1].windings[0] - i / 3.0).abs() < 1e-9);
+    assert!((solution.transformers[0].windings[0] + i / 3.0).abs() < 1e-9);
+    assert_eq!(solution.transformers[1].windings[1], 0.0);
+    assert!((solution.transformers[0].effective - i / 3.0).abs() < 1e-9);
+    assert!((solution.substation_voltages[&2] - 0.4 * i).abs() < 1e-9);
+    assert!((solution.bus_voltages[&2] - 0.6 * i).abs() < 1e-9);
+
+    // An eastward field induces nothing along a north-south line.
+    let east = GeoelectricField {
+        magnitude: 1.0,
+        direction: 90.0,
+    };
+    let solution = solve_gic(&gic, &network, east);
+    assert!(solution.transformers[1].effective.abs() < 1e-9);
+}
+
+#[test]
+fn test_solve_gic_overrides() {
+    let (mut gic, network) = two_substations();
+    let north = GeoelectricField {
+        magnitude: 2.0,
+        direction: 0.0,
+    };
+
+    // 50 V per V/km northward, given from bus 2 to bus 1, and 1 ohm/ph.
+    gic.branches.push(Branch {
+        busi: 2,
+        busj: 1,
+        ckt: "1 ".to_string(),
+        rbrn: 1.0,
+        indvp: 50.0,
+        indvq: 0.0,
+    });
+    let solution = solve_gic(&gic, &network, north);
+    let i = 100.0 / (1.0 / 3.0 + 2.0 * (0.2 + 0.4));
+    assert!((solution.transformers[0].windings[0] - i / 3.0).abs() < 1e-9);
+
+    // A blocking device in the neutral of one transformer stops the current.
+    gic.transformers[0].gicbdi = 1;
+    let solution = solve_gic(&gic, &network, north);
+    assert_eq!(solution.transformers[0].windings[0], 0.0);
+    assert!(solution.transformers[1].effective.abs() < 1e-9);
+}
+
+#[test]
+fn test_solve_gic_autotransformer() {
+    let (mut gic, network) = two_substations();
+    let north = GeoelectricField {
+        magnitude: 1.0,
+        direction: 0.0,
+    };
+
+    // A 100/20 kV autotransformer, whose series and common windings carry
+    // the same current with nothing else at bus 12.
+    gic.transformers[1] = Transformer {
+        i: 2,
+        j: 12,
+        ckt: "1".to_string(),
+        wri: 0.6,
+        wrj: 0.3,
+        vecgrp: "YNa0".to_string(),
+        ..Default::default()
+    };
+    let solution = solve_gic(&gic, &network, north);
+
+    let phi = 40.5_f64.to_radians();
+    let v = 111.133 - 0.56 * (2.0 * phi).cos();
+    let i = v / (1.0 + 0.2 + 0.4 + 0.2 + 0.1 + 0.4);
+    let auto = &solution.transformers[1];
+    assert!((auto.windings[0] - i / 3.0).abs() < 1e-9);
+    assert!((auto.windings[1] - i / 3.0).abs() < 1e-9);
+    // |((a - 1) Is + Ic) / a| with a = 5.
+    assert!((auto.effective - i / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_solve_gic_jumper() {
+    let (gic, mut network) = two_substations();
+    let north = GeoelectricField {
+        magnitude: 1.0,
+        direction: 0.0,
+    };
+
+    network.branches[0].r = 0.0;
+    let solution = solve_gic(&gic, &network, north);
+    let phi = 40.5_f64.to_radians();
+    let v = 111.133 - 0.56 * (2.0 * phi).cos();
+    let i = v / (2.0 * (0.2 + 0.4));
+    assert!((solution.transformers[1].windings[0] - i / 3.0).abs() < 1e-3);
+    assert!((solution.bus_voltages[&1] - solution.bus_voltages[&2] + v).abs() < 1e-2);
+}
+
+fn half_space(r: f64) -> EarthModel {
+    EarthModel {
+        name: "UNIFORM".to_string(),
+        b: 1.0,
+        r: vec![r],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_surface_impedance() {
+    let mu0 = 4e-7 * PI;
+    let w = 2.0 * PI * 0.01;
+
+    // A uniform earth: |Z| = sqrt(w mu0 r), leading by 45 degrees.
+    let z = half_space(100.0).surface_impedance(0.01);
+    assert!((z.norm() - (w * mu0 * 100.0).sqrt()).abs() < 1e-12);
+    assert!((z.arg() - PI / 4.0).abs() < 1e-12);
+
+    // Layers of the same resistivity make no difference.
+    let layered = EarthModel {
+        r: vec![100.0, 100.0, 100.0],
+        t: vec![10.0, 50.0],
+        ..half_space(100.0)
+    };
+    assert!((layered.surface_impedance(0.01) - z).norm() < 1e-12);
+
+    // A thin resistive layer over a conductive half-space looks like the
+    // half-space, and a thick one like the layer.
+    let over = |t| EarthModel {
+        r: vec![1000.0, 10.0],
+        t: vec![t],
+        ..half_space(10.0)
+    };
+    let conductive = half_space(10.0).surface_impedance(1.0);
+    let resistive = half_space(1000.0).surface_impedance(1.0);
+    assert!((over(1e-4).surface_impedance(1.0) - conductive).norm() < 1e-3 * conductive.norm());
+    assert!((over(1e3).surface_impedance(1.0) - resistive).norm() < 1e-9 * resistive.norm());
+    assert_eq!(
+        EarthModel::default().surface_impedance(1.0),
+        Complex64::default()
+    );
+}
+
+#[test]
+fn test_geoelectric_field() {
+    let model = EarthModel {
+        b: 0.5,
+        ..half_space(100.0)
+    };
+    // 100 nT eastward at 0.01 Hz, for 20 cycles.
+    let f = 0.01;
+    let times: Vec<f64> = (0..2000).map(f64::from).collect();
+    let spectrum = GeomagneticField::Spectrum {
+        lines: vec![SpectralLine {
+            frequency: f,
+            bn: Complex64::default(),
+            be: Complex64::new(100.0, 0.0),
+        }],
+        times: times.clone(),
+    };
+    let fields = model.geoelectric_field(&spectrum);
+
+    // E = b |Z| B / mu0, northward, 45 degrees ahead of B.
+    let mu0 = 4e-7 * PI;
+    let peak = 0.5 * (2.0 * PI * f * mu0 * 100.0).sqrt() * 100e-9 / mu0 * 1e3;
+    assert!((fields[0].magnitude - peak / 2f64.sqrt()).abs() < 1e-9);
+    assert!(fields[0].direction.abs() < 1e-9);
+
+    // The same disturbance as a time series, away from its ends.
+    let series = GeomagneticField::TimeSeries {
+        bn: vec![0.0; times.len()],
+        be: times
+            .iter()
+            .map(|t| 100.0 * (2.0 * PI * f * t).cos())
+            .collect(),
+        dt: 1.0,
+    };
+    let from_series = model.geoelectric_field(&series);
+    assert_eq!(from_series.len(), times.len());
+    for k in [900, 1000, 1110] {
+        let (en, _) = from_series[k].components();
+        let (expected, _) = fields[k].components();
+        assert!(
+            (en - expected).abs() < 0.02 * peak,
+            "{} {} {}",
+            k,
+            en,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_solve_gic_fields() {
+    let (mut gic, network) = two_substations();
+    gic.earth_models.push(half_space(100.0));
+    for s in &mut gic.substations {
+        s.earthmdl = Some("UNIFORM".to_string());
+    }
+    let b = GeomagneticField::Spectrum {
+        lines: vec![SpectralLine {
+            frequency: 0.001,
+            bn: Complex64::new(0.0, -300.0),
+            be: Complex64::new(500.0, 0.0),
+        }],
+        times: vec![0.0],
+    };
+
+    let fields = substation_fields(&gic, &b);
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[&1], fields[&2]);
+
+    let at: HashMap<i32, GeoelectricField> = fields.iter().map(|(&i, f)| (i, f[0])).collect();
+    let regional = solve_gic_fields(&gic, &network, &at);
+    let uniform = solve_gic(&gic, &network, at[&1]);
+    for (a, b) in regional.transformers.iter().zip(&uniform.transformers) {
+        assert!((a.effective - b.effective).abs() < 1e-9);
+    }
+    assert!(uniform.transformers[0].effective > 0.0);
+
+    // Substations without an earth model have no field.
+    gic.substations[1].earthmdl = None;
+    assert_eq!(substation_fields(&gic, &b).len(), 1);
+}
@@ -1,11 +1,27 @@
 pub mod parsing;
+pub mod reader;
 mod types;
+pub mod writing;
 
+#[cfg(test)]
+mod dyr_tests;
+#[cfg(test)]
+mod gic_tests;
 #[cfg(test)]
 mod parsing_tests;
+#[cfg(test)]
+mod reader_tests;
+#[cfg(test)]
+mod seq_tests;
+#[cfg(test)]
+mod writing_tests;
 
+mod bus_names;
 pub mod dyr;
+mod error;
 pub mod gic;
+pub mod seq;
 pub(crate) mod traits;
 
+pub use error::{Diagnostic, ParseError, ParseErrorKind, Severity};
 pub use types::*;
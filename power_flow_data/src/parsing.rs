@@ -1,13 +1,29 @@
 use arrayvec::ArrayString;
-use nom::bytes::complete::{tag, take_until, take_while};
-use nom::character::complete::{char, digit1, newline, space0, space1};
-use nom::combinator::{map, map_res, opt, recognize};
-use nom::multi::separated_list1;
-use nom::sequence::{delimited, pair, preceded, separated_pair, tuple};
-use nom::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_until, take_while, take_while1};
+use nom::character::complete::{
+    char, digit0, digit1, line_ending, not_line_ending, one_of, space0, space1,
+};
+use nom::combinator::{cut, eof, map, map_res, not, opt, peek, recognize, verify};
+use nom::error::ErrorKind;
+use nom::multi::{count, many0, many_m_n};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
 use std::str::FromStr;
 
-use crate::{AreaNum, Bus, BusNum, CaseID, Load, Network, OwnerNum, ZoneNum};
+use crate::bus_names::{is_name_ref, name_at, name_ref, quoted, resolve_bus_names, BusNames};
+use crate::error::{Diagnostic, IResult, ParseError, ParseErrorKind, RawError, Severity};
+use crate::traits::{RawField, RawRecord};
+use crate::{
+    ACConverter, AreaInterchange, AreaNum, Branch, Bus, BusNum, CaseID, DCBus, DCLineID, DCLink,
+    EquipmentTerminal, FACTSDevice, FixedShunt, Generator, ImpedanceCorrection, InterAreaTransfer,
+    Load, MultiSectionLineGroup, MultiTerminalDCLine, Network, Owner, OwnerNum, Substation,
+    SubstationNode, SwitchedShunt, SwitchingDevice, SystemSwitchingDevice, SystemWideRecord,
+    Transformer, TwoTerminalDCLine, VSCDCLine, Zone, ZoneNum, IN_SERVICE,
+};
 
 fn _parse_integer(input: &str) -> IResult<&str, i32> {
     map_res(recognize(pair(opt(char('-')), digit1)), |s: &str| {
@@ -16,34 +32,53 @@ fn _parse_integer(input: &str) -> IResult<&str, i32> {
 }
 
 fn parse_i8(input: &str) -> IResult<&str, i8> {
-    map_res(digit1, |s: &str| s.parse::<i8>())(input)
+    parse_int(input)
 }
 
-fn parse_int<I: FromStr>(input: &str) -> IResult<&str, I> {
-    map_res(digit1, |s: &str| s.parse::<I>())(input)
+// Integers may be signed, e.g. `+1`.
+pub(crate) fn parse_int<I: FromStr>(input: &str) -> IResult<&str, I> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), |s: &str| {
+        s.parse::<I>()
+    })(input)
 }
 
-fn parse_bus_num(input: &str) -> IResult<&str, BusNum> {
-    map_res(digit1, |s: &str| s.parse::<BusNum>())(input)
+// A bus number, or an extended bus name in quotes that is resolved once the
+// bus data has been read. Numbers in the range of the placeholders for names
+// are rejected.
+pub(crate) fn parse_bus_num(input: &str) -> IResult<&str, BusNum> {
+    alt((
+        verify(parse_int, |&i: &BusNum| !is_name_ref(i)),
+        parse_bus_name,
+    ))(input)
 }
 
-fn _parse_metered_bus_num(input: &str) -> IResult<&str, BusNum> {
+fn parse_metered_bus_num(input: &str) -> IResult<&str, BusNum> {
     // Bus number can be negative to indicate the metered end of a branch.
-    map_res(recognize(preceded(opt(char('-')), digit1)), |s: &str| {
-        s.parse::<BusNum>()
-    })(input)
+    parse_bus_num(input)
+}
+
+fn parse_bus_name(input: &str) -> IResult<&str, BusNum> {
+    let start = input;
+    let (input, _) = alt((
+        delimited(char('\''), take_while(|c| c != '\''), char('\'')),
+        delimited(char('"'), take_while(|c| c != '"'), char('"')),
+    ))(input)?;
+    match name_ref(start) {
+        Some(bus) => Ok((input, bus)),
+        None => Err(verify_error(start)),
+    }
 }
 
 fn parse_area_num(input: &str) -> IResult<&str, AreaNum> {
-    map_res(digit1, |s: &str| s.parse::<AreaNum>())(input)
+    parse_int(input)
 }
 
 fn parse_zone_num(input: &str) -> IResult<&str, ZoneNum> {
-    map_res(digit1, |s: &str| s.parse::<ZoneNum>())(input)
+    parse_int(input)
 }
 
 fn parse_owner_num(input: &str) -> IResult<&str, OwnerNum> {
-    map_res(digit1, |s: &str| s.parse::<OwnerNum>())(input)
+    parse_int(input)
 }
 
 fn _parse_usize(input: &str) -> IResult<&str, usize> {
@@ -58,19 +93,20 @@ fn _parse_usize(input: &str) -> IResult<&str, usize> {
 // }
 
 fn parse_i32(input: &str) -> IResult<&str, i32> {
-    // Define a parser that can handle an optional minus sign followed by digits
-    let parse_signed = recognize(preceded(opt(char('-')), digit1));
-
-    // Parse the resulting string into an i32
-    map_res(parse_signed, |s: &str| s.parse::<i32>())(input)
+    parse_int(input)
 }
 
+// Reals may be signed, may have no digits before or after the decimal point,
+// e.g. `.95` or `1.`, and may have an exponent, e.g. `1.5E-3`.
 fn parse_f64(input: &str) -> IResult<&str, f64> {
     map_res(
         recognize(tuple((
-            opt(char('-')),
-            digit1,
-            opt(tuple((char('.'), digit1))),
+            opt(one_of("+-")),
+            alt((
+                recognize(pair(digit1, opt(pair(char('.'), digit0)))),
+                recognize(pair(char('.'), digit1)),
+            )),
+            opt(tuple((one_of("eE"), opt(one_of("+-")), digit1))),
         ))),
         |s: &str| s.parse::<f64>(),
     )(input)
@@ -80,10 +116,6 @@ fn _parse_optional_f64(input: &str) -> IResult<&str, Option<f64>> {
     opt(parse_f64)(input)
 }
 
-fn parse_optional_bool(input: &str) -> IResult<&str, Option<bool>> {
-    opt(parse_bool)(input)
-}
-
 fn _parse_float(input: &str) -> IResult<&str, f64> {
     map_res(
         recognize(pair(opt(char('-')), pair(digit1, pair(char('.'), digit1)))),
@@ -106,12 +138,285 @@ fn parse_array_string<const CAP: usize>(input: &str) -> IResult<&str, ArrayStrin
     )(input)
 }
 
-fn _parse_comment(input: &str) -> IResult<&str, &str> {
+// Names of DC lines and FACTS devices must be quoted, in single or double quotes,
+// only if they contain blanks or special characters.
+fn parse_name<const CAP: usize>(input: &str) -> IResult<&str, ArrayString<CAP>> {
+    alt((
+        parse_array_string,
+        map_res(
+            delimited(char('"'), take_while(|c| c != '"'), char('"')),
+            |s: &str| ArrayString::<CAP>::try_from(s),
+        ),
+        map_res(
+            take_while1(|c: char| c.is_alphanumeric() || c == '_' || c == '-'),
+            |s: &str| ArrayString::<CAP>::try_from(s),
+        ),
+    ))(input)
+}
+
+// Free text, such as the names and descriptions in GIC data, quoted as in
+// [parse_name] but of any length.
+fn parse_string(input: &str) -> IResult<&str, String> {
+    map(
+        alt((
+            delimited(char('\''), take_while(|c| c != '\''), char('\'')),
+            delimited(char('"'), take_while(|c| c != '"'), char('"')),
+            take_while1(|c: char| c.is_alphanumeric() || c == '_' || c == '-'),
+        )),
+        str::to_string,
+    )(input)
+}
+
+fn parse_comment(input: &str) -> IResult<&str, &str> {
     delimited(tag("/*"), take_until("*/"), tag("*/"))(input)
 }
 
-fn parse_zero_line(input: &str) -> IResult<&str, ()> {
-    let (input, _) = tuple((char('0'), opt(space1), newline))(input)?;
+// Consumes the remainder of a record line: trailing whitespace, an optional
+// `/* [...] */` annotation or `/ ...` comment and the line ending.
+pub(crate) fn parse_end_of_line(input: &str) -> IResult<&str, ()> {
+    let (input, _) = space0(input)?;
+    let (input, _) = opt(alt((parse_comment, preceded(char('/'), not_line_ending))))(input)?;
+    let (input, _) = space0(input)?;
+    let (input, _) = alt((line_ending, eof))(input)?;
+    Ok((input, ()))
+}
+
+//  0 / END OF BUS DATA, BEGIN LOAD DATA
+pub(crate) fn parse_zero_line(input: &str) -> IResult<&str, ()> {
+    let (input, _) = tuple((space0, char('0'), opt(space1)))(input)?;
+    let (input, _) = alt((
+        map(line_ending, |_| ()),
+        map(eof, |_| ()),
+        map(pair(char('/'), not_line_ending), |_| ()),
+    ))(input)?;
+    let (input, _) = opt(line_ending)(input)?;
+    Ok((input, ()))
+}
+
+// The `Q` record that indicates the end of the case data.
+pub(crate) fn parse_q_line(input: &str) -> IResult<&str, ()> {
+    let (input, _) = tuple((space0, char('Q')))(input)?;
+    Ok((input, ()))
+}
+
+pub(crate) fn at_end_of_case(input: &str) -> bool {
+    peek(parse_q_line)(input).is_ok() || input.trim().is_empty()
+}
+
+fn not_end_of_case(input: &str) -> IResult<&str, ()> {
+    if at_end_of_case(input) {
+        return Err(verify_error(input));
+    }
+    Ok((input, ()))
+}
+
+// Skips the `@!` lines, naming the fields of the records that follow, that
+// PSS/E 35 can write at the start of each section.
+pub(crate) fn parse_data_headers(input: &str) -> IResult<&str, ()> {
+    let (input, _) = many0(tuple((space0, tag("@!"), not_line_ending, line_ending)))(input)?;
+    Ok((input, ()))
+}
+
+// Parses consecutive records, one per line, up to the section terminator.
+// A line that is not the terminator, `Q` or the end of the input must be a valid record.
+pub(crate) fn parse_records<'a, O, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    many0(preceded(
+        tuple((parse_data_headers, not(parse_zero_line), not_end_of_case)),
+        cut(terminated(parser, parse_end_of_line)),
+    ))
+}
+
+// Parses records followed by the `0 / END OF ...` terminator.
+fn parse_block<'a, O, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    terminated(
+        parse_records(parser),
+        preceded(parse_data_headers, parse_zero_line),
+    )
+}
+
+// Parses the records of a data section followed by the `0 / END OF ...` terminator.
+// An absent section, at the `Q` record or the end of the input, is returned empty.
+// With `lenient`, bad records are recovered from as in [Lenient::parse_records].
+pub(crate) fn parse_section<'a, 'l, O, F>(
+    lenient: Option<&'l Lenient<'a>>,
+    name: &'static str,
+    mut parser: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> + 'l
+where
+    F: for<'b> FnMut(&'b str) -> IResult<&'b str, O> + 'l,
+{
+    move |input: &'a str| {
+        if at_end_of_case(input) {
+            return Ok((input, Vec::new()));
+        }
+        match lenient {
+            Some(lenient) => lenient.parse_records(input, name, &mut parser),
+            None => in_section(name, parse_block(&mut parser))(input),
+        }
+    }
+}
+
+// The number of blank fields appended to a record that ends early, in lenient
+// mode, so that its missing fields take their defaults.
+const BLANK_FIELDS: usize = 64;
+
+// The state of a lenient parse: the whole input, to locate diagnostics, and the
+// diagnostics so far.
+pub(crate) struct Lenient<'a> {
+    input: &'a str,
+    diagnostics: RefCell<Vec<Diagnostic>>,
+}
+
+impl<'a> Lenient<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            diagnostics: RefCell::new(Vec::new()),
+        }
+    }
+
+    // Drops the diagnostics of a parse that is to be retried.
+    fn clear(&self) {
+        self.diagnostics.borrow_mut().clear();
+    }
+
+    fn warn(&self, at: &str, section: &'static str, message: String) {
+        let diagnostic = Diagnostic::new(self.input, at, Severity::Warning, section, message);
+        self.diagnostics.borrow_mut().push(diagnostic);
+    }
+
+    fn skip(&self, section: &'static str, mut err: RawError<&str>) {
+        err.section.get_or_insert(section);
+        let err = ParseError::new(self.input, err);
+        self.diagnostics
+            .borrow_mut()
+            .push(Diagnostic::skipped(&err));
+    }
+
+    // Parses the records of section `name` up to its terminator, recovering from
+    // bad records: extra fields at the end of a record are ignored, the missing
+    // fields of a record that ends early take their defaults, and any other
+    // record that cannot be read is skipped, up to the end of the offending line.
+    fn parse_records<O, F>(
+        &self,
+        mut input: &'a str,
+        name: &'static str,
+        parser: &mut F,
+    ) -> IResult<&'a str, Vec<O>>
+    where
+        F: for<'b> FnMut(&'b str) -> IResult<&'b str, O>,
+    {
+        let mut records = Vec::new();
+        loop {
+            (input, _) = parse_data_headers(input)?;
+            if let Ok((input, _)) = parse_zero_line(input) {
+                return Ok((input, records));
+            }
+            if at_end_of_case(input) {
+                self.warn(input, name, format!("missing end of {}", name));
+                return Ok((input, records));
+            }
+
+            let (next_line, line) = parse_rest_of_line(input)?;
+            let err = match parser(input) {
+                Ok((rest, record)) => {
+                    records.push(record);
+                    input = match parse_end_of_line(rest) {
+                        Ok((rest, _)) => rest,
+                        Err(_) => {
+                            let (rest, extra) = parse_rest_of_line(rest)?;
+                            let extra = extra.trim_start_matches([' ', '\t', ',']).trim_end();
+                            self.warn(
+                                input,
+                                name,
+                                format!("extra fields in {} ignored at \"{}\"", name, extra),
+                            );
+                            rest
+                        }
+                    };
+                    continue;
+                }
+                Err(nom::Err::Error(err) | nom::Err::Failure(err)) => err,
+                Err(nom::Err::Incomplete(_)) => RawError::new(input, ErrorKind::Eof),
+            };
+
+            let read = input.len() - err.input.len();
+            if read <= line.len() && parse_end_of_line(err.input).is_ok() {
+                if let Some(record) = Self::complete(&input[..read], input.len(), parser) {
+                    records.push(record);
+                    self.warn(
+                        input,
+                        name,
+                        format!("missing fields in {} set to defaults", name),
+                    );
+                    input = next_line;
+                    continue;
+                }
+            }
+            (input, _) = parse_rest_of_line(err.input)?;
+            self.skip(name, err);
+        }
+    }
+
+    // Parses the start of a record that ends early with blank fields appended.
+    // The text is as long as the `len` of the input at the record, so that bus
+    // name placeholders locate the names in the input.
+    fn complete<O, F>(record: &str, len: usize, parser: &mut F) -> Option<O>
+    where
+        F: for<'b> FnMut(&'b str) -> IResult<&'b str, O>,
+    {
+        let blanks = BLANK_FIELDS.min(len.checked_sub(record.len() + 1)?);
+        let mut text = format!("{}{}\n", record, ",".repeat(blanks));
+        text.extend(std::iter::repeat_n(' ', len - text.len()));
+
+        let mut complete = terminated(parser, pair(many0(char(',')), parse_end_of_line));
+        complete(&text).ok().map(|(_, record)| record)
+    }
+
+    fn into_diagnostics(self) -> Vec<Diagnostic> {
+        self.diagnostics.into_inner()
+    }
+}
+
+// The rest of the line, and the input after its line ending.
+fn parse_rest_of_line(input: &str) -> IResult<&str, &str> {
+    terminated(not_line_ending, opt(line_ending))(input)
+}
+
+// Names the section in errors from `parser`.
+fn in_section<'a, O, F>(
+    name: &'static str,
+    mut parser: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    move |input: &'a str| {
+        parser(input).map_err(|err| {
+            err.map(|mut err| {
+                err.section.get_or_insert(name);
+                err
+            })
+        })
+    }
+}
+
+// Skips over the records of a section that is not (yet) read into the `Network`.
+pub(crate) fn skip_section(input: &str) -> IResult<&str, ()> {
+    if at_end_of_case(input) {
+        return Ok((input, ()));
+    }
+    let (input, _) = many0(preceded(
+        not(parse_zero_line),
+        terminated(not_line_ending, line_ending),
+    ))(input)?;
+    let (input, _) = parse_zero_line(input)?;
     Ok((input, ()))
 }
 
@@ -124,11 +429,11 @@ pub(crate) fn parse_raw_case_id(input: &str) -> IResult<&str, CaseID> {
     let (input, _) = space0(input)?;
 
     let (input, sbase) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, _) = opt(char(','))(input)?;
     let (input, _) = space0(input)?;
 
     let (input, rev) = opt(parse_int::<usize>)(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, _) = opt(char(','))(input)?;
     let (input, _) = space0(input)?;
 
     let (input, xfrrat) = opt(parse_i8)(input)?;
@@ -141,7 +446,7 @@ pub(crate) fn parse_raw_case_id(input: &str) -> IResult<&str, CaseID> {
 
     let (input, basfrq) = opt(parse_f64)(input)?;
 
-    let (input, _) = opt(separated_pair(char('/'), space0, take_until("\n")))(input)?;
+    let (input, comment) = opt(preceded(pair(space0, char('/')), not_line_ending))(input)?;
 
     let case_id = CaseID {
         ic,
@@ -150,196 +455,2002 @@ pub(crate) fn parse_raw_case_id(input: &str) -> IResult<&str, CaseID> {
         xfrrat,
         nxfrat,
         basfrq,
+        comment: comment.map(|text| text.trim().to_string()),
+        titles: Default::default(),
     };
 
     Ok((input, case_id))
 }
 
-// 111,'STBC      ',161.00,1,    0.00,    0.00,227,   1,1.09814,  -8.327,  1 /* [STBC   1   ] */
-pub(crate) fn parse_raw_bus(input: &str) -> IResult<&str, Bus> {
-    let (input, _) = space0(input)?;
+// v30 bus records carry the bus shunt admittance inline, which is returned as a
+// fixed shunt with ID `1` when it is nonzero.
+//
+// 111,'STBC      ',161.00,1,    0.00,    0.00,227,   1,1.09814,  -8.327,  1
+pub(crate) fn parse_raw_bus_v30(input: &str) -> IResult<&str, (Bus, Option<FixedShunt>)> {
+    let def = Bus::default();
 
-    let (input, i) = parse_bus_num(input)?;
-    let (input, _) = char(',')(input)?;
     let (input, _) = space0(input)?;
 
+    let (input, i) = parse_int(input)?;
+    let (input, _) = parse_sep(input)?;
+
     let (input, name) = parse_array_string(input)?;
-    let (input, _) = char(',')(input)?;
-    let (input, _) = space0(input)?;
+    let (input, _) = parse_sep(input)?;
 
     let (input, basekv) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
-    let (input, _) = space0(input)?;
+    let (input, _) = parse_sep(input)?;
 
     let (input, ide) = parse_i8(input)?;
-    let (input, _) = char(',')(input)?;
-    let (input, _) = space0(input)?;
 
-    let (input, area) = parse_area_num(input)?;
-    let (input, _) = char(',')(input)?;
-    let (input, _) = space0(input)?;
+    let (input, gl) = parse_opt_field(parse_f64)(input)?;
+    let (input, bl) = parse_opt_field(parse_f64)(input)?;
+    let (input, area) = parse_opt_field(parse_area_num)(input)?;
+    let (input, zone) = parse_opt_field(parse_zone_num)(input)?;
+    let (input, vm) = parse_opt_field(parse_f64)(input)?;
+    let (input, va) = parse_opt_field(parse_f64)(input)?;
+    let (input, owner) = parse_opt_field(parse_owner_num)(input)?;
 
-    let (input, zone) = parse_zone_num(input)?;
-    let (input, _) = char(',')(input)?;
-    let (input, _) = space0(input)?;
+    let bus = Bus {
+        i,
+        name,
+        basekv,
+        ide,
+        area: area.unwrap_or(def.area),
+        zone: zone.unwrap_or(def.zone),
+        owner: owner.unwrap_or(def.owner),
+        vm: vm.unwrap_or(def.vm),
+        va: va.unwrap_or(def.va),
+        ..def
+    };
 
-    let (input, owner) = parse_owner_num(input)?;
-    let (input, _) = opt(char(','))(input)?;
+    let (gl, bl) = (gl.unwrap_or(0.0), bl.unwrap_or(0.0));
+    let fixed_shunt = (gl != 0.0 || bl != 0.0).then(|| FixedShunt {
+        i,
+        id: ArrayString::from("1").unwrap(),
+        status: IN_SERVICE,
+        gl,
+        bl,
+    });
+
+    Ok((input, (bus, fixed_shunt)))
+}
+
+fn verify_error(input: &str) -> nom::Err<RawError<&str>> {
+    nom::Err::Error(RawError::new(input, nom::error::ErrorKind::Verify))
+}
+
+// Fails, at the start of the record at `input`, for a bus number that has been read before.
+fn verify_new_bus<'a>(
+    input: &'a str,
+    i: BusNum,
+    numbers: &mut HashSet<BusNum>,
+) -> Result<(), nom::Err<RawError<&'a str>>> {
+    if numbers.insert(i) {
+        return Ok(());
+    }
+    let mut err = RawError::new(input.trim_start_matches([' ', '\t']), ErrorKind::Verify);
+    err.error = ParseErrorKind::DuplicateBus;
+    err.field = Some("i");
+    Err(nom::Err::Failure(err))
+}
+
+// Parses a field separator: a comma with optional surrounding spaces or tabs,
+// or just spaces or tabs.
+pub(crate) fn parse_sep(input: &str) -> IResult<&str, ()> {
+    let (input, _) = alt((recognize(tuple((space0, char(','), space0))), space1))(input)?;
+    Ok((input, ()))
+}
+
+// Matches a field left blank, e.g. the second of `1,,3`, without consuming it.
+// PSS/E reads blank fields as their default values.
+pub(crate) fn parse_blank(input: &str) -> IResult<&str, ()> {
+    peek(alt((map(char(','), |_| ()), parse_end_of_line)))(input)
+}
+
+// Parses a field that is only present from revision `since` onwards.
+fn parse_since<'a, O, F>(
+    rev: usize,
+    since: usize,
+    mut parser: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Option<O>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, Option<O>>,
+{
+    move |input| {
+        if rev >= since {
+            parser(input)
+        } else {
+            Ok((input, None))
+        }
+    }
+}
+
+// Parses the fourth to twelfth ratings of a v34 branch, winding or switching device.
+fn parse_rates(input: &str) -> IResult<&str, [f64; 9]> {
+    let (input, first) = parse_f64(input)?;
+    let (input, rest) = count(preceded(parse_sep, parse_f64), 8)(input)?;
+
+    let mut rates = [first; 9];
+    rates[1..].copy_from_slice(&rest);
+
+    Ok((input, rates))
+}
+
+// Parses a trailing field that may be left out, along with its separator, or left blank.
+fn parse_opt_field<'a, O, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, Option<O>>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    map(
+        opt(preceded(
+            parse_sep,
+            alt((map(parser, Some), map(parse_blank, |_| None))),
+        )),
+        Option::flatten,
+    )
+}
+
+// As `parse_opt_field`, along with the input at the start of the field, to
+// locate errors in its value.
+fn parse_opt_field_at<'a, O, F>(
+    parser: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, Option<O>)>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    let mut field = parse_opt_field(parser);
+    move |input| {
+        let at = parse_sep(input).map_or(input, |(at, _)| at);
+        let (input, value) = field(input)?;
+        Ok((input, (at, value)))
+    }
+}
+
+// Field parsers for the records that derive `RawRecord`.
+macro_rules! int_field {
+    ($($t:ty),*) => {
+        $(impl RawField for $t {
+            fn parse_field(input: &str) -> IResult<&str, Self> {
+                parse_int(input)
+            }
+        })*
+    };
+}
+
+int_field!(i8, i16, i32, isize);
+
+impl RawField for f64 {
+    fn parse_field(input: &str) -> IResult<&str, Self> {
+        parse_f64(input)
+    }
+}
+
+impl RawField for bool {
+    fn parse_field(input: &str) -> IResult<&str, Self> {
+        parse_bool(input)
+    }
+}
+
+impl RawField for [f64; 9] {
+    fn parse_field(input: &str) -> IResult<&str, Self> {
+        parse_rates(input)
+    }
+}
+
+impl<const CAP: usize> RawField for ArrayString<CAP> {
+    fn parse_field(input: &str) -> IResult<&str, Self> {
+        parse_name(input)
+    }
+}
+
+impl RawField for String {
+    fn parse_field(input: &str) -> IResult<&str, Self> {
+        parse_string(input)
+    }
+}
+
+/// Data from the bus data section used to default the transformer fields
+/// that PSS/E derives from the connected buses.
+pub(crate) struct TransformerContext {
+    sbase: f64,
+    buses: HashMap<BusNum, (f64, OwnerNum)>,
+    pub(crate) names: BusNames,
+}
+
+impl TransformerContext {
+    pub(crate) fn new(sbase: f64, buses: &[Bus]) -> Self {
+        let mut ctx = Self {
+            sbase,
+            buses: HashMap::with_capacity(buses.len()),
+            names: BusNames::default(),
+        };
+        buses.iter().for_each(|bus| ctx.add_bus(bus));
+        ctx
+    }
+
+    pub(crate) fn add_bus(&mut self, bus: &Bus) {
+        self.buses.insert(bus.i, (bus.basekv, bus.owner));
+        self.names.add_bus(bus);
+    }
+
+    // The number of bus `i` of a record read from `input`, which may be
+    // given by name. Names that cannot be resolved are reported later.
+    fn bus(&self, input: &str, i: BusNum) -> BusNum {
+        name_at(input, i)
+            .and_then(|name| self.names.find(quoted(name)).ok())
+            .unwrap_or(i)
+    }
+
+    fn basekv(&self, i: BusNum) -> f64 {
+        self.buses.get(&i).map(|bus| bus.0).unwrap_or(1.0)
+    }
+
+    fn owner(&self, i: BusNum) -> OwnerNum {
+        self.buses.get(&i).map(|bus| bus.1).unwrap_or(1)
+    }
+}
+
+// The fields of one winding, from the third, fourth or fifth line of a
+// transformer record. Fields that are left out are `None`.
+#[derive(Default)]
+struct Winding {
+    windv: Option<f64>,
+    nomv: Option<f64>,
+    ang: Option<f64>,
+    rata: Option<f64>,
+    ratb: Option<f64>,
+    ratc: Option<f64>,
+    rates: Option<[f64; 9]>,
+    cod: Option<i8>,
+    cont: Option<BusNum>,
+    node: Option<i32>,
+    rma: Option<f64>,
+    rmi: Option<f64>,
+    vma: Option<f64>,
+    vmi: Option<f64>,
+    ntp: Option<i16>,
+    tab: Option<isize>,
+    cr: Option<f64>,
+    cx: Option<f64>,
+    cnxa: Option<f64>,
+}
+
+// 1.00000,   0.000,   0.000,  1200.00,  1100.00,  1000.00,  0,      0, 1.10000, 0.90000, 1.10000, 0.90000,  33, 0, 0.00000, 0.00000,  0.000
+//
+// v34 winding lines have twelve ratings in place of three, and add `node` after `cont`.
+fn parse_raw_winding(input: &str, rev: usize) -> IResult<&str, Winding> {
     let (input, _) = space0(input)?;
 
-    let (input, vm) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, windv) = parse_f64(input)?;
+    let (input, nomv) = parse_opt_field(parse_f64)(input)?;
+    let (input, ang) = parse_opt_field(parse_f64)(input)?;
+    let (input, rata) = parse_opt_field(parse_f64)(input)?;
+    let (input, ratb) = parse_opt_field(parse_f64)(input)?;
+    let (input, ratc) = parse_opt_field(parse_f64)(input)?;
+    let (input, rates) = parse_since(rev, 34, parse_opt_field(parse_rates))(input)?;
+    let (input, cod) = parse_opt_field(parse_int)(input)?;
+    let (input, cont) = parse_opt_field(parse_metered_bus_num)(input)?;
+    let (input, node) = parse_since(rev, 34, parse_opt_field(parse_int))(input)?;
+    let (input, rma) = parse_opt_field(parse_f64)(input)?;
+    let (input, rmi) = parse_opt_field(parse_f64)(input)?;
+    let (input, vma) = parse_opt_field(parse_f64)(input)?;
+    let (input, vmi) = parse_opt_field(parse_f64)(input)?;
+    let (input, ntp) = parse_opt_field(parse_int)(input)?;
+    let (input, tab) = parse_opt_field(parse_int)(input)?;
+    let (input, cr) = parse_opt_field(parse_f64)(input)?;
+    let (input, cx) = parse_opt_field(parse_f64)(input)?;
+    let (input, cnxa) = parse_opt_field(parse_f64)(input)?;
+
+    let winding = Winding {
+        windv: Some(windv),
+        nomv,
+        ang,
+        rata,
+        ratb,
+        ratc,
+        rates,
+        cod,
+        cont,
+        node,
+        rma,
+        rmi,
+        vma,
+        vmi,
+        ntp,
+        tab,
+        cr,
+        cx,
+        cnxa,
+    };
+
+    Ok((input, winding))
+}
+
+impl Winding {
+    // When `cw` is 2 the winding voltage is in kV and defaults to the base
+    // voltage of the winding bus, otherwise it is a ratio that defaults to 1.0.
+    fn windv_or_default(&self, cw: i8, basekv: f64) -> f64 {
+        self.windv.unwrap_or(if cw == 2 { basekv } else { 1.0 })
+    }
+}
+
+//   101,  151,     0,'T1',1,1,1,   0.00000,   0.00000,2,'            ',1,   1,1.0000,   0,1.0000,   0,1.0000,   0,1.0000,'            '
+//  0.00000, 0.01000,   100.00
+// 1.00000,   0.000,   0.000,  1200.00,  1100.00,  1000.00,  0,      0, 1.10000, 0.90000, 1.10000, 0.90000,  33, 0, 0.00000, 0.00000,  0.000
+// 1.00000,   0.000
+//
+// Two-winding transformers (`k` = 0) have four lines; three-winding transformers have five.
+// Fields that are left out at the end of a line take their documented defaults.
+//
+// v35 transformer records add `zcod` at the end of the first line.
+pub(crate) fn parse_raw_transformer<'a>(
+    input: &'a str,
+    ctx: &TransformerContext,
+    rev: usize,
+) -> IResult<&'a str, Transformer> {
+    let def = Transformer::default();
+    let start = input;
+
+    // first line //
     let (input, _) = space0(input)?;
 
-    let (input, va) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, i) = parse_bus_num(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, j) = parse_bus_num(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, k) = parse_bus_num(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, ckt) = parse_array_string(input)?;
+
+    let (input, (cw_at, cw)) = parse_opt_field_at(parse_i8)(input)?;
+    let (input, (cz_at, cz)) = parse_opt_field_at(parse_i8)(input)?;
+    let (input, (cm_at, cm)) = parse_opt_field_at(parse_i8)(input)?;
+    let (input, mag1) = parse_opt_field(parse_f64)(input)?;
+    let (input, mag2) = parse_opt_field(parse_f64)(input)?;
+    let (input, nmetr) = parse_opt_field(parse_i8)(input)?;
+    let (input, name) = parse_opt_field(parse_array_string)(input)?;
+    let (input, stat) = parse_opt_field(parse_i8)(input)?;
+    let (input, o1) = parse_opt_field(parse_owner_num)(input)?;
+    let (input, f1) = parse_opt_field(parse_f64)(input)?;
+    let (input, o2) = parse_opt_field(parse_owner_num)(input)?;
+    let (input, f2) = parse_opt_field(parse_f64)(input)?;
+    let (input, o3) = parse_opt_field(parse_owner_num)(input)?;
+    let (input, f3) = parse_opt_field(parse_f64)(input)?;
+    let (input, o4) = parse_opt_field(parse_owner_num)(input)?;
+    let (input, f4) = parse_opt_field(parse_f64)(input)?;
+    let (input, vecgrp) = parse_opt_field(parse_array_string)(input)?;
+    let (input, zcod) = parse_since(rev, 35, parse_opt_field(parse_i8))(input)?;
+    let (input, _) = parse_end_of_line(input)?;
+
+    let cw = cw.unwrap_or(def.cw);
+    let cz = cz.unwrap_or(def.cz);
+    let cm = cm.unwrap_or(def.cm);
+    for (at, valid) in [
+        (cw_at, (1..=3).contains(&cw)),
+        (cz_at, (1..=3).contains(&cz)),
+        (cm_at, (1..=2).contains(&cm)),
+    ] {
+        if !valid {
+            return Err(verify_error(at));
+        }
+    }
+    let three_winding = k != 0;
+
+    // second line //
     let (input, _) = space0(input)?;
 
-    let (input, nvhi) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, r1_2) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, x1_2) = parse_f64(input)?;
+
+    let (input, sbase1_2) = parse_opt_field(parse_f64)(input)?;
+    let (input, r2_3) = parse_opt_field(parse_f64)(input)?;
+    let (input, x2_3) = parse_opt_field(parse_f64)(input)?;
+    let (input, sbase2_3) = parse_opt_field(parse_f64)(input)?;
+    let (input, r3_1) = parse_opt_field(parse_f64)(input)?;
+    let (input, x3_1) = parse_opt_field(parse_f64)(input)?;
+    let (input, sbase3_1) = parse_opt_field(parse_f64)(input)?;
+    let (input, vmstar) = parse_opt_field(parse_f64)(input)?;
+    let (input, anstar) = parse_opt_field(parse_f64)(input)?;
+    let (input, _) = parse_end_of_line(input)?;
+
+    if three_winding && (x2_3.is_none() || x3_1.is_none()) {
+        // `x2_3` and `x3_1` have no default.
+        return Err(verify_error(input));
+    }
+
+    // third line //
+    let (input, w1) = parse_raw_winding(input, rev)?;
+    let (input, _) = parse_end_of_line(input)?;
+
+    // fourth line //
+    let (input, w2) = parse_raw_winding(input, rev)?;
+
+    // fifth line //
+    let (input, w3) = if three_winding {
+        let (input, _) = parse_end_of_line(input)?;
+        parse_raw_winding(input, rev)?
+    } else {
+        (input, Winding::default())
+    };
+
+    // Data items on the second winding line beyond `nomv2` and the
+    // fifth line are only used for three-winding transformers.
+    let w2_3 = |value: Option<f64>, default: f64| three_winding.then(|| value.unwrap_or(default));
+
+    let transformer = Transformer {
+        i,
+        j,
+        k,
+        ckt,
+        cw,
+        cz,
+        cm,
+        mag1: mag1.unwrap_or(def.mag1),
+        mag2: mag2.unwrap_or(def.mag2),
+        nmetr: nmetr.unwrap_or(def.nmetr),
+        name: name.unwrap_or(def.name),
+        stat: stat.unwrap_or(def.stat),
+        o1: o1.unwrap_or_else(|| ctx.owner(ctx.bus(start, i))),
+        f1: f1.unwrap_or(def.f1),
+        o2,
+        f2,
+        o3,
+        f3,
+        o4,
+        f4,
+        vecgrp,
+        zcod,
+        r1_2,
+        x1_2,
+        sbase1_2: sbase1_2.unwrap_or(ctx.sbase),
+        r2_3: w2_3(r2_3, 0.0),
+        x2_3,
+        sbase2_3: w2_3(sbase2_3, ctx.sbase),
+        r3_1: w2_3(r3_1, 0.0),
+        x3_1,
+        sbase3_1: w2_3(sbase3_1, ctx.sbase),
+        vmstar: w2_3(vmstar, 1.0),
+        anstar: w2_3(anstar, 0.0),
+        windv1: w1.windv_or_default(cw, ctx.basekv(ctx.bus(start, i))),
+        nomv1: w1.nomv.unwrap_or(def.nomv1),
+        ang1: w1.ang.unwrap_or(def.ang1),
+        rata1: w1.rata.unwrap_or(def.rata1),
+        ratb1: w1.ratb.unwrap_or(def.ratb1),
+        rates1: w1.rates,
+        ratc1: w1.ratc.unwrap_or(def.ratc1),
+        cod1: w1.cod.unwrap_or(def.cod1),
+        node1: w1.node,
+        cont1: w1.cont.unwrap_or(def.cont1),
+        rma1: w1.rma.unwrap_or(def.rma1),
+        rmi1: w1.rmi.unwrap_or(def.rmi1),
+        vma1: w1.vma.unwrap_or(def.vma1),
+        vmi1: w1.vmi.unwrap_or(def.vmi1),
+        ntp1: w1.ntp.unwrap_or(def.ntp1),
+        tab1: w1.tab.unwrap_or(def.tab1),
+        cr1: w1.cr.unwrap_or(def.cr1),
+        cx1: w1.cx.unwrap_or(def.cx1),
+        cnxa1: w1.cnxa,
+        windv2: w2.windv_or_default(cw, ctx.basekv(ctx.bus(start, j))),
+        nomv2: w2.nomv.unwrap_or(def.nomv2),
+        ang2: w2_3(w2.ang, 0.0),
+        rata2: w2_3(w2.rata, 0.0),
+        ratb2: w2_3(w2.ratb, 0.0),
+        rates2: w2.rates,
+        ratc2: w2_3(w2.ratc, 0.0),
+        cod2: three_winding.then(|| w2.cod.unwrap_or(0)),
+        node2: w2.node,
+        cont2: three_winding.then(|| w2.cont.unwrap_or(0)),
+        rma2: w2_3(w2.rma, 1.1),
+        rmi2: w2_3(w2.rmi, 0.9),
+        vma2: w2_3(w2.vma, 1.1),
+        vmi2: w2_3(w2.vmi, 0.9),
+        ntp2: three_winding.then(|| w2.ntp.unwrap_or(33)),
+        tab2: three_winding.then(|| w2.tab.unwrap_or(0)),
+        cr2: w2_3(w2.cr, 0.0),
+        cx2: w2_3(w2.cx, 0.0),
+        cnxa2: w2.cnxa,
+        windv3: three_winding.then(|| w3.windv_or_default(cw, ctx.basekv(ctx.bus(start, k)))),
+        nomv3: w2_3(w3.nomv, 0.0),
+        ang3: w2_3(w3.ang, 0.0),
+        rata3: w2_3(w3.rata, 0.0),
+        ratb3: w2_3(w3.ratb, 0.0),
+        rates3: w3.rates,
+        ratc3: w2_3(w3.ratc, 0.0),
+        cod3: three_winding.then(|| w3.cod.unwrap_or(0)),
+        node3: w3.node,
+        cont3: three_winding.then(|| w3.cont.unwrap_or(0)),
+        rma3: w2_3(w3.rma, 1.1),
+        rmi3: w2_3(w3.rmi, 0.9),
+        vma3: w2_3(w3.vma, 1.1),
+        vmi3: w2_3(w3.vmi, 0.9),
+        ntp3: three_winding.then(|| w3.ntp.unwrap_or(33)),
+        tab3: three_winding.then(|| w3.tab.unwrap_or(0)),
+        cr3: w2_3(w3.cr, 0.0),
+        cx3: w2_3(w3.cx, 0.0),
+        cnxa3: w3.cnxa,
+    };
+
+    Ok((input, transformer))
+}
+
+// 'DC1',1,   8.2000,  1000.00,   500.00,   400.00,   0.0000,   0.1000,'I',   0.0000,20, 1.00000
+//   401,2,  20.000,   5.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,     0,     0,     0,'1 ',   0.000
+//   402,2,  20.000,  15.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,     0,     0,     0,'1 ',   0.000
+pub(crate) fn parse_raw_two_terminal_dc_line(
+    input: &str,
+    rev: usize,
+) -> IResult<&str, TwoTerminalDCLine> {
+    let def = TwoTerminalDCLine::default();
+
+    // first line: control parameters //
     let (input, _) = space0(input)?;
 
-    let (input, nvlo) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, name) = parse_name(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, mdc) = parse_i8(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, rdc) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, setvl) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, vschd) = parse_f64(input)?;
+
+    let (input, vcmod) = parse_opt_field(parse_f64)(input)?;
+    let (input, rcomp) = parse_opt_field(parse_f64)(input)?;
+    let (input, delti) = parse_opt_field(parse_f64)(input)?;
+    let (input, meter) = parse_opt_field(parse_name)(input)?;
+    let (input, dcvmin) = parse_opt_field(parse_f64)(input)?;
+    let (input, cccitmx) = parse_opt_field(parse_int)(input)?;
+    let (input, cccacc) = parse_opt_field(parse_f64)(input)?;
+    let (input, _) = parse_end_of_line(input)?;
+
+    // second line: rectifier //
+    let (input, rectifier) = parse_raw_dc_converter(input, rev)?;
+    let (input, _) = parse_end_of_line(input)?;
+
+    // third line: inverter //
+    let (input, inverter) = parse_raw_dc_converter(input, rev)?;
+
+    let dc_line = TwoTerminalDCLine {
+        name,
+        mdc,
+        rdc,
+        setvl,
+        vschd,
+        vcmod: vcmod.unwrap_or(def.vcmod),
+        rcomp: rcomp.unwrap_or(def.rcomp),
+        delti: delti.unwrap_or(def.delti),
+        meter: meter.unwrap_or(def.meter),
+        dcvmin: dcvmin.unwrap_or(def.dcvmin),
+        cccitmx: cccitmx.unwrap_or(def.cccitmx),
+        cccacc: cccacc.unwrap_or(def.cccacc),
+        ipr: rectifier.ip,
+        nbr: rectifier.nb,
+        alfmx: rectifier.anmx,
+        alfmn: rectifier.anmn,
+        rcr: rectifier.rc,
+        xcr: rectifier.xc,
+        ebasr: rectifier.ebas,
+        trr: rectifier.tr.unwrap_or(def.trr),
+        tapr: rectifier.tap.unwrap_or(def.tapr),
+        tmxr: rectifier.tmx.unwrap_or(def.tmxr),
+        tmnr: rectifier.tmn.unwrap_or(def.tmnr),
+        stpr: rectifier.stp.unwrap_or(def.stpr),
+        icr: rectifier.ic.unwrap_or(def.icr),
+        ndr: rectifier.nd,
+        ifr: rectifier.if_.unwrap_or(def.ifr),
+        itr: rectifier.it.unwrap_or(def.itr),
+        idr: rectifier.id.unwrap_or(def.idr),
+        xcapr: rectifier.xcap.unwrap_or(def.xcapr),
+        ipi: inverter.ip,
+        nbi: inverter.nb,
+        gammx: inverter.anmx,
+        gammn: inverter.anmn,
+        rci: inverter.rc,
+        xci: inverter.xc,
+        ebasi: inverter.ebas,
+        tri: inverter.tr.unwrap_or(def.tri),
+        tapi: inverter.tap.unwrap_or(def.tapi),
+        tmxi: inverter.tmx.unwrap_or(def.tmxi),
+        tmni: inverter.tmn.unwrap_or(def.tmni),
+        stpi: inverter.stp.unwrap_or(def.stpi),
+        ici: inverter.ic.unwrap_or(def.ici),
+        ndi: inverter.nd,
+        ifi: inverter.if_.unwrap_or(def.ifi),
+        iti: inverter.it.unwrap_or(def.iti),
+        idi: inverter.id.unwrap_or(def.idi),
+        xcapi: inverter.xcap.unwrap_or(def.xcapi),
+    };
+
+    Ok((input, dc_line))
+}
+
+// The rectifier and inverter lines of a two-terminal DC line record share a layout.
+struct DCConverter {
+    ip: BusNum,
+    nb: i32,
+    anmx: f64,
+    anmn: f64,
+    rc: f64,
+    xc: f64,
+    ebas: f64,
+    tr: Option<f64>,
+    tap: Option<f64>,
+    tmx: Option<f64>,
+    tmn: Option<f64>,
+    stp: Option<f64>,
+    ic: Option<BusNum>,
+    nd: Option<i32>,
+    if_: Option<BusNum>,
+    it: Option<BusNum>,
+    id: Option<ArrayString<3>>,
+    xcap: Option<f64>,
+}
+
+// v34 converter lines add `nd` after `ic`.
+fn parse_raw_dc_converter(input: &str, rev: usize) -> IResult<&str, DCConverter> {
     let (input, _) = space0(input)?;
 
-    let (input, evhi) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, ip) = parse_bus_num(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, nb) = parse_int(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, anmx) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, anmn) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, rc) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, xc) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, ebas) = parse_f64(input)?;
+
+    let (input, tr) = parse_opt_field(parse_f64)(input)?;
+    let (input, tap) = parse_opt_field(parse_f64)(input)?;
+    let (input, tmx) = parse_opt_field(parse_f64)(input)?;
+    let (input, tmn) = parse_opt_field(parse_f64)(input)?;
+    let (input, stp) = parse_opt_field(parse_f64)(input)?;
+    let (input, ic) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, nd) = parse_since(rev, 34, parse_opt_field(parse_int))(input)?;
+    let (input, if_) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, it) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, id) = parse_opt_field(parse_name)(input)?;
+    let (input, xcap) = parse_opt_field(parse_f64)(input)?;
+
+    let converter = DCConverter {
+        ip,
+        nb,
+        anmx,
+        anmn,
+        rc,
+        xc,
+        ebas,
+        tr,
+        tap,
+        tmx,
+        tmn,
+        stp,
+        ic,
+        nd,
+        if_,
+        it,
+        id,
+        xcap,
+    };
+
+    Ok((input, converter))
+}
+
+// 'VDCLINE1',1,   0.7100,   1,1.0000,   0,1.0000,   0,1.0000,   0,1.0000
+//  3005,2,1,   100.00,  1.01500,  0.00,  0.00,  0.00,  400.00,  1200.00, 1.0000,   100.00,  -110.00,     0, 100.0
+//  3008,1,1,   400.00,  1.01500,  0.00,  0.00,  0.00,  400.00,  1200.00, 1.0000,   100.00,  -140.00,     0, 100.0
+pub(crate) fn parse_raw_vsc_dc_line(input: &str, rev: usize) -> IResult<&str, VSCDCLine> {
+    let def = VSCDCLine::default();
+
+    // first line //
     let (input, _) = space0(input)?;
 
-    let (input, evlo) = parse_f64(input)?;
+    let (input, name) = parse_name(input)?;
+    let (input, _) = parse_sep(input)?;
 
-    let bus = Bus {
-        i,
+    let (input, mdc) = parse_i8(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, rdc) = parse_f64(input)?;
+
+    let (input, o1) = parse_opt_field(parse_owner_num)(input)?;
+    let (input, f1) = parse_opt_field(parse_f64)(input)?;
+    let (input, o2) = parse_opt_field(parse_owner_num)(input)?;
+    let (input, f2) = parse_opt_field(parse_f64)(input)?;
+    let (input, o3) = parse_opt_field(parse_owner_num)(input)?;
+    let (input, f3) = parse_opt_field(parse_f64)(input)?;
+    let (input, o4) = parse_opt_field(parse_owner_num)(input)?;
+    let (input, f4) = parse_opt_field(parse_f64)(input)?;
+    let (input, _) = parse_end_of_line(input)?;
+
+    // second line: converter 1 //
+    let (input, c1) = parse_raw_vsc_converter(input, rev)?;
+    let (input, _) = parse_end_of_line(input)?;
+
+    // third line: converter 2 //
+    let (input, c2) = parse_raw_vsc_converter(input, rev)?;
+
+    let vsc = VSCDCLine {
         name,
-        basekv,
-        ide,
-        area,
-        zone,
-        owner,
-        vm,
-        va,
-        nvhi,
-        nvlo,
-        evhi,
-        evlo,
+        mdc,
+        rdc,
+        o1: o1.unwrap_or(def.o1),
+        f1: f1.unwrap_or(def.f1),
+        o2: o2.unwrap_or(def.o2),
+        f2: f2.unwrap_or(def.f2),
+        o3: o3.unwrap_or(def.o3),
+        f3: f3.unwrap_or(def.f3),
+        o4: o4.unwrap_or(def.o4),
+        f4: f4.unwrap_or(def.f4),
+        ibus1: c1.ibus,
+        type1: c1.type_,
+        mode1: c1.mode,
+        docet1: c1.dcset,
+        acset1: c1.acset.unwrap_or(def.acset1),
+        aloss1: c1.aloss.unwrap_or(def.aloss1),
+        bloss1: c1.bloss.unwrap_or(def.bloss1),
+        minloss1: c1.minloss.unwrap_or(def.minloss1),
+        smax1: c1.smax.unwrap_or(def.smax1),
+        imax1: c1.imax.unwrap_or(def.imax1),
+        pwf1: c1.pwf.unwrap_or(def.pwf1),
+        maxq1: c1.maxq.unwrap_or(def.maxq1),
+        minq1: c1.minq.unwrap_or(def.minq1),
+        remot1: c1.remot.unwrap_or(def.remot1),
+        nreg1: c1.nreg,
+        rmpct1: c1.rmpct.unwrap_or(def.rmpct1),
+        ibus2: c2.ibus,
+        type2: c2.type_,
+        mode2: c2.mode,
+        docet2: c2.dcset,
+        acset2: c2.acset.unwrap_or(def.acset2),
+        aloss2: c2.aloss.unwrap_or(def.aloss2),
+        bloss2: c2.bloss.unwrap_or(def.bloss2),
+        minloss2: c2.minloss.unwrap_or(def.minloss2),
+        smax2: c2.smax.unwrap_or(def.smax2),
+        imax2: c2.imax.unwrap_or(def.imax2),
+        pwf2: c2.pwf.unwrap_or(def.pwf2),
+        maxq2: c2.maxq.unwrap_or(def.maxq2),
+        minq2: c2.minq.unwrap_or(def.minq2),
+        remot2: c2.remot.unwrap_or(def.remot2),
+        nreg2: c2.nreg,
+        rmpct2: c2.rmpct.unwrap_or(def.rmpct2),
     };
 
-    Ok((input, bus))
+    Ok((input, vsc))
 }
 
-fn parse_raw_buses(input: &str) -> IResult<&str, Vec<Bus>> {
-    separated_list1(newline, parse_raw_bus)(input)
+// The two converter lines of a VSC DC line record share a layout.
+struct VSCConverter {
+    ibus: BusNum,
+    type_: i8,
+    mode: i8,
+    dcset: f64,
+    acset: Option<f64>,
+    aloss: Option<f64>,
+    bloss: Option<f64>,
+    minloss: Option<f64>,
+    smax: Option<f64>,
+    imax: Option<f64>,
+    pwf: Option<f64>,
+    maxq: Option<f64>,
+    minq: Option<f64>,
+    remot: Option<BusNum>,
+    nreg: Option<i32>,
+    rmpct: Option<f64>,
 }
 
-// 111,'G1',1,227,   1,   -0.004,   -0.000,   -0.003,   -0.000,    0.000,   -0.000,  1 /* [STBC   G1                   ] */
-pub(crate) fn parse_raw_load(input: &str) -> IResult<&str, Load> {
-    let (input, i) = parse_bus_num(input)?;
-    let (input, _) = char(',')(input)?;
+// v34 converter lines add `nreg` after `remot`.
+fn parse_raw_vsc_converter(input: &str, rev: usize) -> IResult<&str, VSCConverter> {
     let (input, _) = space0(input)?;
 
-    let (input, id) = parse_array_string(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, ibus) = parse_bus_num(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, type_) = parse_i8(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, mode) = parse_i8(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, dcset) = parse_f64(input)?;
+
+    let (input, acset) = parse_opt_field(parse_f64)(input)?;
+    let (input, aloss) = parse_opt_field(parse_f64)(input)?;
+    let (input, bloss) = parse_opt_field(parse_f64)(input)?;
+    let (input, minloss) = parse_opt_field(parse_f64)(input)?;
+    let (input, smax) = parse_opt_field(parse_f64)(input)?;
+    let (input, imax) = parse_opt_field(parse_f64)(input)?;
+    let (input, pwf) = parse_opt_field(parse_f64)(input)?;
+    let (input, maxq) = parse_opt_field(parse_f64)(input)?;
+    let (input, minq) = parse_opt_field(parse_f64)(input)?;
+    let (input, remot) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, nreg) = parse_since(rev, 34, parse_opt_field(parse_int))(input)?;
+    let (input, rmpct) = parse_opt_field(parse_f64)(input)?;
+
+    let converter = VSCConverter {
+        ibus,
+        type_,
+        mode,
+        dcset,
+        acset,
+        aloss,
+        bloss,
+        minloss,
+        smax,
+        imax,
+        pwf,
+        maxq,
+        minq,
+        remot,
+        nreg,
+        rmpct,
+    };
+
+    Ok((input, converter))
+}
+
+// 'MTDC',3,4,3,1,  402,     0.00,    0
+pub(crate) fn parse_raw_dc_line_id(input: &str) -> IResult<&str, DCLineID> {
     let (input, _) = space0(input)?;
 
-    let (input, status) = parse_i8(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, name) = parse_name(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, nconv) = parse_i8(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, ndcbs) = parse_i8(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, ndcln) = parse_int(input)?;
+
+    let (input, mdc) = parse_opt_field(parse_i8)(input)?;
+    let (input, vconv) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, vcmod) = parse_opt_field(parse_f64)(input)?;
+    let (input, vconvn) = parse_opt_field(parse_bus_num)(input)?;
+
+    let line_id = DCLineID {
+        name,
+        nconv,
+        ndcbs,
+        ndcln,
+        mdc: mdc.unwrap_or(0),
+        vconv: vconv.unwrap_or(0),
+        vcmod: vcmod.unwrap_or(0.0),
+        vconvn: vconvn.unwrap_or(0),
+    };
+
+    Ok((input, line_id))
+}
+
+//   401,2,  20.000,   5.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,   500.00, 1.0000, 0.0000, 1
+pub(crate) fn parse_raw_ac_converter(input: &str) -> IResult<&str, ACConverter> {
     let (input, _) = space0(input)?;
 
-    let (input, area) = parse_area_num(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, ib) = parse_bus_num(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, n) = parse_i8(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, angmx) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, angmn) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, rc) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, xc) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, ebas) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, tr) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, tap) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, tpmx) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, tpmn) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, tstp) = parse_f64(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, setvl) = parse_f64(input)?;
+
+    let (input, dcpf) = parse_opt_field(parse_f64)(input)?;
+    let (input, marg) = parse_opt_field(parse_f64)(input)?;
+    let (input, cnvcod) = parse_opt_field(parse_int)(input)?;
+
+    let converter = ACConverter {
+        ib,
+        n,
+        angmx,
+        angmn,
+        rc,
+        xc,
+        ebas,
+        tr,
+        tap,
+        tpmx,
+        tpmn,
+        tstp,
+        setvl,
+        dcpf: dcpf.unwrap_or(1.0),
+        marg: marg.unwrap_or(0.0),
+        cnvcod: cnvcod.unwrap_or(1),
+    };
+
+    Ok((input, converter))
+}
+
+//    1,  401,   1,   1,'DC BUS 1    ',    0,   0.0000,   1
+pub(crate) fn parse_raw_dc_bus(input: &str) -> IResult<&str, DCBus> {
     let (input, _) = space0(input)?;
 
-    let (input, zone) = parse_zone_num(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, idc) = parse_int(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, ib) = parse_bus_num(input)?;
+
+    let (input, ia) = parse_opt_field(parse_area_num)(input)?;
+    let (input, zone) = parse_opt_field(parse_zone_num)(input)?;
+    let (input, name) = parse_opt_field(parse_array_string)(input)?;
+    let (input, idc2) = parse_opt_field(parse_int)(input)?;
+    let (input, rgrnd) = parse_opt_field(parse_f64)(input)?;
+    let (input, owner) = parse_opt_field(parse_owner_num)(input)?;
+
+    let dc_bus = DCBus {
+        idc,
+        ib,
+        ia: ia.unwrap_or(1),
+        zone: zone.unwrap_or(1),
+        name: name.unwrap_or_default(),
+        idc2: idc2.unwrap_or(0),
+        rgrnd: rgrnd.unwrap_or(0.0),
+        owner: owner.unwrap_or(1),
+    };
+
+    Ok((input, dc_bus))
+}
+
+//    1,    2,'1',1,   4.5000,   0.0000
+//
+// v30 DC link records have no `met` field; the `idc` end is metered.
+fn parse_raw_dc_link(input: &str, rev: usize) -> IResult<&str, DCLink> {
     let (input, _) = space0(input)?;
 
-    let (input, pl) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, idc) = parse_int(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, jdc) = parse_int(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, dcckt) = parse_name(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, met) = if rev <= 30 {
+        (input, 1)
+    } else {
+        terminated(parse_i8, parse_sep)(input)?
+    };
+
+    let (input, rdc) = parse_f64(input)?;
+
+    let (input, ldc) = parse_opt_field(parse_f64)(input)?;
+
+    let dc_link = DCLink {
+        idc,
+        jdc,
+        dcckt,
+        met,
+        rdc,
+        ldc: ldc.unwrap_or(0.0),
+    };
+
+    Ok((input, dc_link))
+}
+
+// The first line gives the number of converter, DC bus and DC link lines that follow.
+pub(crate) fn parse_raw_multi_terminal_dc_line(
+    input: &str,
+    rev: usize,
+) -> IResult<&str, MultiTerminalDCLine> {
+    let (input, line_id) = parse_raw_dc_line_id(input)?;
+
+    let (input, converters) = count(
+        preceded(parse_end_of_line, parse_raw_ac_converter),
+        line_id.nconv.max(0) as usize,
+    )(input)?;
+    let (input, buses) = count(
+        preceded(parse_end_of_line, parse_raw_dc_bus),
+        line_id.ndcbs.max(0) as usize,
+    )(input)?;
+    let (input, links) = count(
+        preceded(parse_end_of_line, |input| parse_raw_dc_link(input, rev)),
+        line_id.ndcln.max(0) as usize,
+    )(input)?;
+
+    let dc_line = MultiTerminalDCLine {
+        line_id,
+        converters,
+        buses,
+        links,
+    };
+
+    Ok((input, dc_line))
+}
+
+// 'FACTS 1',  153,    0,1,     0.000,     0.000,1.01500,  50.000,  9999.000,0.90000,1.10000,1.00000,     0.000,0.05000,  100.0,   1,   0.00000,   0.00000,0,     0,'            '
+//
+// v34 FACTS device records add `nreg` after `remot`.
+pub(crate) fn parse_raw_facts_device(input: &str, rev: usize) -> IResult<&str, FACTSDevice> {
+    let def = FACTSDevice::default();
+
     let (input, _) = space0(input)?;
 
-    let (input, ql) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, name) = parse_name(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, i) = parse_bus_num(input)?;
+
+    let (input, j) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, mode) = parse_opt_field(parse_i8)(input)?;
+    let (input, pdes) = parse_opt_field(parse_f64)(input)?;
+    let (input, qdes) = parse_opt_field(parse_f64)(input)?;
+    let (input, vset) = parse_opt_field(parse_f64)(input)?;
+    let (input, shmx) = parse_opt_field(parse_f64)(input)?;
+    let (input, trmx) = parse_opt_field(parse_f64)(input)?;
+    let (input, vtmn) = parse_opt_field(parse_f64)(input)?;
+    let (input, vtmx) = parse_opt_field(parse_f64)(input)?;
+    let (input, vsmx) = parse_opt_field(parse_f64)(input)?;
+    let (input, imx) = parse_opt_field(parse_f64)(input)?;
+    let (input, linx) = parse_opt_field(parse_f64)(input)?;
+    let (input, rmpct) = parse_opt_field(parse_f64)(input)?;
+    let (input, owner) = parse_opt_field(parse_owner_num)(input)?;
+    let (input, set1) = parse_opt_field(parse_f64)(input)?;
+    let (input, set2) = parse_opt_field(parse_f64)(input)?;
+    let (input, vsref) = parse_opt_field(parse_i8)(input)?;
+    let (input, remot) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, nreg) = parse_since(rev, 34, parse_opt_field(parse_int))(input)?;
+    let (input, mname) = parse_opt_field(parse_name)(input)?;
+
+    let facts = FACTSDevice {
+        name,
+        i,
+        j: j.unwrap_or(def.j),
+        mode: mode.unwrap_or(def.mode),
+        pdes: pdes.unwrap_or(def.pdes),
+        qdes: qdes.unwrap_or(def.qdes),
+        vset: vset.unwrap_or(def.vset),
+        shmx: shmx.unwrap_or(def.shmx),
+        trmx: trmx.unwrap_or(def.trmx),
+        vtmn: vtmn.unwrap_or(def.vtmn),
+        vtmx: vtmx.unwrap_or(def.vtmx),
+        vsmx: vsmx.unwrap_or(def.vsmx),
+        imx: imx.unwrap_or(def.imx),
+        linx: linx.unwrap_or(def.linx),
+        rmpct: rmpct.unwrap_or(def.rmpct),
+        owner: owner.unwrap_or(def.owner),
+        set1: set1.unwrap_or(def.set1),
+        set2: set2.unwrap_or(def.set2),
+        vsref: vsref.unwrap_or(def.vsref),
+        remot: remot.unwrap_or(def.remot),
+        nreg,
+        mname: mname.unwrap_or(def.mname),
+    };
+
+    Ok((input, facts))
+}
+
+//   151,1,0,1,1.05000,0.95000,     0,100.0,'            ',   100.00,   2,   50.00,   1, -25.00
+//
+// v30 switched shunt records have no `adjm` or `stat` fields. v34 records add `nreg` after
+// `swrem`, and v35 records add `id` after `i` and a status `s_k` before each block.
+pub(crate) fn parse_raw_switched_shunt(input: &str, rev: usize) -> IResult<&str, SwitchedShunt> {
+    let def = SwitchedShunt::default();
+
     let (input, _) = space0(input)?;
 
-    let (input, ip) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, i) = parse_bus_num(input)?;
+
+    let (input, id) = parse_since(rev, 35, parse_opt_field(parse_array_string))(input)?;
+    let (input, modsw) = parse_opt_field(parse_i8)(input)?;
+    let (input, (adjm, stat)) = if rev <= 30 {
+        (input, (None, None))
+    } else {
+        pair(parse_opt_field(parse_bool), parse_opt_field(parse_i8))(input)?
+    };
+    let (input, vswhi) = parse_opt_field(parse_f64)(input)?;
+    let (input, vswlo) = parse_opt_field(parse_f64)(input)?;
+    let (input, swrem) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, nreg) = parse_since(rev, 34, parse_opt_field(parse_int))(input)?;
+    let (input, rmpct) = parse_opt_field(parse_f64)(input)?;
+    let (input, rmidnt) = parse_opt_field(parse_name)(input)?;
+    let (input, binit) = parse_opt_field(parse_f64)(input)?;
+
+    // Up to eight blocks of `(s_k, n_k, b_k)`; blocks that are left out are empty.
+    let (input, blocks) = many_m_n(
+        0,
+        8,
+        tuple((
+            parse_since(rev, 35, map(preceded(parse_sep, parse_i8), Some)),
+            preceded(parse_sep, parse_int::<i32>),
+            preceded(parse_sep, parse_f64),
+        )),
+    )(input)?;
+    let block = |k: usize| blocks.get(k).copied().unwrap_or((None, 0, 0.0));
+
+    let switched_shunt = SwitchedShunt {
+        i,
+        id,
+        modsw: modsw.unwrap_or(def.modsw),
+        adjm: adjm.unwrap_or(def.adjm),
+        stat: stat.unwrap_or(def.stat),
+        vswhi: vswhi.unwrap_or(def.vswhi),
+        vswlo: vswlo.unwrap_or(def.vswlo),
+        swrem: swrem.unwrap_or(def.swrem),
+        nreg,
+        rmpct: rmpct.unwrap_or(def.rmpct),
+        rmidnt: rmidnt.unwrap_or(def.rmidnt),
+        binit: binit.unwrap_or(def.binit),
+        s1: block(0).0,
+        n1: block(0).1,
+        b1: block(0).2,
+        s2: block(1).0,
+        n2: block(1).1,
+        b2: block(1).2,
+        s3: block(2).0,
+        n3: block(2).1,
+        b3: block(2).2,
+        s4: block(3).0,
+        n4: block(3).1,
+        b4: block(3).2,
+        s5: block(4).0,
+        n5: block(4).1,
+        b5: block(4).2,
+        s6: block(5).0,
+        n6: block(5).1,
+        b6: block(5).2,
+        s7: block(6).0,
+        n7: block(6).1,
+        b7: block(6).2,
+        s8: block(7).0,
+        n8: block(7).1,
+        b8: block(7).2,
+    };
+
+    Ok((input, switched_shunt))
+}
+
+//     1,  -30.00, 1.10000,    0.00, 1.00000,   30.00, 1.10000
+pub(crate) fn parse_raw_impedance_correction(input: &str) -> IResult<&str, ImpedanceCorrection> {
     let (input, _) = space0(input)?;
 
-    let (input, iq) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, i) = parse_int(input)?;
+
+    // At least 2 and up to 11 `(t_i, f_i)` points; points that are left out are zero.
+    let (input, points) = many_m_n(
+        2,
+        11,
+        pair(
+            preceded(parse_sep, parse_f64),
+            preceded(parse_sep, parse_f64),
+        ),
+    )(input)?;
+    let point = |k: usize| points.get(k).copied().unwrap_or((0.0, 0.0));
+
+    let impedance_correction = ImpedanceCorrection {
+        i,
+        t1: point(0).0,
+        f1: point(0).1,
+        t2: point(1).0,
+        f2: point(1).1,
+        t3: point(2).0,
+        f3: point(2).1,
+        t4: point(3).0,
+        f4: point(3).1,
+        t5: point(4).0,
+        f5: point(4).1,
+        t6: point(5).0,
+        f6: point(5).1,
+        t7: point(6).0,
+        f7: point(6).1,
+        t8: point(7).0,
+        f8: point(7).1,
+        t9: point(8).0,
+        f9: point(8).1,
+        t10: point(9).0,
+        f10: point(9).1,
+        t11: point(10).0,
+        f11: point(10).1,
+        f_im: None,
+    };
+
+    Ok((input, impedance_correction))
+}
+
+//     1,  -30.00, 1.10000, 0.00000,    0.00, 1.00000, 0.00000,   30.00, 1.10000, 0.00000
+//          0.00, 0.00000, 0.00000
+//
+// v34 tables have complex scaling factors and may continue over several lines;
+// they end with a `0.0, 0.0, 0.0` point.
+pub(crate) fn parse_raw_impedance_correction_v34(
+    input: &str,
+) -> IResult<&str, ImpedanceCorrection> {
     let (input, _) = space0(input)?;
 
-    let (input, yp) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, i) = parse_int(input)?;
+
+    let point = tuple((
+        parse_f64,
+        preceded(parse_sep, parse_f64),
+        preceded(parse_sep, parse_f64),
+    ));
+    let mut point = preceded(
+        alt((parse_sep, terminated(parse_end_of_line, space0))),
+        point,
+    );
+
+    let mut points = Vec::new();
+    let mut input = input;
+    loop {
+        let (rest, (t, re, im)) = point(input)?;
+        input = rest;
+        if t == 0.0 && re == 0.0 && im == 0.0 {
+            break;
+        }
+        if points.len() == 11 {
+            return Err(verify_error(input));
+        }
+        points.push((t, re, im));
+    }
+    let point = |k: usize| points.get(k).copied().unwrap_or((0.0, 0.0, 0.0));
+
+    let f_im = std::array::from_fn(|k| point(k).2);
+
+    let impedance_correction = ImpedanceCorrection {
+        i,
+        t1: point(0).0,
+        f1: point(0).1,
+        t2: point(1).0,
+        f2: point(1).1,
+        t3: point(2).0,
+        f3: point(2).1,
+        t4: point(3).0,
+        f4: point(3).1,
+        t5: point(4).0,
+        f5: point(4).1,
+        t6: point(5).0,
+        f6: point(5).1,
+        t7: point(6).0,
+        f7: point(6).1,
+        t8: point(7).0,
+        f8: point(7).1,
+        t9: point(8).0,
+        f9: point(8).1,
+        t10: point(9).0,
+        f10: point(9).1,
+        t11: point(10).0,
+        f11: point(10).1,
+        f_im: Some(f_im),
+    };
+
+    Ok((input, impedance_correction))
+}
+
+//   151,  152,'&1',1,  9501,  9502
+//
+// v30 multi-section line records have no `met` field; the `i` end is metered.
+pub(crate) fn parse_raw_multi_section_line_group(
+    input: &str,
+    rev: usize,
+) -> IResult<&str, MultiSectionLineGroup> {
     let (input, _) = space0(input)?;
 
-    let (input, yq) = parse_f64(input)?;
-    let (input, _) = char(',')(input)?;
+    let (input, i) = parse_bus_num(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, j) = parse_metered_bus_num(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, id) = parse_array_string(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, met) = if rev <= 30 {
+        (input, 1)
+    } else {
+        terminated(parse_i8, parse_sep)(input)?
+    };
+
+    let (input, dum1) = parse_bus_num(input)?;
+
+    let (input, dum2) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, dum3) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, dum4) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, dum5) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, dum6) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, dum7) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, dum8) = parse_opt_field(parse_bus_num)(input)?;
+    let (input, dum9) = parse_opt_field(parse_bus_num)(input)?;
+
+    let multi_section_line = MultiSectionLineGroup {
+        i,
+        j,
+        id,
+        met,
+        dum1,
+        dum2,
+        dum3,
+        dum4,
+        dum5,
+        dum6,
+        dum7,
+        dum8,
+        dum9,
+    };
+
+    Ok((input, multi_section_line))
+}
+
+// GENERAL, THRSHZ=0.0001, PQBRAK=0.7, BLOWUP=5.0
+// RATING, 1, "RATE1 ", "RATING SET 1                    "
+pub(crate) fn parse_raw_system_wide_record(input: &str) -> IResult<&str, SystemWideRecord> {
     let (input, _) = space0(input)?;
 
-    let (input, owner) = parse_owner_num(input)?;
-    let (input, _) = opt(char(','))(input)?;
+    let (input, name) = take_while1(|c: char| c.is_alphanumeric() || c == '_')(input)?;
+
+    let field = alt((
+        recognize(delimited(char('\''), take_until("'"), char('\''))),
+        recognize(delimited(char('"'), take_until("\""), char('"'))),
+        take_while1(|c: char| !matches!(c, ',' | '/' | ' ' | '\t' | '\r' | '\n')),
+    ));
+    let (input, fields) = many0(preceded(parse_sep, field))(input)?;
+
+    let record = SystemWideRecord {
+        name: name.to_string(),
+        fields: fields.into_iter().map(str::to_string).collect(),
+    };
+
+    Ok((input, record))
+}
+
+//     1,'STATION 1                               ',   0.0000,   0.0000,  0.1000
+//      1,'NB1                                     ',  101,   1,1.0000,   0.0000
+// 0 / END OF SUBSTATION NODE DATA, BEGIN SUBSTATION SWITCHING DEVICE DATA
+//      1,     2,'1 ','BREAKER 1                               ', 2, 1, 1, 0.00010,  0.00,  0.00,  0.00
+// 0 / END OF SUBSTATION SWITCHING DEVICE DATA, BEGIN SUBSTATION EQUIPMENT TERMINAL DATA
+//    101,     1,'M','1 '
+// 0 / END OF SUBSTATION EQUIPMENT TERMINAL DATA
+//
+// Each substation record is followed by its node, switching device and equipment terminal data.
+pub(crate) fn parse_raw_substation(input: &str) -> IResult<&str, Substation> {
     let (input, _) = space0(input)?;
 
-    let (input, scale) = parse_optional_bool(input)?;
-    let (input, _) = opt(char(','))(input)?;
+    let (input, is) = parse_int(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, name) = parse_array_string(input)?;
+
+    let (input, lati) = parse_opt_field(parse_f64)(input)?;
+    let (input, long) = parse_opt_field(parse_f64)(input)?;
+    let (input, srg) = parse_opt_field(parse_f64)(input)?;
+    let (input, _) = parse_end_of_line(input)?;
+
+    let (input, nodes) = parse_block(parse_raw_substation_node)(input)?;
+    let (input, switching_devices) = parse_block(parse_raw_switching_device)(input)?;
+    let (input, terminals) = parse_records(parse_raw_equipment_terminal)(input)?;
+    // The rest of the terminator is left to end the record.
+    let (input, _) = tuple((parse_data_headers, space0, char('0')))(input)?;
+
+    let substation = Substation {
+        is,
+        name,
+        lati: lati.unwrap_or(0.0),
+        long: long.unwrap_or(0.0),
+        srg: srg.unwrap_or(0.1),
+        nodes,
+        switching_devices,
+        terminals,
+    };
+
+    Ok((input, substation))
+}
+
+//      1,'NB1                                     ',  101,   1,1.0000,   0.0000
+fn parse_raw_substation_node(input: &str) -> IResult<&str, SubstationNode> {
     let (input, _) = space0(input)?;
 
-    let (input, intrpt) = parse_optional_bool(input)?;
-    let (input, _) = opt(char(','))(input)?;
+    let (input, ni) = parse_int(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, name) = parse_array_string(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, i) = parse_bus_num(input)?;
+
+    let (input, status) = parse_opt_field(parse_i8)(input)?;
+    let (input, vm) = parse_opt_field(parse_f64)(input)?;
+    let (input, va) = parse_opt_field(parse_f64)(input)?;
+
+    let node = SubstationNode {
+        ni,
+        name,
+        i,
+        status: status.unwrap_or(IN_SERVICE),
+        vm: vm.unwrap_or(1.0),
+        va: va.unwrap_or(0.0),
+    };
+
+    Ok((input, node))
+}
+
+//      1,     2,'1 ','BREAKER 1                               ', 2, 1, 1, 0.00010,  0.00,  0.00,  0.00
+fn parse_raw_switching_device(input: &str) -> IResult<&str, SwitchingDevice> {
     let (input, _) = space0(input)?;
 
-    let load = Load {
+    let (input, ni) = parse_int(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, nj) = parse_int(input)?;
+
+    let (input, ckt) = parse_opt_field(parse_name)(input)?;
+    let (input, name) = parse_opt_field(parse_array_string)(input)?;
+    let (input, typ) = parse_opt_field(parse_i8)(input)?;
+    let (input, status) = parse_opt_field(parse_i8)(input)?;
+    let (input, nstat) = parse_opt_field(parse_i8)(input)?;
+    let (input, x) = parse_opt_field(parse_f64)(input)?;
+    let (input, rate1) = parse_opt_field(parse_f64)(input)?;
+    let (input, rate2) = parse_opt_field(parse_f64)(input)?;
+    let (input, rate3) = parse_opt_field(parse_f64)(input)?;
+    let (input, rates) = parse_opt_field(parse_rates)(input)?;
+
+    let switching_device = SwitchingDevice {
+        ni,
+        nj,
+        ckt: ckt.unwrap_or_else(|| ArrayString::from("1").unwrap()),
+        name: name.unwrap_or_default(),
+        typ: typ.unwrap_or(1),
+        status: status.unwrap_or(IN_SERVICE),
+        nstat: nstat.unwrap_or(IN_SERVICE),
+        x: x.unwrap_or(0.0001),
+        rate1: rate1.unwrap_or(0.0),
+        rate2: rate2.unwrap_or(0.0),
+        rate3: rate3.unwrap_or(0.0),
+        rates,
+    };
+
+    Ok((input, switching_device))
+}
+
+//    101,     1,'M','1 '
+//    151,     2,'B',   152,'1 '
+//
+// Branches and two-winding transformers give the other bus, and three-winding
+// transformers the other two buses, before the equipment identifier.
+fn parse_raw_equipment_terminal(input: &str) -> IResult<&str, EquipmentTerminal> {
+    let (input, _) = space0(input)?;
+
+    let (input, i) = parse_bus_num(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, ni) = parse_int(input)?;
+    let (input, _) = parse_sep(input)?;
+
+    let (input, typ) = parse_name::<1>(input)?;
+
+    let buses = match typ.as_str() {
+        "B" | "2" => 1,
+        "3" => 2,
+        _ => 0,
+    };
+    let (input, others) = count(preceded(parse_sep, parse_metered_bus_num), buses)(input)?;
+
+    let (input, _) = parse_sep(input)?;
+    let (input, id) = parse_name(input)?;
+
+    let terminal = EquipmentTerminal {
         i,
+        ni,
+        typ,
+        j: others.first().copied().unwrap_or(0),
+        k: others.get(1).copied().unwrap_or(0),
         id,
-        status,
-        area,
-        zone,
-        pl,
-        ql,
-        ip,
-        iq,
-        yp,
-        yq,
-        owner,
-        scale,
-        intrpt,
     };
 
-    Ok((input, load))
+    Ok((input, terminal))
 }
 
-pub(crate) fn parse_raw_loads(input: &str) -> IResult<&str, Vec<Load>> {
-    separated_list1(newline, parse_raw_load)(input)
+// Case identification data is followed by two lines of free text.
+fn parse_title_lines(input: &str) -> IResult<&str, [String; 2]> {
+    let (input, _) = terminated(not_line_ending, line_ending)(input)?;
+    let (input, title1) = terminated(not_line_ending, line_ending)(input)?;
+    let (input, title2) = terminated(not_line_ending, line_ending)(input)?;
+    Ok((input, [title1.to_string(), title2.to_string()]))
 }
 
-pub fn parse_raw_case(input: &str) -> IResult<&str, Network> {
-    let (input, caseid) = parse_raw_case_id(input)?;
-    let (input, buses) = parse_raw_buses(input)?;
-    let (input, _) = parse_zero_line(input)?;
-    let (input, loads) = parse_raw_loads(input)?;
+/// Options for [parse_raw_case].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Recover from bad records instead of failing. A record with extra fields
+    /// is read without them, a record that ends early has its missing fields
+    /// defaulted, and any other bad record, including a bus with the number of
+    /// an earlier bus, is skipped. Each is reported as a [Diagnostic].
+    pub lenient: bool,
+}
+
+/// Parses a PSS/E RAW case.
+///
+/// The record layouts are chosen from the revision in the case identification
+/// data: revision 30 and earlier are read as v30, revisions 34 and 35 with
+/// their own layouts, and anything else as v33. A revision that is missing, or
+/// not one of 30 to 35, is replaced with the one guessed by [detect_revision],
+/// as is one in whose layouts the case cannot be read.
+/// v30 bus shunt admittances are returned as [FixedShunt] records.
+///
+/// GNE device and induction machine data are skipped.
+///
+/// Buses may be given by extended bus name, e.g. `'STBC        161.00'`, in
+/// place of bus numbers. Names are resolved once the bus data has been read.
+///
+/// A record that cannot be read, a duplicate bus number, or a bus name that
+/// matches no bus or more than one, is reported with its line and column in
+/// `input`. In lenient mode (see [ParseOptions]) such records are dropped and
+/// reported in the returned diagnostics, ordered by line; only a case whose
+/// identification data cannot be read is an error.
+pub fn parse_raw_case(
+    input: &str,
+    options: ParseOptions,
+) -> Result<(Network, Vec<Diagnostic>), ParseError> {
+    let lenient = options.lenient.then(|| Lenient::new(input));
+    let (_, mut network) = parse_raw_network(input, lenient.as_ref())
+        .map_err(|err| ParseError::from_nom(input, err))?;
+
+    let mut diagnostics = lenient.map(Lenient::into_diagnostics);
+    resolve_bus_names(&mut network, input, diagnostics.as_mut())?;
+    let mut diagnostics = diagnostics.unwrap_or_default();
+    diagnostics.sort_by_key(|diagnostic| diagnostic.line);
+    Ok((network, diagnostics))
+}
+
+/// Reads and parses a PSS/E RAW case file. See [parse_raw_case].
+pub fn parse_raw_file(
+    path: impl AsRef<Path>,
+    options: ParseOptions,
+) -> Result<(Network, Vec<Diagnostic>), ParseError> {
+    let path = path.as_ref();
+    let input =
+        fs::read_to_string(path).map_err(|err| ParseError::io(&path.display().to_string(), err))?;
+    parse_raw_case(&input, options)
+}
+
+/// Guesses the revision of a RAW case, for cases with a missing or wrong `rev`
+/// in the case identification data.
+///
+/// The layout of the case narrows down the revision: system-wide data is
+/// written from revision 34, and bus, load and generator records gain fields
+/// with revisions 31, 34 and 35. The `rev` field, or failing that the revision
+/// in the header comment (e.g. `PSS(R)E-33.5`), is returned if it fits the
+/// layout. Otherwise the latest revision that fits is returned, or 33 for
+/// revisions 31 to 33, which share their layouts.
+///
+/// Returns `None` if the case identification data cannot be read, or if the
+/// case has no records and no revision in its header.
+pub fn detect_revision(input: &str) -> Option<u32> {
+    let mut lines = input
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("@!"));
+    let (_, caseid) = parse_raw_case_id(lines.next()?).ok()?;
+    let header = [
+        caseid.rev.and_then(|rev| u32::try_from(rev).ok()),
+        caseid.comment.as_deref().and_then(comment_revision),
+    ];
+
+    // The records of each section, up to the `Q` record.
+    let mut sections = vec![Vec::new()];
+    let mut terminators = Vec::new();
+    for line in lines.skip(2) {
+        if parse_q_line(line).is_ok() {
+            break;
+        }
+        if parse_zero_line(line).is_ok() {
+            terminators.push(line);
+            sections.push(Vec::new());
+        } else if !line.trim().is_empty() {
+            sections.last_mut()?.push(line);
+        }
+    }
+    let fields = |k: usize| {
+        let records = sections.get(k)?;
+        records.iter().map(|line| count_fields(line)).max()
+    };
+
+    // Narrows `revs` to `from..=to`, unless that leaves nothing.
+    let mut revs = 0..=u32::MAX;
+    let mut narrow = |from: u32, to: u32| {
+        let (from, to) = (from.max(*revs.start()), to.min(*revs.end()));
+        if from <= to {
+            revs = from..=to;
+        }
+    };
+
+    let starts_with_letter = |line: &&str| line.trim_start().starts_with(char::is_alphabetic);
+    let system_wide = sections[0].first().is_some_and(starts_with_letter)
+        || terminators
+            .first()
+            .is_some_and(|line| line.contains("SYSTEM-WIDE"));
+    let bus = usize::from(system_wide);
+    if system_wide {
+        narrow(34, u32::MAX);
+    } else if !sections[0].is_empty() {
+        narrow(0, 33);
+    }
+    let v30 = match fields(bus) {
+        Some(..=11) => {
+            narrow(0, 30);
+            true
+        }
+        Some(_) => {
+            narrow(31, u32::MAX);
+            false
+        }
+        None => false,
+    };
+    match fields(bus + 1) {
+        Some(..=12) => narrow(0, 30),
+        Some(13..=14) => narrow(31, 33),
+        Some(15..=17) => narrow(34, 34),
+        Some(_) => narrow(35, u32::MAX),
+        None => {}
+    }
+    // v30 cases have no fixed shunt data before the generators.
+    let generator = if v30 { bus + 2 } else { bus + 3 };
+    if let Some(29..) = fields(generator) {
+        narrow(34, u32::MAX);
+    }
+
+    if let Some(rev) = header.into_iter().flatten().find(|rev| revs.contains(rev)) {
+        return Some(rev);
+    }
+    if revs == (0..=u32::MAX) {
+        return None;
+    }
+    Some(33.clamp(*revs.start(), *revs.end()))
+}
+
+// The revision in a header comment such as `PSS(R)E-33.5` or `PSS/E-30.3`.
+fn comment_revision(comment: &str) -> Option<u32> {
+    let (_, rest) = comment.split_once("PSS")?;
+    let (product, version) = rest.split_once('-')?;
+    if !product.ends_with('E') {
+        return None;
+    }
+    let digits = version
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(version.len());
+    version[..digits].parse().ok()
+}
+
+// The number of fields in a record line: values separated by commas or spaces,
+// including blank fields between commas, up to any `/` comment.
+fn count_fields(line: &str) -> usize {
+    let mut fields = 0;
+    let mut quote = None;
+    let mut in_value = false;
+    let mut after_comma = true;
+    for c in line.chars() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '/' => break,
+            ',' => {
+                if after_comma {
+                    fields += 1;
+                }
+                in_value = false;
+                after_comma = true;
+            }
+            ' ' | '\t' | '\r' => in_value = false,
+            _ => {
+                if c == '\'' || c == '"' {
+                    quote = Some(c);
+                }
+                if !in_value {
+                    fields += 1;
+                }
+                in_value = true;
+                after_comma = false;
+            }
+        }
+    }
+    fields
+}
+
+fn parse_raw_network<'a>(
+    input: &'a str,
+    lenient: Option<&Lenient<'a>>,
+) -> IResult<&'a str, Network> {
+    let case = input;
+    let (input, _) = parse_data_headers(input)?;
+    let (input, mut caseid) = in_section("CASE IDENTIFICATION DATA", parse_raw_case_id)(input)?;
+    let (input, titles) = parse_title_lines(input)?;
+    caseid.titles = titles;
+    let header = header_revision(&caseid);
+    caseid.rev = case_revision(&caseid, case);
+
+    let parse = |caseid: CaseID| match caseid.rev {
+        Some(rev) if rev <= 30 => parse_raw_sections_v30(input, caseid, lenient),
+        _ => parse_raw_sections(input, caseid, lenient),
+    };
+    let (input, network) = match (parse(caseid.clone()), header) {
+        // A case that cannot be read in the layouts of its header revision is
+        // tried in those that its records fit.
+        (Err(err), Some(rev)) => match detect_revision(case).map(|rev| rev as usize) {
+            Some(detected) if detected != rev => {
+                if let Some(lenient) = lenient {
+                    lenient.clear();
+                }
+                caseid.rev = Some(detected);
+                parse(caseid).map_err(|_| err)?
+            }
+            _ => return Err(err),
+        },
+        (result, _) => result?,
+    };
+    let (input, _) = opt(parse_q_line)(input)?;
+
+    Ok((input, network))
+}
+
+// The revision of the case identification data, if it is one of 30 to 35.
+pub(crate) fn header_revision(caseid: &CaseID) -> Option<usize> {
+    caseid.rev.filter(|rev| (30..=35).contains(rev))
+}
+
+// The revision whose layouts a case is read with: that of its header if it is
+// one of 30 to 35, or else the one guessed from `input`, which need only hold
+// the case up to its generator data.
+pub(crate) fn case_revision(caseid: &CaseID, input: &str) -> Option<usize> {
+    header_revision(caseid)
+        .or_else(|| detect_revision(input).map(|rev| rev as usize))
+        .or(caseid.rev)
+}
+
+// Cases from v31 onwards share the v33 section order. v34 cases start with system-wide
+// data, and v35 cases end with substation data.
+fn parse_raw_sections<'a>(
+    input: &'a str,
+    caseid: CaseID,
+    lenient: Option<&Lenient<'a>>,
+) -> IResult<&'a str, Network> {
+    let rev = caseid.rev.unwrap_or(33);
+
+    let (input, system_wide) = if rev >= 34 {
+        parse_section(lenient, "SYSTEM-WIDE DATA", parse_raw_system_wide_record)(input)?
+    } else {
+        (input, Vec::new())
+    };
+    let mut numbers = HashSet::new();
+    let (input, buses) = parse_section(lenient, "BUS DATA", |input| {
+        let (rest, bus) = Bus::parse_raw(input, rev)?;
+        verify_new_bus(input, bus.i, &mut numbers)?;
+        Ok((rest, bus))
+    })(input)?;
+    let (input, loads) =
+        parse_section(lenient, "LOAD DATA", |input| Load::parse_raw(input, rev))(input)?;
+    let (input, fixed_shunts) = parse_section(lenient, "FIXED SHUNT DATA", |input| {
+        FixedShunt::parse_raw(input, rev)
+    })(input)?;
+    let (input, generators) = parse_section(lenient, "GENERATOR DATA", |input| {
+        Generator::parse_raw(input, rev)
+    })(input)?;
+    let (input, branches) = parse_section(lenient, "BRANCH DATA", |input| {
+        Branch::parse_raw(input, rev)
+    })(input)?;
+    let (input, system_switching_devices) = if rev >= 34 {
+        parse_section(lenient, "SYSTEM SWITCHING DEVICE DATA", |input| {
+            SystemSwitchingDevice::parse_raw(input, rev)
+        })(input)?
+    } else {
+        (input, Vec::new())
+    };
+    let ctx = TransformerContext::new(caseid.sbase, &buses);
+    let (input, transformers) = parse_section(lenient, "TRANSFORMER DATA", |input| {
+        parse_raw_transformer(input, &ctx, rev)
+    })(input)?;
+    let (input, area_interchanges) = parse_section(lenient, "AREA DATA", |input| {
+        AreaInterchange::parse_raw(input, rev)
+    })(input)?;
+    let (input, two_terminal_dc) = parse_section(lenient, "TWO-TERMINAL DC DATA", |input| {
+        parse_raw_two_terminal_dc_line(input, rev)
+    })(input)?;
+    let (input, vsc_dc) = parse_section(lenient, "VSC DC LINE DATA", |input| {
+        parse_raw_vsc_dc_line(input, rev)
+    })(input)?;
+    let (input, impedance_corrections) = if rev >= 34 {
+        parse_section(
+            lenient,
+            "IMPEDANCE CORRECTION DATA",
+            parse_raw_impedance_correction_v34,
+        )(input)?
+    } else {
+        parse_section(
+            lenient,
+            "IMPEDANCE CORRECTION DATA",
+            parse_raw_impedance_correction,
+        )(input)?
+    };
+    let (input, multi_terminal_dc) = parse_section(lenient, "MULTI-TERMINAL DC DATA", |input| {
+        parse_raw_multi_terminal_dc_line(input, rev)
+    })(input)?;
+    let (input, multi_section_lines) =
+        parse_section(lenient, "MULTI-SECTION LINE DATA", |input| {
+            parse_raw_multi_section_line_group(input, rev)
+        })(input)?;
+    let (input, zones) =
+        parse_section(lenient, "ZONE DATA", |input| Zone::parse_raw(input, rev))(input)?;
+    let (input, area_transfers) = parse_section(lenient, "INTER-AREA TRANSFER DATA", |input| {
+        InterAreaTransfer::parse_raw(input, rev)
+    })(input)?;
+    let (input, owners) =
+        parse_section(lenient, "OWNER DATA", |input| Owner::parse_raw(input, rev))(input)?;
+    let (input, facts) = parse_section(lenient, "FACTS DEVICE DATA", |input| {
+        parse_raw_facts_device(input, rev)
+    })(input)?;
+    let (input, switched_shunts) = parse_section(lenient, "SWITCHED SHUNT DATA", |input| {
+        parse_raw_switched_shunt(input, rev)
+    })(input)?;
+    let (input, _) = skip_section(input)?; // GNE devices
+    let (input, _) = skip_section(input)?; // induction machines
+    let (input, substations) = if rev >= 35 {
+        parse_section(lenient, "SUBSTATION DATA", parse_raw_substation)(input)?
+    } else {
+        (input, Vec::new())
+    };
+
+    let network = Network {
+        version: rev as i8,
+        caseid,
+        system_wide,
+        buses,
+        loads,
+        fixed_shunts,
+        generators,
+        branches,
+        system_switching_devices,
+        transformers,
+        area_interchanges,
+        two_terminal_dc,
+        vsc_dc,
+        switched_shunts,
+        impedance_corrections,
+        multi_terminal_dc,
+        multi_section_lines,
+        zones,
+        area_transfers,
+        owners,
+        facts,
+        substations,
+    };
+
+    Ok((input, network))
+}
+
+// v30 cases have no fixed shunt, GNE device or induction machine sections, and
+// switched shunt data comes straight after the VSC DC lines.
+fn parse_raw_sections_v30<'a>(
+    input: &'a str,
+    caseid: CaseID,
+    lenient: Option<&Lenient<'a>>,
+) -> IResult<&'a str, Network> {
+    let mut numbers = HashSet::new();
+    let (input, bus_records) = parse_section(lenient, "BUS DATA", |input| {
+        let (rest, record) = parse_raw_bus_v30(input)?;
+        verify_new_bus(input, record.0.i, &mut numbers)?;
+        Ok((rest, record))
+    })(input)?;
+    let (buses, fixed_shunts): (Vec<_>, Vec<_>) = bus_records.into_iter().unzip();
+    let fixed_shunts = fixed_shunts.into_iter().flatten().collect();
+    let (input, loads) =
+        parse_section(lenient, "LOAD DATA", |input| Load::parse_raw(input, 30))(input)?;
+    let (input, generators) = parse_section(lenient, "GENERATOR DATA", |input| {
+        Generator::parse_raw(input, 30)
+    })(input)?;
+    let (input, branches) =
+        parse_section(lenient, "BRANCH DATA", |input| Branch::parse_raw(input, 30))(input)?;
+    let ctx = TransformerContext::new(caseid.sbase, &buses);
+    let (input, transformers) = parse_section(lenient, "TRANSFORMER DATA", |input| {
+        parse_raw_transformer(input, &ctx, 30)
+    })(input)?;
+    let (input, area_interchanges) = parse_section(lenient, "AREA DATA", |input| {
+        AreaInterchange::parse_raw(input, 30)
+    })(input)?;
+    let (input, two_terminal_dc) = parse_section(lenient, "TWO-TERMINAL DC DATA", |input| {
+        parse_raw_two_terminal_dc_line(input, 30)
+    })(input)?;
+    let (input, vsc_dc) = parse_section(lenient, "VSC DC LINE DATA", |input| {
+        parse_raw_vsc_dc_line(input, 30)
+    })(input)?;
+    let (input, switched_shunts) = parse_section(lenient, "SWITCHED SHUNT DATA", |input| {
+        parse_raw_switched_shunt(input, 30)
+    })(input)?;
+    let (input, impedance_corrections) = parse_section(
+        lenient,
+        "IMPEDANCE CORRECTION DATA",
+        parse_raw_impedance_correction,
+    )(input)?;
+    let (input, multi_terminal_dc) = parse_section(lenient, "MULTI-TERMINAL DC DATA", |input| {
+        parse_raw_multi_terminal_dc_line(input, 30)
+    })(input)?;
+    let (input, multi_section_lines) =
+        parse_section(lenient, "MULTI-SECTION LINE DATA", |input| {
+            parse_raw_multi_section_line_group(input, 30)
+        })(input)?;
+    let (input, zones) =
+        parse_section(lenient, "ZONE DATA", |input| Zone::parse_raw(input, 30))(input)?;
+    let (input, area_transfers) = parse_section(lenient, "INTER-AREA TRANSFER DATA", |input| {
+        InterAreaTransfer::parse_raw(input, 30)
+    })(input)?;
+    let (input, owners) =
+        parse_section(lenient, "OWNER DATA", |input| Owner::parse_raw(input, 30))(input)?;
+    let (input, facts) = parse_section(lenient, "FACTS DEVICE DATA", |input| {
+        parse_raw_facts_device(input, 30)
+    })(input)?;
 
     let network = Network {
-        version: 0,
+        version: 30,
         caseid,
+        system_wide: Vec::new(),
         buses,
         loads,
-        fixed_shunts: vec![],
-        generators: vec![],
-        branches: vec![],
-        transformers: vec![],
-        area_interchanges: vec![],
-        two_terminal_dc: vec![],
-        vsc_dc: vec![],
-        switched_shunts: vec![],
-        impedance_corrections: vec![],
-        multi_terminal_dc: vec![],
-        multi_section_lines: vec![],
-        zones: vec![],
-        area_transfers: vec![],
-        owners: vec![],
-        facts: vec![],
+        fixed_shunts,
+        generators,
+        branches,
+        system_switching_devices: Vec::new(),
+        transformers,
+        area_interchanges,
+        two_terminal_dc,
+        vsc_dc,
+        switched_shunts,
+        impedance_corrections,
+        multi_terminal_dc,
+        multi_section_lines,
+        zones,
+        area_transfers,
+        owners,
+        facts,
+        substations: Vec::new(),
     };
 
     Ok((input, network))
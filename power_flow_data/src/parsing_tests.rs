@@ -1,10 +1,15 @@
-use crate::{Bus, CaseID, Load};
+use crate::parsing::TransformerContext;
+use crate::parsing::{detect_revision, parse_raw_case, parse_raw_file, ParseOptions};
+use crate::traits::{RawField, RawRecord};
+use crate::{
+    Branch, Bus, CaseID, Generator, Load, ParseError, ParseErrorKind, Severity, SwitchedShunt,
+    Transformer,
+};
 use arrayvec::ArrayString;
 
 #[test]
 fn test_parse_raw_case_id() {
-    // let input = " 0,    100.00, 33, 0, 0, 60.00       / May 16, 2017 17:17:11; Simulator Version 20 Beta; BuildDate 2017_5_15";
-    let input = " 0,    100.00, 33, 0, 0, 60.00";
+    let input = " 0,    100.00, 33, 0, 0, 60.00       / May 16, 2017 17:17:11; Simulator Version 20 Beta; BuildDate 2017_5_15";
     let expected = CaseID {
         ic: 0,
         sbase: 100.0,
@@ -12,6 +17,10 @@ fn test_parse_raw_case_id() {
         xfrrat: Some(0),
         nxfrat: Some(0),
         basfrq: Some(60.0),
+        comment: Some(
+            "May 16, 2017 17:17:11; Simulator Version 20 Beta; BuildDate 2017_5_15".to_string(),
+        ),
+        titles: Default::default(),
     };
     assert_eq!(
         crate::parsing::parse_raw_case_id(input).unwrap().1,
@@ -26,7 +35,7 @@ fn test_parse_raw_load() {
     let expected = Load {
         i: 111,
         id: ArrayString::from("G1").unwrap(),
-        status: true,
+        status: 1,
         area: 227,
         zone: 1,
         pl: -0.004,
@@ -38,8 +47,12 @@ fn test_parse_raw_load() {
         owner: 1,
         scale: None,
         intrpt: None,
+        dgenp: None,
+        dgenq: None,
+        dgenm: None,
+        loadtype: None,
     };
-    assert_eq!(crate::parsing::parse_raw_load(input).unwrap().1, expected);
+    assert_eq!(Load::parse_raw(input, 33).unwrap().1, expected);
 }
 
 #[test]
@@ -51,7 +64,7 @@ fn test_parse_raw_loads() {
         Load {
             i: 111,
             id: ArrayString::from("G1").unwrap(),
-            status: true,
+            status: 1,
             area: 227,
             zone: 1,
             pl: -0.004,
@@ -63,11 +76,15 @@ fn test_parse_raw_loads() {
             owner: 1,
             scale: None,
             intrpt: None,
+            dgenp: None,
+            dgenq: None,
+            dgenm: None,
+            loadtype: None,
         },
         Load {
             i: 113,
             id: ArrayString::from(" G2").unwrap(),
-            status: true,
+            status: 1,
             area: 227,
             zone: 1,
             pl: 0.345,
@@ -79,9 +96,18 @@ fn test_parse_raw_loads() {
             owner: 2,
             scale: None,
             intrpt: None,
+            dgenp: None,
+            dgenq: None,
+            dgenm: None,
+            loadtype: None,
         },
     ];
-    assert_eq!(crate::parsing::parse_raw_loads(input).unwrap().1, expected);
+    assert_eq!(
+        crate::parsing::parse_records(|input| Load::parse_raw(input, 33))(input)
+            .unwrap()
+            .1,
+        expected
+    );
 }
 
 #[test]
@@ -103,6 +129,836 @@ fn test_parse_raw_bus() {
         evhi: 1.1,
         evlo: 0.9,
     };
-    let actual = crate::parsing::parse_raw_bus(input).unwrap().1;
+    let actual = Bus::parse_raw(input, 33).unwrap().1;
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_parse_raw_fields() {
+    for (input, expected) in [
+        ("1.5E-3", 1.5e-3),
+        (".95", 0.95),
+        ("1.", 1.0),
+        ("+0.5", 0.5),
+        ("-2e+2", -200.0),
+        ("7", 7.0),
+    ] {
+        assert_eq!(f64::parse_field(input).unwrap(), ("", expected));
+    }
+    assert_eq!(i32::parse_field("+101").unwrap(), ("", 101));
+    assert!(f64::parse_field("E5").is_err());
+
+    // Blank fields take their defaults, and fields may be delimited by tabs or spaces.
+    let input = "101\t'NUC-A'  21.6, 2,,, +1,.995,-1.5E1,,0.85,1.2 ,\t";
+    let expected = Bus {
+        i: 101,
+        name: ArrayString::from("NUC-A").unwrap(),
+        basekv: 21.6,
+        ide: 2,
+        vm: 0.995,
+        va: -15.0,
+        nvlo: 0.85,
+        evhi: 1.2,
+        ..Bus::default()
+    };
+    assert_eq!(Bus::parse_raw(input, 33).unwrap(), ("", expected));
+}
+
+#[test]
+fn test_parse_raw_generator() {
+    let input = "  101,'1 ',   750.000,    81.198,   600.000,  -100.000,1.02000,     0,   900.000, 0.01000, 0.30000, 0.00000, 0.00000,1.00000,1,  100.0,   810.000,     0.000,   1,1.0000,   0,1.0000,   0,1.0000,   0,1.0000,0, 1.0000";
+    let expected = Generator {
+        i: 101,
+        id: ArrayString::from("1 ").unwrap(),
+        pg: 750.0,
+        qg: 81.198,
+        qt: 600.0,
+        qb: -100.0,
+        vs: 1.02,
+        ireg: 0,
+        nreg: None,
+        mbase: 900.0,
+        zr: 0.01,
+        zx: 0.3,
+        rt: 0.0,
+        xt: 0.0,
+        gtap: 1.0,
+        stat: 1,
+        rmpct: 100.0,
+        pt: 810.0,
+        pb: 0.0,
+        baslod: None,
+        o1: 1,
+        f1: Some(1.0),
+        o2: Some(0),
+        f2: Some(1.0),
+        o3: Some(0),
+        f3: Some(1.0),
+        o4: Some(0),
+        f4: Some(1.0),
+        wmod: Some(0),
+        wpf: Some(1.0),
+    };
+    assert_eq!(Generator::parse_raw(input, 33).unwrap().1, expected);
+}
+
+#[test]
+fn test_parse_raw_branch() {
+    let input = "  151,  -152,'1 ', 0.00260, 0.04600, 3.50000, 1200.00, 1100.00, 1000.00,  0.01000, -0.25000,  0.01100, -0.15000,1,1,   0.00,   1,1.0000";
+    let expected = Branch {
+        i: 151,
+        j: -152,
+        ckt: ArrayString::from("1 ").unwrap(),
+        r: 0.0026,
+        x: 0.046,
+        b: 3.5,
+        name: None,
+        rate_a: 1200.0,
+        rate_b: 1100.0,
+        rate_c: 1000.0,
+        rates: None,
+        gi: 0.01,
+        bi: -0.25,
+        gj: 0.011,
+        bj: -0.15,
+        st: 1,
+        met: 1,
+        len: 0.0,
+        o1: 1,
+        f1: 1.0,
+        o2: None,
+        f2: None,
+        o3: None,
+        f3: None,
+        o4: None,
+        f4: None,
+    };
+    assert_eq!(Branch::parse_raw(input, 33).unwrap().1, expected);
+}
+
+pub(crate) const CASE_V33: &str = "0,   100.00, 33, 0, 1, 60.00     / PSS(R)E-33.5    WED, JUN 23 2021  13:05
+ TEST CASE
+ TWO AREAS
+  101,'NUC-A       ', 21.6000,2,   1,   1,   1,1.02000,  -0.4000,1.10000,0.90000,1.10000,0.90000
+  151,'NUCPANT     ',500.0000,1,   1,   1,   1,1.01215,  -3.1123,1.10000,0.90000,1.10000,0.90000
+  152,'MID500      ',500.0000,3,   2,   2,   2,1.01000,   0.0000,1.10000,0.90000,1.10000,0.90000 /* [MID500  ] */
+0 / END OF BUS DATA, BEGIN LOAD DATA
+  152,'1 ',1,   2,   2,  1200.000,   700.000,     0.000,     0.000,     0.000,     0.000,   2,1,0
+0 / END OF LOAD DATA, BEGIN FIXED SHUNT DATA
+  151,'1 ',1,     0.000,   150.000
+0 / END OF FIXED SHUNT DATA, BEGIN GENERATOR DATA
+  101,'1 ',   750.000,    81.198,   600.000,  -100.000,1.02000,     0,   900.000, 0.01000, 0.30000, 0.00000, 0.00000,1.00000,1,  100.0,   810.000,     0.000,   1,1.0000,   0,1.0000,   0,1.0000,   0,1.0000,0, 1.0000
+  152,'1 ',   450.000,    20.000,  9999.000, -9999.000,1.01000,     0,   600.000, 0.00000, 0.25000, 0.00000, 0.00000,1.00000,1,  100.0,  9999.000, -9999.000,   2,1.0000,   0,1.0000,   0,1.0000,   0,1.0000,0, 1.0000
+0 / END OF GENERATOR DATA, BEGIN BRANCH DATA
+  151,  152,'1 ', 0.00260, 0.04600, 3.50000, 1200.00, 1100.00, 1000.00,  0.00000,  0.00000,  0.00000,  0.00000,1,1,   0.00,   1,1.0000,   0,1.0000,   0,1.0000,   0,1.0000
+0 / END OF BRANCH DATA, BEGIN TRANSFORMER DATA
+  101,  151,     0,'T1',1,1,1,   0.00000,   0.00000,2,'            ',1,   1,1.0000,   0,1.0000,   0,1.0000,   0,1.0000,'            '
+ 0.00000, 0.01000,   100.00
+1.00000,   0.000,   0.000,  1200.00,  1100.00,  1000.00,  0,      0, 1.10000, 0.90000, 1.10000, 0.90000,  33, 0, 0.00000, 0.00000,  0.000
+1.00000,   0.000
+0 / END OF TRANSFORMER DATA, BEGIN AREA DATA
+    1,  101,   -100.000,    10.000,'CENTRAL     '
+    2,    0,      0.000,    10.000,'EAST        '
+0 / END OF AREA DATA, BEGIN TWO-TERMINAL DC DATA
+'DC1',1,   8.2000,  1000.00,   500.00,   400.00,   0.0000,   0.1000,'I',   0.0000,20, 1.00000
+  151,2,  20.000,   5.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,     0,     0,     0,'1 ',   0.000
+  152,2,  20.000,  15.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,     0,     0,     0,'1 ',   0.000
+0 / END OF TWO-TERMINAL DC DATA, BEGIN VSC DC LINE DATA
+'VDCLINE1',1,   0.7100,   1,1.0000,   0,1.0000,   0,1.0000,   0,1.0000
+  151,2,1,   100.00,  1.01500,  0.00,  0.00,  0.00,  400.00,  1200.00, 1.0000,   100.00,  -110.00,     0, 100.0
+  152,1,2,   400.00,  1.00000,  0.00,  0.00,  0.00,  400.00,  1200.00, 1.0000,   100.00,  -140.00,     0, 100.0
+0 / END OF VSC DC LINE DATA, BEGIN IMPEDANCE CORRECTION DATA
+    1,  -30.00, 1.10000,    0.00, 1.00000,   30.00, 1.10000
+0 / END OF IMPEDANCE CORRECTION DATA, BEGIN MULTI-TERMINAL DC DATA
+'MTDC',2,2,1,1,  152,     0.00,    0
+  151,2,  20.000,   5.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,   100.00, 1.0000, 0.0000, 1
+  152,2,  20.000,  15.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,   500.00, 1.0000, 0.0000, 1
+    1,  151,   1,   1,'DC BUS 1    ',    0,   0.0000,   1
+    2,  152,   1,   1,'DC BUS 2    ',    0,   0.0000,   1
+    1,    2,'1',1,   4.5000,   0.0000
+0 / END OF MULTI-TERMINAL DC DATA, BEGIN MULTI-SECTION LINE DATA
+  151,  152,'&1',1,  9501,  9502
+0 / END OF MULTI-SECTION LINE DATA, BEGIN ZONE DATA
+    1,'FIRST       '
+    2,'SECOND      '
+0 / END OF ZONE DATA, BEGIN INTER-AREA TRANSFER DATA
+    1,    2,'A',    10.000
+0 / END OF INTER-AREA TRANSFER DATA, BEGIN OWNER DATA
+    1,'OWNER ONE   '
+    2,'OWNER TWO   '
+0 / END OF OWNER DATA, BEGIN FACTS DEVICE DATA
+'FACTS 1',  151,    0,1,     0.000,     0.000,1.01500,  50.000,  9999.000,0.90000,1.10000,1.00000,     0.000,0.05000,  100.0,   1,   0.00000,   0.00000,0,     0,'            '
+0 / END OF FACTS DEVICE DATA, BEGIN SWITCHED SHUNT DATA
+  152,1,0,1,1.05000,0.95000,     0,100.0,'            ',   100.00,   2,   50.00
+0 / END OF SWITCHED SHUNT DATA, BEGIN GNE DATA
+0 / END OF GNE DATA, BEGIN INDUCTION MACHINE DATA
+0 / END OF INDUCTION MACHINE DATA
+Q
+";
+
+#[test]
+fn test_parse_raw_case() {
+    let network = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+
+    assert_eq!(network.caseid.rev, Some(33));
+    assert_eq!(
+        network.caseid.comment.as_deref(),
+        Some("PSS(R)E-33.5    WED, JUN 23 2021  13:05")
+    );
+    assert_eq!(network.caseid.titles, [" TEST CASE", " TWO AREAS"]);
+    assert_eq!(network.buses.len(), 3);
+    assert_eq!(network.buses[2].name.as_str(), "MID500      ");
+    assert_eq!(network.loads.len(), 1);
+    assert_eq!(network.loads[0].scale, Some(true));
+    assert_eq!(network.loads[0].intrpt, Some(false));
+    assert_eq!(network.fixed_shunts.len(), 1);
+    assert_eq!(network.fixed_shunts[0].bl, 150.0);
+    assert_eq!(network.generators.len(), 2);
+    assert_eq!(network.generators[1].o1, 2);
+    assert_eq!(network.branches.len(), 1);
+    assert_eq!(network.branches[0].rate_a, 1200.0);
+    assert_eq!(network.transformers.len(), 1);
+    assert_eq!(network.transformers[0].ckt.as_str(), "T1");
+    assert_eq!(network.transformers[0].x1_2, 0.01);
+    assert_eq!(network.transformers[0].rata1, 1200.0);
+    assert_eq!(network.area_interchanges.len(), 2);
+    assert_eq!(network.area_interchanges[0].isw, 101);
+    assert_eq!(network.area_interchanges[0].pdes, -100.0);
+    assert_eq!(network.zones.len(), 2);
+    assert_eq!(network.zones[1].zoname.as_str(), "SECOND      ");
+    assert_eq!(network.area_transfers.len(), 1);
+    assert_eq!(network.area_transfers[0].trid.as_str(), "A");
+    assert_eq!(network.owners.len(), 2);
+    assert_eq!(network.owners[0].owname.as_str(), "OWNER ONE   ");
+    assert_eq!(network.two_terminal_dc.len(), 1);
+    assert_eq!(network.two_terminal_dc[0].name.as_str(), "DC1");
+    assert_eq!(network.two_terminal_dc[0].meter.as_str(), "I");
+    assert_eq!(network.two_terminal_dc[0].ipr, 151);
+    assert_eq!(network.two_terminal_dc[0].gammn, 15.0);
+    assert_eq!(network.vsc_dc.len(), 1);
+    assert_eq!(network.vsc_dc[0].type1, 2);
+    assert_eq!(network.vsc_dc[0].mode2, 2);
+    assert_eq!(network.vsc_dc[0].minq2, -140.0);
+    assert_eq!(network.multi_terminal_dc.len(), 1);
+    assert_eq!(network.multi_terminal_dc[0].converters.len(), 2);
+    assert_eq!(network.multi_terminal_dc[0].buses.len(), 2);
+    assert_eq!(network.multi_terminal_dc[0].links.len(), 1);
+    assert_eq!(network.facts.len(), 1);
+    assert_eq!(network.facts[0].name.as_str(), "FACTS 1");
+    assert_eq!(network.facts[0].shmx, 50.0);
+    assert_eq!(network.impedance_corrections.len(), 1);
+    assert_eq!(network.impedance_corrections[0].t3, 30.0);
+    assert_eq!(network.impedance_corrections[0].f4, 0.0);
+    assert_eq!(network.multi_section_lines.len(), 1);
+    assert_eq!(network.multi_section_lines[0].dum2, Some(9502));
+    assert_eq!(network.multi_section_lines[0].dum3, None);
+    assert_eq!(network.switched_shunts.len(), 1);
+    assert_eq!(network.switched_shunts[0].n1, 2);
+    assert_eq!(network.switched_shunts[0].b1, 50.0);
+    assert_eq!(network.switched_shunts[0].n2, 0);
+}
+
+pub(crate) const CASE_V30: &str = "0,   100.00, 30      / PSS/E-30.3    WED, SEP 15 2021  21:04
+ SE SNAPSHOT 09-15-2021 PEAK CASE 18:00
+ FULL COPY OF ETC.
+  101,'NUC-A       ', 21.6000,2,     0.000,     0.000,   1,   1,1.02000,  -0.4000,   1
+  151,'NUCPANT     ',500.0000,1,     0.000,   150.000,   1,   1,1.01215,  -3.1123,   1
+  152,'MID500      ',500.0000,3,     0.000,     0.000,   2,   2,1.01000,   0.0000,   2
+0 / END OF BUS DATA, BEGIN LOAD DATA
+  152,'1 ',1,   2,   2,  1200.000,   700.000,     0.000,     0.000,     0.000,     0.000,   2
+0 / END OF LOAD DATA, BEGIN GENERATOR DATA
+  101,'1 ',   750.000,    81.198,   600.000,  -100.000,1.02000,     0,   900.000, 0.01000, 0.30000, 0.00000, 0.00000,1.00000,1,  100.0,   810.000,     0.000,   1,1.0000
+0 / END OF GENERATOR DATA, BEGIN BRANCH DATA
+  151,  152,'1 ', 0.00260, 0.04600, 3.50000, 1200.00, 1100.00, 1000.00,  0.00000,  0.00000,  0.00000,  0.00000,1,   0.00,   1,1.0000
+0 / END OF BRANCH DATA, BEGIN TRANSFORMER DATA
+  101,  151,     0,'T1',1,1,1,   0.00000,   0.00000,2,'            ',1,   1,1.0000
+ 0.00000, 0.01000,   100.00
+1.00000,   0.000,   0.000,  1200.00,  1100.00,  1000.00,  0,      0, 1.10000, 0.90000, 1.10000, 0.90000,  33, 0, 0.00000, 0.00000
+1.00000,   0.000
+0 / END OF TRANSFORMER DATA, BEGIN AREA DATA
+    1,  101,   -100.000,    10.000,'CENTRAL     '
+0 / END OF AREA DATA, BEGIN TWO-TERMINAL DC DATA
+0 / END OF TWO-TERMINAL DC DATA, BEGIN VSC DC LINE DATA
+0 / END OF VSC DC LINE DATA, BEGIN SWITCHED SHUNT DATA
+  152,1,1.05000,0.95000,     0,100.0,'            ',   100.00,   2,   50.00
+0 / END OF SWITCHED SHUNT DATA, BEGIN IMPEDANCE CORRECTION DATA
+0 / END OF IMPEDANCE CORRECTION DATA, BEGIN MULTI-TERMINAL DC DATA
+    1,2,2,1,1,  152,     0.00,    0
+  151,2,  20.000,   5.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,   100.00, 1.0000, 0.0000, 1
+  152,2,  20.000,  15.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,   500.00, 1.0000, 0.0000, 1
+    1,  151,   1,   1,'DC BUS 1    ',    0,   0.0000,   1
+    2,  152,   1,   1,'DC BUS 2    ',    0,   0.0000,   1
+    1,    2,'1',   4.5000,   0.0000
+0 / END OF MULTI-TERMINAL DC DATA, BEGIN MULTI-SECTION LINE DATA
+  151,  152,'&1',  9501,  9502
+0 / END OF MULTI-SECTION LINE DATA, BEGIN ZONE DATA
+    1,'FIRST       '
+0 / END OF ZONE DATA, BEGIN INTER-AREA TRANSFER DATA
+0 / END OF INTER-AREA TRANSFER DATA, BEGIN OWNER DATA
+    1,'OWNER ONE   '
+0 / END OF OWNER DATA, BEGIN FACTS DEVICE DATA
+0 / END OF FACTS DEVICE DATA
+Q
+";
+
+#[test]
+fn test_parse_raw_case_v30() {
+    let network = parse_raw_case(CASE_V30, ParseOptions::default()).unwrap().0;
+
+    assert_eq!(network.version, 30);
+    assert_eq!(network.caseid.rev, Some(30));
+    assert_eq!(network.buses.len(), 3);
+    assert_eq!(network.buses[2].owner, 2);
+    assert_eq!(network.buses[2].nvhi, 1.1);
+    assert_eq!(network.fixed_shunts.len(), 1);
+    assert_eq!(network.fixed_shunts[0].i, 151);
+    assert_eq!(network.fixed_shunts[0].id.as_str(), "1");
+    assert_eq!(network.fixed_shunts[0].bl, 150.0);
+    assert_eq!(network.loads.len(), 1);
+    assert_eq!(network.loads[0].owner, 2);
+    assert_eq!(network.loads[0].scale, None);
+    assert_eq!(network.generators.len(), 1);
+    assert_eq!(network.generators[0].wmod, None);
+    assert_eq!(network.branches.len(), 1);
+    assert_eq!(network.branches[0].met, 1);
+    assert_eq!(network.branches[0].o1, 1);
+    assert_eq!(network.transformers.len(), 1);
+    assert_eq!(network.transformers[0].vecgrp, None);
+    assert_eq!(network.switched_shunts.len(), 1);
+    assert_eq!(network.switched_shunts[0].vswhi, 1.05);
+    assert_eq!(network.switched_shunts[0].n1, 2);
+    assert_eq!(network.multi_terminal_dc.len(), 1);
+    assert_eq!(network.multi_terminal_dc[0].line_id.name.as_str(), "1");
+    assert_eq!(network.multi_terminal_dc[0].links[0].rdc, 4.5);
+    assert_eq!(network.multi_section_lines.len(), 1);
+    assert_eq!(network.multi_section_lines[0].dum1, 9501);
+    assert_eq!(network.zones.len(), 1);
+    assert_eq!(network.owners.len(), 1);
+}
+
+pub(crate) const CASE_V35: &str = r#"@!IC,SBASE,REV,XFRRAT,NXFRAT,BASFRQ
+0,   100.00, 35, 0, 1, 60.00     / PSS(R)E-35.3    TUE, MAR 01 2022  09:30
+ TEST CASE
+ NODE-BREAKER
+GENERAL, THRSHZ=0.0001, PQBRAK=0.7, BLOWUP=5.0, MaxIsolLvls=4
+GAUSS, ITMX=100, ACCP=1.6, ACCQ=1.6, ACCM=1.0, TOL=0.0001
+RATING, 1, "RATE1 ", "RATING SET 1                    "
+0 / END OF SYSTEM-WIDE DATA, BEGIN BUS DATA
+@!   I,'NAME        ', BASKV, IDE,AREA,ZONE,OWNER, VM,        VA,    NVHI,   NVLO,   EVHI,   EVLO
+  101,'NUC-A       ', 21.6000,2,   1,   1,   1,1.02000,  -0.4000,1.10000,0.90000,1.10000,0.90000
+  151,'NUCPANT     ',500.0000,1,   1,   1,   1,1.01215,  -3.1123,1.10000,0.90000,1.10000,0.90000
+  152,'MID500      ',500.0000,3,   2,   2,   2,1.01000,   0.0000,1.10000,0.90000,1.10000,0.90000
+0 / END OF BUS DATA, BEGIN LOAD DATA
+  152,'1 ',1,   2,   2,  1200.000,   700.000,     0.000,     0.000,     0.000,     0.000,   2,1,0,    10.000,     5.000,1,'COMMERCIAL'
+0 / END OF LOAD DATA, BEGIN FIXED SHUNT DATA
+0 / END OF FIXED SHUNT DATA, BEGIN GENERATOR DATA
+  101,'1 ',   750.000,    81.198,   600.000,  -100.000,1.02000,     0,     3,   900.000, 0.01000, 0.30000, 0.00000, 0.00000,1.00000,1,  100.0,   810.000,     0.000,1,   1,1.0000,   0,1.0000,   0,1.0000,   0,1.0000,0, 1.0000
+0 / END OF GENERATOR DATA, BEGIN BRANCH DATA
+  151,  152,'1 ', 0.00260, 0.04600, 3.50000,'LINE 151-152',  1200.00, 1100.00, 1000.00, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00,  900.00,  0.00000,  0.00000,  0.00000,  0.00000,1,1,   0.00,   1,1.0000
+0 / END OF BRANCH DATA, BEGIN SYSTEM SWITCHING DEVICE DATA
+  151,  152,'SW', 0.00010,  1200.00, 1100.00, 1000.00, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00,  950.00,0,1,2,2,'BRK 151-152'
+0 / END OF SYSTEM SWITCHING DEVICE DATA, BEGIN TRANSFORMER DATA
+  101,  151,     0,'T1',1,1,1,   0.00000,   0.00000,2,'            ',1,   1,1.0000,   0,1.0000,   0,1.0000,   0,1.0000,'            ',1
+ 0.00000, 0.01000,   100.00
+1.00000,   0.000,   0.000,  1200.00,  1100.00,  1000.00, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00,  800.00,  0,      0,    2, 1.10000, 0.90000, 1.10000, 0.90000,  33, 1, 0.00000, 0.00000,  0.000
+1.00000,   0.000
+0 / END OF TRANSFORMER DATA, BEGIN AREA DATA
+    1,  101,   -100.000,    10.000,'CENTRAL     '
+0 / END OF AREA DATA, BEGIN TWO-TERMINAL DC DATA
+0 / END OF TWO-TERMINAL DC DATA, BEGIN VSC DC LINE DATA
+'VDCLINE1',1,   0.7100,   1,1.0000
+  151,2,1,   100.00,  1.01500,  0.00,  0.00,  0.00,  400.00,  1200.00, 1.0000,   100.00,  -110.00,     0,     4, 100.0
+  152,1,2,   400.00,  1.00000,  0.00,  0.00,  0.00,  400.00,  1200.00, 1.0000,   100.00,  -140.00,     0,     0, 100.0
+0 / END OF VSC DC LINE DATA, BEGIN IMPEDANCE CORRECTION DATA
+    1,  -30.00, 1.10000, 0.00000,    0.00, 1.00000, 0.00000,   30.00, 1.10000, 0.10000
+      0.00, 0.00000, 0.00000
+0 / END OF IMPEDANCE CORRECTION DATA, BEGIN MULTI-TERMINAL DC DATA
+0 / END OF MULTI-TERMINAL DC DATA, BEGIN MULTI-SECTION LINE DATA
+0 / END OF MULTI-SECTION LINE DATA, BEGIN ZONE DATA
+0 / END OF ZONE DATA, BEGIN INTER-AREA TRANSFER DATA
+0 / END OF INTER-AREA TRANSFER DATA, BEGIN OWNER DATA
+0 / END OF OWNER DATA, BEGIN FACTS DEVICE DATA
+'FACTS 1',  151,    0,1,     0.000,     0.000,1.01500,  50.000,  9999.000,0.90000,1.10000,1.00000,     0.000,0.05000,  100.0,   1,   0.00000,   0.00000,0,     0,     2,'            '
+0 / END OF FACTS DEVICE DATA, BEGIN SWITCHED SHUNT DATA
+  152,'1 ',1,0,1,1.05000,0.95000,     0,     0,100.0,'            ',   100.00,1,   2,   50.00,0,   1,  -25.00
+0 / END OF SWITCHED SHUNT DATA, BEGIN GNE DATA
+0 / END OF GNE DATA, BEGIN INDUCTION MACHINE DATA
+0 / END OF INDUCTION MACHINE DATA, BEGIN SUBSTATION DATA
+@!  IS,'NAME                                    ',     LATITUDE,    LONGITUDE,     SGR
+     1,'STATION 1                               ',   35.0000,  -90.0000,  0.1000
+@!  NI,'NAME                                    ',     I,STATUS,   VM,       VA
+     1,'NB1                                     ',  101,   1,1.0000,   0.0000
+     2,'NB2                                     ',  151,   1,1.0000,   0.0000
+0 / END OF SUBSTATION NODE DATA, BEGIN SUBSTATION SWITCHING DEVICE DATA
+     1,     2,'1 ','BREAKER 1                               ', 2, 1, 1, 0.00010,  0.00,  0.00,  0.00
+0 / END OF SUBSTATION SWITCHING DEVICE DATA, BEGIN SUBSTATION EQUIPMENT TERMINAL DATA
+   101,     1,'M','1 '
+   101,     1,'2',   151,'T1'
+0 / END OF SUBSTATION EQUIPMENT TERMINAL DATA
+0 / END OF SUBSTATION DATA
+Q
+"#;
+
+#[test]
+fn test_parse_raw_case_bus_names() {
+    let case = CASE_V33
+        .replace(
+            "  151,  152,'1 ', 0.00260",
+            "'NUCPANT     500.00','-MID500', '1 ', 0.00260",
+        )
+        .replace("  101,  151,     0,'T1'", "'NUC-A 21.6','NUCPANT',0,'T1'");
+    let expected = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+
+    let network = parse_raw_case(&case, ParseOptions::default()).unwrap().0;
+    assert_eq!(network.branches[0].i, 151);
+    assert_eq!(network.branches[0].j, -152);
+    assert_eq!(network.transformers, expected.transformers);
+
+    // Names are matched with the base voltage, when it is given.
+    let case = case.replace("'NUC-A       ', 21.6000", "'MID500      ', 21.6000");
+    let err = parse_raw_case(&case, ParseOptions::default()).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::AmbiguousBus);
+    assert_eq!(err.section, Some("BRANCH DATA"));
+    assert_eq!(err.field, Some("j"));
+    assert_eq!(err.text, "-MID500");
+    assert_eq!(err.column, 22);
+
+    let case = case.replace("'-MID500'", "'-MID500 500'");
+    let err = parse_raw_case(&case, ParseOptions::default()).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::UnknownBus);
+    assert_eq!(err.section, Some("TRANSFORMER DATA"));
+    assert_eq!(err.field, Some("i"));
+    assert_eq!(err.text, "NUC-A 21.6");
+    assert_eq!((err.line, err.column), (17, 1));
+}
+
+#[test]
+fn test_parse_raw_case_bus_num_out_of_range() {
+    // Numbers at or below -1000000 would be taken for bus names.
+    let case = CASE_V33.replace("'NUCPANT     '", "'NUCPANTÉ    '");
+    let line = case
+        .lines()
+        .position(|l| l.starts_with("  151,  152,"))
+        .unwrap()
+        + 1;
+    for j in [-1_000_000, -1_000_010, -1_003_793] {
+        let case = case.replace("  151,  152,'1 '", &format!("  151,{},'1 '", j));
+        let err = parse_raw_case(&case, ParseOptions::default()).unwrap_err();
+        assert_eq!((err.line, err.column), (line, 7));
+        assert_eq!(err.section, Some("BRANCH DATA"));
+        assert_eq!(err.text, j.to_string());
+    }
+}
+
+#[test]
+fn test_parse_raw_case_v35() {
+    let network = parse_raw_case(CASE_V35, ParseOptions::default()).unwrap().0;
+
+    assert_eq!(network.version, 35);
+    assert_eq!(network.system_wide.len(), 3);
+    assert_eq!(network.system_wide[0].name, "GENERAL");
+    assert_eq!(network.system_wide[0].fields[1], "PQBRAK=0.7");
+    assert_eq!(network.system_wide[2].name, "RATING");
+    assert_eq!(
+        network.system_wide[2].fields[2],
+        r#""RATING SET 1                    ""#
+    );
+    assert_eq!(network.buses.len(), 3);
+    assert_eq!(network.loads[0].dgenp, Some(10.0));
+    assert_eq!(network.loads[0].dgenm, Some(1));
+    assert_eq!(network.loads[0].loadtype.unwrap().as_str(), "COMMERCIAL");
+    assert_eq!(network.generators[0].nreg, Some(3));
+    assert_eq!(network.generators[0].mbase, 900.0);
+    assert_eq!(network.generators[0].baslod, Some(1));
+    assert_eq!(network.generators[0].o1, 1);
+    assert_eq!(network.branches[0].name.unwrap().as_str(), "LINE 151-152");
+    assert_eq!(network.branches[0].rate_c, 1000.0);
+    assert_eq!(network.branches[0].rates.unwrap()[8], 900.0);
+    assert_eq!(network.branches[0].met, 1);
+    assert_eq!(network.system_switching_devices.len(), 1);
+    let switch = &network.system_switching_devices[0];
+    assert_eq!((switch.i, switch.j), (151, 152));
+    assert_eq!(switch.ckt.as_str(), "SW");
+    assert_eq!(switch.x, 0.0001);
+    assert_eq!(switch.rate3, 1000.0);
+    assert_eq!(switch.rates[8], 950.0);
+    assert_eq!((switch.stat, switch.nstat), (0, 1));
+    assert_eq!((switch.metered, switch.stype), (2, 2));
+    assert_eq!(switch.name.as_str(), "BRK 151-152");
+    assert_eq!(network.transformers[0].zcod, Some(1));
+    assert_eq!(network.transformers[0].rates1.unwrap()[8], 800.0);
+    assert_eq!(network.transformers[0].node1, Some(2));
+    assert_eq!(network.transformers[0].tab1, 1);
+    assert_eq!(network.vsc_dc[0].nreg1, Some(4));
+    assert_eq!(network.vsc_dc[0].rmpct1, 100.0);
+    assert_eq!(network.impedance_corrections.len(), 1);
+    assert_eq!(network.impedance_corrections[0].t3, 30.0);
+    assert_eq!(network.impedance_corrections[0].f_im.unwrap()[2], 0.1);
+    assert_eq!(network.facts[0].nreg, Some(2));
+    assert_eq!(network.switched_shunts[0].id.unwrap().as_str(), "1 ");
+    assert_eq!(network.switched_shunts[0].rmpct, 100.0);
+    assert_eq!(network.switched_shunts[0].s2, Some(0));
+    assert_eq!(network.switched_shunts[0].b2, -25.0);
+    assert_eq!(network.substations.len(), 1);
+    let substation = &network.substations[0];
+    assert_eq!(substation.lati, 35.0);
+    assert_eq!(substation.nodes.len(), 2);
+    assert_eq!(substation.nodes[1].i, 151);
+    assert_eq!(substation.switching_devices.len(), 1);
+    assert_eq!(substation.switching_devices[0].typ, 2);
+    assert_eq!(substation.terminals.len(), 2);
+    assert_eq!(substation.terminals[1].typ.as_str(), "2");
+    assert_eq!(substation.terminals[1].j, 151);
+    assert_eq!(substation.terminals[1].id.as_str(), "T1");
+}
+
+#[test]
+fn test_parse_raw_case_error() {
+    let case = CASE_V33.replace("450.000,    20.000", "450.000,    2x.000");
+    let line = case.lines().position(|l| l.contains("2x.000")).unwrap() + 1;
+
+    let err = parse_raw_case(&case, ParseOptions::default()).unwrap_err();
+    assert_eq!(err.line, line);
+    assert_eq!(err.column, 28);
+    assert_eq!(err.section, Some("GENERATOR DATA"));
+    assert_eq!(err.field, Some("qt"));
+    assert_eq!(err.text, "x.000");
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "line {}, column 28: invalid `qt` in GENERATOR DATA at \"x.000\"",
+            line
+        )
+    );
+
+    let err = parse_raw_file("does/not/exist.raw", ParseOptions::default()).unwrap_err();
+    assert_eq!(err.line, 0);
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn test_parse_raw_case_short_records() {
+    // PSS/E allows the fields at the end of a record to be left out.
+    let case = CASE_V33.replace(
+        "1.01215,  -3.1123,1.10000,0.90000,1.10000,0.90000",
+        "1.01215,  -3.1123",
+    );
+    let network = parse_raw_case(&case, ParseOptions::default()).unwrap().0;
+    let full = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+    assert_eq!(network.buses[1].vm, full.buses[1].vm);
+    assert_eq!(network.buses[1].nvhi, Bus::default().nvhi);
+    assert_eq!(network.buses[1].evlo, Bus::default().evlo);
+    assert_eq!(network.branches, full.branches);
+
+    // A valid header revision is kept, even though the records also fit v30.
+    assert_eq!(network.version, 33);
+    let (lenient, diagnostics) = parse_raw_case(&case, ParseOptions { lenient: true }).unwrap();
+    assert_eq!(lenient, network);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+}
+
+#[test]
+fn test_parse_raw_case_lenient() {
+    let case = CASE_V33.replace(
+        "0 / END OF BUS DATA",
+        "  151,'DUP         ',500.0000,1,1,1,1,1.0,0.0,1.1,0.9,1.1,0.9\n0 / END OF BUS DATA",
+    );
+    let err = parse_raw_case(&case, ParseOptions::default()).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::DuplicateBus);
+    assert_eq!((err.line, err.column), (7, 3));
+    assert_eq!(err.text, "151");
+
+    let case = case
+        .replace(
+            "1.01215,  -3.1123,1.10000,0.90000,1.10000,0.90000",
+            "1.01215,  -3.1123",
+        )
+        .replace("     0.000,   2,1,0", "     0.000,   2,1,0,  7, 'X'")
+        .replace("450.000,    20.000", "450.000,    2x.000");
+    let line = |text| case.lines().position(|l| l.contains(text)).unwrap() + 1;
+
+    let options = ParseOptions { lenient: true };
+    let (network, diagnostics) = parse_raw_case(&case, options).unwrap();
+    assert_eq!(network.buses.len(), 3);
+    assert_eq!(network.buses[1].nvhi, Bus::default().nvhi);
+    assert_eq!(network.loads.len(), 1);
+    assert_eq!(network.generators.len(), 1);
+
+    let found: Vec<_> = diagnostics
+        .iter()
+        .map(|d| (d.severity, d.line, d.section.unwrap()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (Severity::Error, line("DUP"), "BUS DATA"),
+            (Severity::Warning, line("'X'"), "LOAD DATA"),
+            (Severity::Error, line("2x.000"), "GENERATOR DATA"),
+        ]
+    );
+    assert_eq!(
+        diagnostics[1].to_string(),
+        format!(
+            "line {}: warning: extra fields in LOAD DATA ignored at \"7, 'X'\"",
+            line("'X'")
+        )
+    );
+    assert_eq!(
+        diagnostics[2].message,
+        "invalid `qt` in GENERATOR DATA at \"x.000\"; record skipped"
+    );
+
+    // Records are kept as they are read in strict mode.
+    let (network, diagnostics) = parse_raw_case(CASE_V33, options).unwrap();
+    assert!(diagnostics.is_empty());
+    assert_eq!(
+        network,
+        parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0
+    );
+}
+
+#[test]
+fn test_detect_revision() {
+    assert_eq!(detect_revision(CASE_V30), Some(30));
+    assert_eq!(detect_revision(CASE_V33), Some(33));
+    assert_eq!(detect_revision(CASE_V35), Some(35));
+    assert_eq!(detect_revision("not a case"), None);
+
+    // The layout is trusted over the header.
+    let case = CASE_V35.replacen("0,   100.00, 35,", "0,   100.00, 33,", 1);
+    assert_eq!(detect_revision(&case), Some(35));
+    let case = CASE_V33.replacen("0,   100.00, 33,", "0,   100.00, 32,", 1);
+    assert_eq!(detect_revision(&case), Some(32));
+
+    // Without `rev`, the header comment gives the revision.
+    let case = CASE_V30.replacen("0,   100.00, 30 ", "0,   100.00,    ", 1);
+    assert_eq!(detect_revision(&case), Some(30));
+    let case = case.replacen("PSS/E-30.3", "", 1);
+    assert_eq!(detect_revision(&case), Some(30));
+
+    let network = parse_raw_case(&case, ParseOptions::default()).unwrap().0;
+    let expected = parse_raw_case(CASE_V30, ParseOptions::default()).unwrap().0;
+    assert_eq!(network.version, 30);
+    assert_eq!(network.buses, expected.buses);
+    assert_eq!(network.generators, expected.generators);
+}
+
+#[test]
+fn test_parse_raw_two_winding_transformer() {
+    let buses = [Bus {
+        i: 101,
+        owner: 3,
+        ..Default::default()
+    }];
+    let ctx = TransformerContext::new(100.0, &buses);
+    let input = "  101,  151,     0,'T1',1,1,1,   0.00000,   0.00000,2,'STEPUP      ',1
+ 0.00100, 0.01000
+1.02500,   0.000,   0.000,  1200.00,  1100.00,  1000.00,  1,    151, 1.10000, 0.90000, 1.04000, 0.96000
+1.00000";
+    let expected = Transformer {
+        i: 101,
+        j: 151,
+        ckt: ArrayString::from("T1").unwrap(),
+        name: ArrayString::from("STEPUP      ").unwrap(),
+        o1: 3,
+        r1_2: 0.001,
+        x1_2: 0.01,
+        sbase1_2: 100.0,
+        windv1: 1.025,
+        rata1: 1200.0,
+        ratb1: 1100.0,
+        ratc1: 1000.0,
+        cod1: 1,
+        cont1: 151,
+        vma1: 1.04,
+        vmi1: 0.96,
+        windv2: 1.0,
+        ..Default::default()
+    };
+    let (rest, actual) = crate::parsing::parse_raw_transformer(input, &ctx, 33).unwrap();
+    assert_eq!(rest, "");
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_parse_raw_three_winding_transformer() {
+    let buses = [
+        Bus {
+            i: 1,
+            basekv: 345.0,
+            ..Default::default()
+        },
+        Bus {
+            i: 2,
+            basekv: 138.0,
+            ..Default::default()
+        },
+        Bus {
+            i: 3,
+            basekv: 13.8,
+            ..Default::default()
+        },
+    ];
+    let ctx = TransformerContext::new(100.0, &buses);
+    let input = "    1,    2,    3,'1 ',2,2,1,   0.00000,   0.00000,2,'3WNDTR      ',1,   1,1.0000
+ 0.00100, 0.10000,   500.00, 0.00200, 0.20000,   100.00, 0.00300, 0.30000,   100.00,1.01000,  -5.000
+345.000
+138.000,   0.000,   0.000,   400.00,   400.00,   400.00,  -3,      0, 10.0000,-10.0000 /* [W2] */
+14.000";
+    let (rest, tr) = crate::parsing::parse_raw_transformer(input, &ctx, 33).unwrap();
+    assert_eq!(rest, "");
+    assert_eq!(tr.k, 3);
+    assert_eq!(tr.cw, 2);
+    assert_eq!(tr.cz, 2);
+    assert_eq!(tr.sbase1_2, 500.0);
+    assert_eq!(tr.x2_3, Some(0.2));
+    assert_eq!(tr.sbase3_1, Some(100.0));
+    assert_eq!(tr.vmstar, Some(1.01));
+    assert_eq!(tr.anstar, Some(-5.0));
+    assert_eq!(tr.windv1, 345.0);
+    assert_eq!(tr.ntp1, 33);
+    assert_eq!(tr.windv2, 138.0);
+    assert_eq!(tr.rata2, Some(400.0));
+    assert_eq!(tr.cod2, Some(-3));
+    assert_eq!(tr.rma2, Some(10.0));
+    assert_eq!(tr.rmi2, Some(-10.0));
+    assert_eq!(tr.vma2, Some(1.1));
+    assert_eq!(tr.ntp2, Some(33));
+    assert_eq!(tr.windv3, Some(14.0));
+    assert_eq!(tr.rata3, Some(0.0));
+    assert_eq!(tr.cod3, Some(0));
+}
+
+#[test]
+fn test_parse_raw_transformer_invalid_code() {
+    let ctx = TransformerContext::new(100.0, &[]);
+    let input = "  101,  151,     0,'T1',4,1,1
+ 0.00100, 0.01000
+1.00000
+1.00000";
+    let err = crate::parsing::parse_raw_transformer(input, &ctx, 33).unwrap_err();
+    let err = ParseError::from_nom(input, err);
+    assert_eq!((err.line, err.column), (1, 25));
+    assert_eq!(err.text, "4");
+
+    let input = input.replacen("'T1',4,1,1", "'T1',1,1,  3", 1);
+    let err = crate::parsing::parse_raw_transformer(&input, &ctx, 33).unwrap_err();
+    let err = ParseError::from_nom(&input, err);
+    assert_eq!((err.line, err.column), (1, 31));
+    assert_eq!(err.text, "3");
+}
+
+#[test]
+fn test_parse_raw_multi_terminal_dc_line() {
+    let input = "'MTDC',3,4,3,1,  402,     0.00,    0
+  401,2,  20.000,   5.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,   500.00, 1.0000, 0.0000, 1
+  402,2,  20.000,  15.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,   500.00
+  403,2,  20.000,  15.000,   0.0000,   8.0000,  230.00, 0.50000, 1.00000, 1.50000, 0.51000, 0.00625,  -200.00, 1.0000, 0.0000, -1
+    1,  401,   1,   1,'DC BUS 1    ',    0,   0.0000,   1
+    2,  402,   1,   1,'DC BUS 2    ',    0,   0.0000,   1
+    3,  403,   2,   2,'DC BUS 3    ',    4,   0.0000,   1
+    4,    0,   2,   2,'DC BUS 4    ',    0,   2.5000,   2
+    1,    2,'1',1,   4.5000,   0.0000
+    2,   -3,'1',2,   6.0000,  10.0000
+    3,    1,'2',1,   3.0000";
+    let (rest, mtdc) = crate::parsing::parse_raw_multi_terminal_dc_line(input, 33).unwrap();
+    assert_eq!(rest, "");
+    assert_eq!(mtdc.line_id.name.as_str(), "MTDC");
+    assert_eq!(mtdc.line_id.vconv, 402);
+    assert_eq!(mtdc.converters.len(), 3);
+    assert_eq!(mtdc.converters[1].dcpf, 1.0);
+    assert_eq!(mtdc.converters[1].cnvcod, 1);
+    assert_eq!(mtdc.converters[2].cnvcod, -1);
+    assert_eq!(mtdc.buses.len(), 4);
+    assert_eq!(mtdc.buses[2].idc2, 4);
+    assert_eq!(mtdc.buses[3].rgrnd, 2.5);
+    assert_eq!(mtdc.links.len(), 3);
+    assert_eq!(mtdc.links[1].jdc, -3);
+    assert_eq!(mtdc.links[1].met, 2);
+    assert_eq!(mtdc.links[1].ldc, 10.0);
+    assert_eq!(mtdc.links[2].ldc, 0.0);
+}
+
+#[test]
+fn test_parse_raw_switched_shunt() {
+    let input = "  151,2,1,1,1.05000,0.95000,  152,50.0,'            ',   -25.00,   1,  -50.00,   2,   25.00,   3,   10.00";
+    let expected = SwitchedShunt {
+        i: 151,
+        modsw: 2,
+        adjm: true,
+        vswhi: 1.05,
+        vswlo: 0.95,
+        swrem: 152,
+        rmpct: 50.0,
+        rmidnt: ArrayString::from("            ").unwrap(),
+        binit: -25.0,
+        n1: 1,
+        b1: -50.0,
+        n2: 2,
+        b2: 25.0,
+        n3: 3,
+        b3: 10.0,
+        ..Default::default()
+    };
+    assert_eq!(
+        crate::parsing::parse_raw_switched_shunt(input, 33)
+            .unwrap()
+            .1,
+        expected
+    );
+}
+
+#[test]
+fn test_parse_raw_impedance_correction() {
+    let input = "    2,  0.90000, 1.05000,  1.00000, 1.00000,  1.10000, 1.07000";
+    let (rest, ic) = crate::parsing::parse_raw_impedance_correction(input).unwrap();
+    assert_eq!(rest, "");
+    assert_eq!((ic.i, ic.t1, ic.f1), (2, 0.9, 1.05));
+    assert_eq!((ic.t3, ic.f3), (1.1, 1.07));
+    assert_eq!((ic.t11, ic.f11), (0.0, 0.0));
+
+    // At least two points must be specified.
+    assert!(crate::parsing::parse_raw_impedance_correction("    3,  0.90000, 1.05000").is_err());
+}
+
+#[derive(PartialEq, Debug, Default, RawRecord)]
+struct TwoLineRecord {
+    i: i32,
+    name: ArrayString<12>,
+    #[rev30]
+    old: i8,
+    #[rev34]
+    new: f64,
+    opt: Option<f64>,
+    #[line2]
+    x: f64,
+    #[rev35]
+    y: Option<bool>,
+}
+
+#[test]
+fn test_derive_raw_record() {
+    let input = " 1, 'ONE', 2.5\n 0.1, 1";
+    let expected = TwoLineRecord {
+        i: 1,
+        name: ArrayString::from("ONE").unwrap(),
+        old: 0,
+        new: 2.5,
+        opt: None,
+        x: 0.1,
+        y: Some(true),
+    };
+    assert_eq!(TwoLineRecord::parse_raw(input, 35).unwrap().1, expected);
+
+    let input = "1,'ONE',-3, 4.0 /* [ONE] */\n0.1";
+    let expected = TwoLineRecord {
+        i: 1,
+        name: ArrayString::from("ONE").unwrap(),
+        old: -3,
+        new: 0.0,
+        opt: Some(4.0),
+        x: 0.1,
+        y: None,
+    };
+    assert_eq!(TwoLineRecord::parse_raw(input, 30).unwrap().1, expected);
+}
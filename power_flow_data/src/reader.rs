@@ -0,0 +1,468 @@
+use nom::combinator::{all_consuming, map};
+use nom::sequence::terminated;
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use crate::error::{IResult, ParseError, RawError};
+use crate::parsing::{case_revision, header_revision};
+use crate::parsing::{
+    parse_end_of_line, parse_q_line, parse_raw_bus_v30, parse_raw_case_id, parse_raw_facts_device,
+    parse_raw_impedance_correction, parse_raw_impedance_correction_v34,
+    parse_raw_multi_section_line_group, parse_raw_multi_terminal_dc_line, parse_raw_substation,
+    parse_raw_switched_shunt, parse_raw_system_wide_record, parse_raw_transformer,
+    parse_raw_two_terminal_dc_line, parse_raw_vsc_dc_line, parse_zero_line, TransformerContext,
+};
+use crate::traits::RawRecord;
+use crate::{
+    AreaInterchange, Branch, Bus, CaseID, FixedShunt, Generator, InterAreaTransfer, Load, Owner,
+    Records, SystemSwitchingDevice, Zone,
+};
+
+/// An item of a RAW case, as read by [RawReader].
+#[derive(PartialEq, Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum RawEvent {
+    /// Case identification data, read first.
+    CaseID(CaseID),
+
+    /// The first line of a data section, e.g. `"BRANCH DATA"`.
+    BeginSection(&'static str),
+
+    /// A record of the current section.
+    Record(Records),
+
+    /// The `0 / END OF ...` terminator of the current section.
+    EndSection(&'static str),
+}
+
+/// Reads a PSS/E RAW case one record at a time.
+///
+/// Only the lines of the record being read are held in memory, so a case
+/// can be filtered without building a [crate::Network]:
+///
+/// ```no_run
+/// use power_flow_data::reader::{RawEvent, RawReader};
+/// use power_flow_data::Records;
+/// use std::fs::File;
+/// use std::io::BufReader;
+///
+/// let file = BufReader::new(File::open("case.raw").unwrap());
+/// for event in RawReader::new(file) {
+///     if let RawEvent::Record(Records::Branch(br)) = event.unwrap() {
+///         println!("{} - {}", br.i, br.j);
+///     }
+/// }
+/// ```
+///
+/// Sections are read in the order of the revision in the case identification
+/// data, as for [crate::parsing::parse_raw_case]. For a case without a revision
+/// from 30 to 35, the lines up to the end of its generator data are read ahead,
+/// and held, to guess one with [crate::parsing::detect_revision]. Unlike
+/// `parse_raw_case`, a case that cannot be read in the layouts of a valid header
+/// revision is not retried in others, as its earlier records have already been
+/// returned. Records of the GNE device and induction machine sections are skipped. A `BeginSection` event is only
+/// produced for sections that are present before the `Q` record.
+///
+/// Iteration stops after the first error.
+pub struct RawReader<R> {
+    reader: R,
+    line: usize,
+    rev: usize,
+    sections: Vec<Section>,
+    section: usize,
+    begun: bool,
+    ctx: TransformerContext,
+    pending: VecDeque<RawEvent>,
+    lookahead: VecDeque<String>,
+    started: bool,
+    done: bool,
+}
+
+impl<R: BufRead> RawReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: 0,
+            rev: 33,
+            sections: Vec::new(),
+            section: 0,
+            begun: false,
+            ctx: TransformerContext::new(100.0, &[]),
+            pending: VecDeque::new(),
+            lookahead: VecDeque::new(),
+            started: false,
+            done: false,
+        }
+    }
+
+    // Appends the next line to `buf`, returning false at the end of the input.
+    fn read_into(&mut self, buf: &mut String) -> Result<bool, ParseError> {
+        if let Some(line) = self.lookahead.pop_front() {
+            buf.push_str(&line);
+            self.line += 1;
+            return Ok(true);
+        }
+        match self.reader.read_line(buf) {
+            Ok(0) => Ok(false),
+            Ok(_) => {
+                self.line += 1;
+                Ok(true)
+            }
+            Err(err) => Err(ParseError::io(&format!("line {}", self.line + 1), err)),
+        }
+    }
+
+    fn read_line(&mut self) -> Result<Option<String>, ParseError> {
+        let mut line = String::new();
+        Ok(self.read_into(&mut line)?.then_some(line))
+    }
+
+    // Locates an error in `input`, which starts at line `first`.
+    fn error(
+        &self,
+        input: &str,
+        first: usize,
+        section: &'static str,
+        err: nom::Err<RawError<&str>>,
+    ) -> ParseError {
+        let mut err = ParseError::from_nom(input, err);
+        err.line += first - 1;
+        err.section.get_or_insert(section);
+        err
+    }
+
+    // Reads the case identification data and the two title lines.
+    fn read_case_id(&mut self) -> Result<(), ParseError> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if !self.read_into(&mut line)? {
+                self.done = true;
+                return Ok(());
+            }
+            if !line.trim_start().starts_with("@!") {
+                break;
+            }
+        }
+
+        let mut caseid = match terminated(parse_raw_case_id, parse_end_of_line)(&line) {
+            Ok((_, caseid)) => caseid,
+            Err(err) => return Err(self.error(&line, self.line, "CASE IDENTIFICATION DATA", err)),
+        };
+        let mut head = line.clone();
+        for title in &mut caseid.titles {
+            let line = self.read_line()?.unwrap_or_default();
+            *title = line.trim_end_matches(['\r', '\n']).to_string();
+            head.push_str(&line);
+        }
+        if header_revision(&caseid).is_none() {
+            self.read_ahead(&mut head)?;
+        }
+
+        caseid.rev = case_revision(&caseid, &head);
+        self.rev = caseid.rev.unwrap_or(33);
+        self.sections = Section::order(self.rev);
+        self.ctx = TransformerContext::new(caseid.sbase, &[]);
+        self.pending.push_back(RawEvent::CaseID(caseid));
+        self.started = true;
+        Ok(())
+    }
+
+    // Holds the lines up to the end of the generator data, the fifth section
+    // when there is system-wide data, appending them to `head`.
+    fn read_ahead(&mut self, head: &mut String) -> Result<(), ParseError> {
+        let mut terminators = 0;
+        while terminators < 5 {
+            let mut line = String::new();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(err) => {
+                    let at = self.line + self.lookahead.len() + 1;
+                    return Err(ParseError::io(&format!("line {}", at), err));
+                }
+            }
+            if parse_q_line(&line).is_ok() {
+                terminators = 5;
+            } else if parse_zero_line(&line).is_ok() {
+                terminators += 1;
+            }
+            head.push_str(&line);
+            self.lookahead.push_back(line);
+        }
+        Ok(())
+    }
+
+    // Reads the next line and the rest of the record that it starts, if any.
+    fn advance(&mut self) -> Result<(), ParseError> {
+        if !self.started {
+            return self.read_case_id();
+        }
+
+        let Some(mut buf) = self.read_line()? else {
+            self.done = true;
+            return Ok(());
+        };
+        let first = self.line;
+        let section = self.sections[self.section];
+
+        if buf.trim().is_empty() || buf.trim_start().starts_with("@!") {
+            return Ok(());
+        }
+        if !self.begun {
+            if parse_q_line(&buf).is_ok() {
+                self.done = true;
+                return Ok(());
+            }
+            self.pending
+                .push_back(RawEvent::BeginSection(section.name()));
+            self.begun = true;
+        }
+        if parse_zero_line(&buf).is_ok() {
+            self.pending.push_back(RawEvent::EndSection(section.name()));
+            self.section += 1;
+            self.begun = false;
+            self.done = self.section == self.sections.len();
+            return Ok(());
+        }
+        if section.skipped() {
+            return Ok(());
+        }
+
+        // Records that span several lines fail at the end of the input
+        // until all their lines have been read.
+        loop {
+            let err = match self.parse_record(section, &buf) {
+                Ok((_, records)) => {
+                    for mut record in records {
+                        if let Err(mut err) =
+                            self.ctx.names.resolve(&buf, section.name(), &mut record)
+                        {
+                            err.line += first - 1;
+                            return Err(err);
+                        }
+                        if let Records::Bus(bus) = &record {
+                            self.ctx.add_bus(bus);
+                        }
+                        self.pending.push_back(RawEvent::Record(record));
+                    }
+                    return Ok(());
+                }
+                Err(err) => err,
+            };
+            let more = match &err {
+                nom::Err::Error(err) | nom::Err::Failure(err) => err.input.is_empty(),
+                nom::Err::Incomplete(_) => true,
+            };
+            let err = self.error(&buf, first, section.name(), err);
+            if !more || !self.read_into(&mut buf)? {
+                return Err(err);
+            }
+        }
+    }
+
+    fn parse_record<'a>(&self, section: Section, input: &'a str) -> IResult<&'a str, Vec<Records>> {
+        all_consuming(terminated(
+            |input| parse_section_record(input, section, &self.ctx, self.rev),
+            parse_end_of_line,
+        ))(input)
+    }
+}
+
+// Parses one record of `section`. v30 bus records can give a fixed shunt as well.
+fn parse_section_record<'a>(
+    input: &'a str,
+    section: Section,
+    ctx: &TransformerContext,
+    rev: usize,
+) -> IResult<&'a str, Vec<Records>> {
+    let record = match section {
+        Section::Bus if rev <= 30 => {
+            let (input, (bus, shunt)) = parse_raw_bus_v30(input)?;
+            let mut records = vec![Records::Bus(bus)];
+            records.extend(shunt.map(Records::FixedShunt));
+            return Ok((input, records));
+        }
+        Section::SystemWide => map(parse_raw_system_wide_record, Records::SystemWide)(input),
+        Section::Bus => map(|i| Bus::parse_raw(i, rev), Records::Bus)(input),
+        Section::Load => map(|i| Load::parse_raw(i, rev), Records::Load)(input),
+        Section::FixedShunt => map(|i| FixedShunt::parse_raw(i, rev), Records::FixedShunt)(input),
+        Section::Generator => map(|i| Generator::parse_raw(i, rev), Records::Generator)(input),
+        Section::Branch => map(|i| Branch::parse_raw(i, rev), Records::Branch)(input),
+        Section::SystemSwitchingDevice => map(
+            |i| SystemSwitchingDevice::parse_raw(i, rev),
+            Records::SystemSwitchingDevice,
+        )(input),
+        Section::Transformer => {
+            map(|i| parse_raw_transformer(i, ctx, rev), Records::Transformer)(input)
+        }
+        Section::Area => map(
+            |i| AreaInterchange::parse_raw(i, rev),
+            Records::AreaInterchange,
+        )(input),
+        Section::TwoTerminalDC => map(
+            |i| parse_raw_two_terminal_dc_line(i, rev),
+            Records::TwoTerminalDCLine,
+        )(input),
+        Section::VscDc => map(|i| parse_raw_vsc_dc_line(i, rev), Records::VSCDCLine)(input),
+        Section::ImpedanceCorrection if rev >= 34 => map(
+            parse_raw_impedance_correction_v34,
+            Records::ImpedanceCorrection,
+        )(input),
+        Section::ImpedanceCorrection => {
+            map(parse_raw_impedance_correction, Records::ImpedanceCorrection)(input)
+        }
+        Section::MultiTerminalDC => map(
+            |i| parse_raw_multi_terminal_dc_line(i, rev),
+            Records::MultiTerminalDCLine,
+        )(input),
+        Section::MultiSectionLine => map(
+            |i| parse_raw_multi_section_line_group(i, rev),
+            Records::MultiSectionLineGroup,
+        )(input),
+        Section::Zone => map(|i| Zone::parse_raw(i, rev), Records::Zone)(input),
+        Section::InterAreaTransfer => map(
+            |i| InterAreaTransfer::parse_raw(i, rev),
+            Records::InterAreaTransfer,
+        )(input),
+        Section::Owner => map(|i| Owner::parse_raw(i, rev), Records::Owner)(input),
+        Section::FACTSDevice => {
+            map(|i| parse_raw_facts_device(i, rev), Records::FACTSDevice)(input)
+        }
+        Section::SwitchedShunt => {
+            map(|i| parse_raw_switched_shunt(i, rev), Records::SwitchedShunt)(input)
+        }
+        Section::Substation => map(parse_raw_substation, Records::Substation)(input),
+        Section::Gne | Section::InductionMachine => unreachable!(),
+    };
+    record.map(|(input, record)| (input, vec![record]))
+}
+
+impl<R: BufRead> Iterator for RawReader<R> {
+    type Item = Result<RawEvent, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() && !self.done {
+            if let Err(err) = self.advance() {
+                self.done = true;
+                return Some(Err(err));
+            }
+        }
+        self.pending.pop_front().map(Ok)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Section {
+    SystemWide,
+    Bus,
+    Load,
+    FixedShunt,
+    Generator,
+    Branch,
+    SystemSwitchingDevice,
+    Transformer,
+    Area,
+    TwoTerminalDC,
+    VscDc,
+    ImpedanceCorrection,
+    MultiTerminalDC,
+    MultiSectionLine,
+    Zone,
+    InterAreaTransfer,
+    Owner,
+    FACTSDevice,
+    SwitchedShunt,
+    Gne,
+    InductionMachine,
+    Substation,
+}
+
+impl Section {
+    // The sections of a case, in the order of its revision.
+    fn order(rev: usize) -> Vec<Section> {
+        use Section::*;
+
+        if rev <= 30 {
+            return vec![
+                Bus,
+                Load,
+                Generator,
+                Branch,
+                Transformer,
+                Area,
+                TwoTerminalDC,
+                VscDc,
+                SwitchedShunt,
+                ImpedanceCorrection,
+                MultiTerminalDC,
+                MultiSectionLine,
+                Zone,
+                InterAreaTransfer,
+                Owner,
+                FACTSDevice,
+            ];
+        }
+
+        let mut sections = Vec::new();
+        if rev >= 34 {
+            sections.push(SystemWide);
+        }
+        sections.extend([Bus, Load, FixedShunt, Generator, Branch]);
+        if rev >= 34 {
+            sections.push(SystemSwitchingDevice);
+        }
+        sections.extend([
+            Transformer,
+            Area,
+            TwoTerminalDC,
+            VscDc,
+            ImpedanceCorrection,
+            MultiTerminalDC,
+            MultiSectionLine,
+            Zone,
+            InterAreaTransfer,
+            Owner,
+            FACTSDevice,
+            SwitchedShunt,
+            Gne,
+            InductionMachine,
+        ]);
+        if rev >= 35 {
+            sections.push(Substation);
+        }
+        sections
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Section::SystemWide => "SYSTEM-WIDE DATA",
+            Section::Bus => "BUS DATA",
+            Section::Load => "LOAD DATA",
+            Section::FixedShunt => "FIXED SHUNT DATA",
+            Section::Generator => "GENERATOR DATA",
+            Section::Branch => "BRANCH DATA",
+            Section::SystemSwitchingDevice => "SYSTEM SWITCHING DEVICE DATA",
+            Section::Transformer => "TRANSFORMER DATA",
+            Section::Area => "AREA DATA",
+            Section::TwoTerminalDC => "TWO-TERMINAL DC DATA",
+            Section::VscDc => "VSC DC LINE DATA",
+            Section::ImpedanceCorrection => "IMPEDANCE CORRECTION DATA",
+            Section::MultiTerminalDC => "MULTI-TERMINAL DC DATA",
+            Section::MultiSectionLine => "MULTI-SECTION LINE DATA",
+            Section::Zone => "ZONE DATA",
+            Section::InterAreaTransfer => "INTER-AREA TRANSFER DATA",
+            Section::Owner => "OWNER DATA",
+            Section::FACTSDevice => "FACTS DEVICE DATA",
+            Section::SwitchedShunt => "SWITCHED SHUNT DATA",
+            Section::Gne => "GNE DATA",
+            Section::InductionMachine => "INDUCTION MACHINE DATA",
+            Section::Substation => "SUBSTATION DATA",
+        }
+    }
+
+    // Sections whose records are not read.
+    fn skipped(self) -> bool {
+        matches!(self, Section::Gne | Section::InductionMachine)
+    }
+}
@@ -0,0 +1,150 @@
+use crate::parsing::{parse_raw_case, ParseOptions};
+use crate::parsing_tests::{CASE_V30, CASE_V33, CASE_V35};
+use crate::reader::{RawEvent, RawReader};
+use crate::{Network, Records};
+
+// Collects the records read from `case` into a network, to compare with `parse_raw_case`.
+fn read_network(case: &str) -> (Network, Vec<&'static str>) {
+    let mut network = Network::default();
+    let mut sections = Vec::new();
+    for event in RawReader::new(case.as_bytes()) {
+        match event.unwrap() {
+            RawEvent::CaseID(caseid) => {
+                network.version = caseid.rev.unwrap_or(33) as i8;
+                network.caseid = caseid;
+            }
+            RawEvent::BeginSection(name) => sections.push(name),
+            RawEvent::EndSection(name) => assert_eq!(sections.last(), Some(&name)),
+            RawEvent::Record(record) => match record {
+                Records::Bus(r) => network.buses.push(r),
+                Records::Load(r) => network.loads.push(r),
+                Records::FixedShunt(r) => network.fixed_shunts.push(r),
+                Records::Generator(r) => network.generators.push(r),
+                Records::Branch(r) => network.branches.push(r),
+                Records::SystemSwitchingDevice(r) => network.system_switching_devices.push(r),
+                Records::Transformer(r) => network.transformers.push(r),
+                Records::AreaInterchange(r) => network.area_interchanges.push(r),
+                Records::TwoTerminalDCLine(r) => network.two_terminal_dc.push(r),
+                Records::VSCDCLine(r) => network.vsc_dc.push(r),
+                Records::SwitchedShunt(r) => network.switched_shunts.push(r),
+                Records::ImpedanceCorrection(r) => network.impedance_corrections.push(r),
+                Records::MultiTerminalDCLine(r) => network.multi_terminal_dc.push(r),
+                Records::MultiSectionLineGroup(r) => network.multi_section_lines.push(r),
+                Records::Zone(r) => network.zones.push(r),
+                Records::InterAreaTransfer(r) => network.area_transfers.push(r),
+                Records::Owner(r) => network.owners.push(r),
+                Records::FACTSDevice(r) => network.facts.push(r),
+                Records::SystemWide(r) => network.system_wide.push(r),
+                Records::Substation(r) => network.substations.push(r),
+            },
+        }
+    }
+    (network, sections)
+}
+
+#[test]
+fn test_read_raw_records() {
+    for case in [CASE_V30, CASE_V33, CASE_V35] {
+        let (network, _) = read_network(case);
+        assert_eq!(
+            network,
+            parse_raw_case(case, ParseOptions::default()).unwrap().0
+        );
+    }
+
+    let (_, sections) = read_network(CASE_V33);
+    assert_eq!(sections.len(), 19);
+    assert_eq!(sections[0], "BUS DATA");
+    assert_eq!(sections[18], "INDUCTION MACHINE DATA");
+}
+
+#[test]
+fn test_read_raw_records_v35() {
+    let (network, sections) = read_network(CASE_V35);
+    assert_eq!(network.system_switching_devices.len(), 1);
+    assert_eq!(
+        network.system_switching_devices[0].name.as_str(),
+        "BRK 151-152"
+    );
+    assert_eq!(network.transformers.len(), 1);
+
+    let k = sections
+        .iter()
+        .position(|&s| s == "SYSTEM SWITCHING DEVICE DATA")
+        .unwrap();
+    assert_eq!(sections[k - 1], "BRANCH DATA");
+    assert_eq!(sections[k + 1], "TRANSFORMER DATA");
+    assert_eq!(sections.len(), 22);
+}
+
+#[test]
+fn test_read_raw_records_revision() {
+    // Without `rev`, the revision is guessed as by `parse_raw_case`.
+    let case = CASE_V30.replacen("0,   100.00, 30 ", "0,   100.00,    ", 1);
+    let (network, _) = read_network(&case);
+    assert_eq!(network.version, 30);
+    assert_eq!(
+        network,
+        parse_raw_case(&case, ParseOptions::default()).unwrap().0
+    );
+
+    // A valid one is kept, with records that leave out their trailing fields.
+    let case = CASE_V33.replace(
+        "1.01215,  -3.1123,1.10000,0.90000,1.10000,0.90000",
+        "1.01215,  -3.1123",
+    );
+    let (network, _) = read_network(&case);
+    assert_eq!(network.version, 33);
+    assert_eq!(
+        network,
+        parse_raw_case(&case, ParseOptions::default()).unwrap().0
+    );
+}
+
+#[test]
+fn test_read_raw_records_error() {
+    let case = CASE_V33.replace("450.000,    20.000", "450.000,    2x.000");
+    let line = case.lines().position(|l| l.contains("2x.000")).unwrap() + 1;
+
+    let events: Vec<_> = RawReader::new(case.as_bytes()).collect();
+    let err = events.last().unwrap().as_ref().unwrap_err();
+    assert_eq!(err.line, line);
+    assert_eq!(err.column, 28);
+    assert_eq!(err.section, Some("GENERATOR DATA"));
+    assert_eq!(err.field, Some("qt"));
+    assert!(events[..events.len() - 1].iter().all(|event| event.is_ok()));
+}
+
+#[test]
+fn test_read_raw_records_bus_names() {
+    let case = CASE_V33
+        .replace(
+            "  152,'1 ',1,   2,   2,  1200.000",
+            "'MID500',  '1 ',1,   2,   2,  1200.000",
+        )
+        .replace("  101,  151,     0,'T1'", "'NUC-A 21.6','NUCPANT',0,'T1'");
+
+    let (network, _) = read_network(&case);
+    assert_eq!(
+        network,
+        parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0
+    );
+}
+
+#[test]
+fn test_read_raw_records_bus_name_error() {
+    let case = CASE_V33.replace(
+        "  152,'1 ',1,   2,   2,  1200.000",
+        "'NOWHERE',  '1 ',1,   2,   2,  1200.000",
+    );
+    let line = case.lines().position(|l| l.contains("NOWHERE")).unwrap() + 1;
+
+    let err = RawReader::new(case.as_bytes())
+        .find_map(Result::err)
+        .unwrap();
+    let expected = parse_raw_case(&case, ParseOptions::default()).unwrap_err();
+    assert_eq!((err.line, err.column), (line, 1));
+    assert_eq!((err.line, err.column), (expected.line, expected.column));
+    assert_eq!(err.kind, expected.kind);
+    assert_eq!(err.section, Some("LOAD DATA"));
+}
@@ -0,0 +1,388 @@
+//! PSS/E sequence data (`.seq`): the negative- and zero-sequence data of the
+//! equipment of a RAW case, for short-circuit studies.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use arrayvec::ArrayString;
+use nom::character::complete::space0;
+use nom::combinator::opt;
+use nom::error::ErrorKind;
+use nom::sequence::preceded;
+
+use crate::error::{IResult, ParseError, ParseErrorKind, RawError};
+use crate::parsing::{
+    at_end_of_case, parse_data_headers, parse_end_of_line, parse_int, parse_q_line, parse_section,
+    parse_sep, skip_section,
+};
+use crate::traits::RawRecord;
+use crate::{BusNum, Network};
+
+/// Generator sequence impedances, in pu on the generator MVA base.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
+pub struct Generator {
+    pub i: BusNum,
+    pub id: ArrayString<3>,
+    /// Positive sequence resistance.
+    pub zrpos: f64,
+    /// Subtransient reactance.
+    pub zxppdv: f64,
+    /// Transient reactance.
+    pub zxpdv: Option<f64>,
+    /// Synchronous reactance.
+    pub zxsdv: Option<f64>,
+    /// Negative sequence resistance.
+    pub zrneg: Option<f64>,
+    /// Negative sequence reactance.
+    pub zxneg: Option<f64>,
+    /// Zero sequence resistance.
+    pub zrzero: Option<f64>,
+    /// Zero sequence reactance.
+    pub zxzero: Option<f64>,
+}
+
+/// Load sequence admittances, as the MW and Mvar drawn at 1 pu voltage.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
+pub struct Load {
+    pub i: BusNum,
+    pub id: ArrayString<3>,
+    /// Negative sequence active power (MW).
+    pub pneg: f64,
+    /// Negative sequence reactive power (Mvar).
+    pub qneg: f64,
+    /// 1 if the zero sequence load is grounded, 0 if not.
+    pub grdflg: Option<i8>,
+    /// Zero sequence active power (MW).
+    pub pzero: Option<f64>,
+    /// Zero sequence reactive power (Mvar).
+    pub qzero: Option<f64>,
+}
+
+/// Zero sequence impedance of a non-transformer branch, in pu.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
+pub struct Branch {
+    pub i: BusNum,
+    pub j: BusNum,
+    pub ckt: ArrayString<3>,
+    pub rlinz: f64,
+    pub xlinz: f64,
+    /// Total zero sequence line charging susceptance.
+    pub bchz: Option<f64>,
+    /// Zero sequence line shunt admittance at bus `i`.
+    pub gi: Option<f64>,
+    pub bi: Option<f64>,
+    /// Zero sequence line shunt admittance at bus `j`.
+    pub gj: Option<f64>,
+    pub bj: Option<f64>,
+}
+
+/// Zero sequence mutual coupling between two branches, in pu.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
+pub struct MutualCoupling {
+    pub i: BusNum,
+    pub j: BusNum,
+    pub ckt1: ArrayString<3>,
+    pub k: BusNum,
+    pub l: BusNum,
+    pub ckt2: ArrayString<3>,
+    pub rm: f64,
+    pub xm: f64,
+    /// Start and end of the coupled section of branch `i`-`j`, as fractions of
+    /// its length from bus `i`. 0.0 and 1.0 by default.
+    pub bij1: Option<f64>,
+    pub bij2: Option<f64>,
+    /// Start and end of the coupled section of branch `k`-`l`, as fractions of
+    /// its length from bus `k`. 0.0 and 1.0 by default.
+    pub bkl1: Option<f64>,
+    pub bkl2: Option<f64>,
+}
+
+/// Zero sequence data of a two- or three-winding transformer.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
+pub struct Transformer {
+    pub i: BusNum,
+    pub j: BusNum,
+    /// 0 for a two-winding transformer.
+    pub k: BusNum,
+    pub ckt: ArrayString<3>,
+    /// Units of the winding impedances: 1 for pu on system base, 2 for pu on
+    /// winding base.
+    pub cz0: i8,
+    /// Units of the grounding impedances, as `cz0`.
+    pub czg: i8,
+    /// Winding connection code, e.g. 1 for grounded wye-grounded wye or 2 for
+    /// delta-grounded wye.
+    pub cc: i8,
+    /// Grounding impedance of winding 1.
+    pub rg1: f64,
+    pub xg1: f64,
+    /// Zero sequence impedance of winding 1.
+    pub r01: f64,
+    pub x01: f64,
+    /// Grounding impedance of winding 2.
+    pub rg2: Option<f64>,
+    pub xg2: Option<f64>,
+    /// Zero sequence impedance of winding 2.
+    pub r02: Option<f64>,
+    pub x02: Option<f64>,
+    /// Grounding impedance of winding 3.
+    pub rg3: Option<f64>,
+    pub xg3: Option<f64>,
+    /// Zero sequence impedance of winding 3.
+    pub r03: Option<f64>,
+    pub x03: Option<f64>,
+    /// Common neutral grounding impedance of an auto-transformer. These
+    /// follow the winding 2 impedances in a two-winding record.
+    pub rnutrl: Option<f64>,
+    pub xnutrl: Option<f64>,
+}
+
+/// Zero sequence susceptances of the blocks of a switched shunt, in Mvar at
+/// 1 pu voltage.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
+pub struct SwitchedShunt {
+    pub i: BusNum,
+    pub bz1: Option<f64>,
+    pub bz2: Option<f64>,
+    pub bz3: Option<f64>,
+    pub bz4: Option<f64>,
+    pub bz5: Option<f64>,
+    pub bz6: Option<f64>,
+    pub bz7: Option<f64>,
+    pub bz8: Option<f64>,
+}
+
+/// Zero sequence admittance of a fixed shunt, in MW and Mvar at 1 pu voltage.
+#[derive(Clone, Debug, Default, PartialEq, RawRecord)]
+pub struct FixedShunt {
+    pub i: BusNum,
+    pub id: ArrayString<3>,
+    pub gszero: f64,
+    pub bszero: f64,
+}
+
+/// A sequence data record with the index of the equipment it belongs to, in
+/// the matching list of the [Network].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Linked<T, I = usize> {
+    pub index: I,
+    pub record: T,
+}
+
+/// The sequence data of a case.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SequenceData {
+    pub ic: i8,
+    pub rev: Option<usize>,
+    /// Linked to [Network::generators].
+    pub generators: Vec<Linked<Generator>>,
+    /// Linked to [Network::loads].
+    pub loads: Vec<Linked<Load>>,
+    /// Linked to [Network::branches].
+    pub branches: Vec<Linked<Branch>>,
+    /// Linked to the two coupled [Network::branches].
+    pub mutual_couplings: Vec<Linked<MutualCoupling, [usize; 2]>>,
+    /// Linked to [Network::transformers].
+    pub transformers: Vec<Linked<Transformer>>,
+    /// Linked to [Network::switched_shunts].
+    pub switched_shunts: Vec<Linked<SwitchedShunt>>,
+    /// Linked to [Network::fixed_shunts].
+    pub fixed_shunts: Vec<Linked<FixedShunt>>,
+}
+
+const GENERATORS: &str = "GENERATOR SEQUENCE DATA";
+const LOADS: &str = "LOAD SEQUENCE DATA";
+const BRANCHES: &str = "ZERO SEQ. NON-TRANSFORMER BRANCH DATA";
+const MUTUAL_COUPLINGS: &str = "ZERO SEQ. MUTUAL DATA";
+const TRANSFORMERS: &str = "ZERO SEQ. TRANSFORMER DATA";
+const SWITCHED_SHUNTS: &str = "ZERO SEQ. SWITCHED SHUNT DATA";
+const FIXED_SHUNTS: &str = "ZERO SEQ. FIXED SHUNT DATA";
+
+/// Parses PSS/E sequence data, in the layout of revisions 31 to 34, for the
+/// equipment of `network`.
+///
+/// Records are linked to the equipment of the network by bus numbers and ID.
+/// Branches are matched in either direction, and a switched shunt by its bus.
+/// Induction machine data and any sections after it are skipped.
+///
+/// A record that cannot be read, or that matches no equipment of the network,
+/// is reported with its line and column in `input`.
+pub fn parse_seq(input: &str, network: &Network) -> Result<SequenceData, ParseError> {
+    let (_, records) = parse_seq_records(input).map_err(|err| ParseError::from_nom(input, err))?;
+
+    let generators = index_by(&network.generators, |g| vec![(g.i, id(&g.id))]);
+    let loads = index_by(&network.loads, |l| vec![(l.i, id(&l.id))]);
+    let branches = index_by(&network.branches, |b| {
+        let (i, j) = (b.i.abs(), b.j.abs());
+        vec![(i, j, id(&b.ckt)), (j, i, id(&b.ckt))]
+    });
+    let transformers = index_by(&network.transformers, |t| {
+        let (i, j) = (t.i.abs(), t.j.abs());
+        vec![(i, j, t.k, id(&t.ckt)), (j, i, t.k, id(&t.ckt))]
+    });
+    let switched_shunts = index_by(&network.switched_shunts, |s| vec![s.i]);
+    let fixed_shunts = index_by(&network.fixed_shunts, |s| vec![(s.i, id(&s.id))]);
+
+    let mutual_couplings = records
+        .mutual_couplings
+        .into_iter()
+        .map(|(at, record)| {
+            let ij = branches.get(&(record.i, record.j, id(&record.ckt1)));
+            let kl = branches.get(&(record.k, record.l, id(&record.ckt2)));
+            match (ij, kl) {
+                (Some(&ij), Some(&kl)) => Ok(Linked {
+                    index: [ij, kl],
+                    record,
+                }),
+                _ => Err(unknown_equipment(input, MUTUAL_COUPLINGS, at)),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(SequenceData {
+        ic: records.ic,
+        rev: records.rev,
+        generators: link(input, GENERATORS, records.generators, &generators, |r| {
+            (r.i, id(&r.id))
+        })?,
+        loads: link(input, LOADS, records.loads, &loads, |r| (r.i, id(&r.id)))?,
+        branches: link(input, BRANCHES, records.branches, &branches, |r| {
+            (r.i, r.j, id(&r.ckt))
+        })?,
+        mutual_couplings,
+        transformers: link(
+            input,
+            TRANSFORMERS,
+            records.transformers,
+            &transformers,
+            |r| (r.i, r.j, r.k, id(&r.ckt)),
+        )?,
+        switched_shunts: link(
+            input,
+            SWITCHED_SHUNTS,
+            records.switched_shunts,
+            &switched_shunts,
+            |r| r.i,
+        )?,
+        fixed_shunts: link(
+            input,
+            FIXED_SHUNTS,
+            records.fixed_shunts,
+            &fixed_shunts,
+            |r| (r.i, id(&r.id)),
+        )?,
+    })
+}
+
+// The records of each section, with the length of the input at each record to
+// locate it.
+struct Records {
+    ic: i8,
+    rev: Option<usize>,
+    generators: Vec<(usize, Generator)>,
+    loads: Vec<(usize, Load)>,
+    branches: Vec<(usize, Branch)>,
+    mutual_couplings: Vec<(usize, MutualCoupling)>,
+    transformers: Vec<(usize, Transformer)>,
+    switched_shunts: Vec<(usize, SwitchedShunt)>,
+    fixed_shunts: Vec<(usize, FixedShunt)>,
+}
+
+fn parse_seq_records(input: &str) -> IResult<&str, Records> {
+    let (input, _) = parse_data_headers(input)?;
+    let (input, (ic, rev)) = parse_change_code(input)?;
+    let (input, generators) = parse_section(None, GENERATORS, located)(input)?;
+    let (input, loads) = parse_section(None, LOADS, located)(input)?;
+    let (input, branches) = parse_section(None, BRANCHES, located)(input)?;
+    let (input, mutual_couplings) = parse_section(None, MUTUAL_COUPLINGS, located)(input)?;
+    let (input, transformers) = parse_section(None, TRANSFORMERS, located_transformer)(input)?;
+    let (input, switched_shunts) = parse_section(None, SWITCHED_SHUNTS, located)(input)?;
+    let (input, fixed_shunts) = parse_section(None, FIXED_SHUNTS, located)(input)?;
+
+    let mut input = input;
+    while !at_end_of_case(input) {
+        (input, _) = skip_section(input)?;
+    }
+    let (input, _) = opt(parse_q_line)(input)?;
+
+    let records = Records {
+        ic,
+        rev,
+        generators,
+        loads,
+        branches,
+        mutual_couplings,
+        transformers,
+        switched_shunts,
+        fixed_shunts,
+    };
+
+    Ok((input, records))
+}
+
+//  0,   33     / PSS(R)E-33.5    WED, JUN 23 2021  13:05
+fn parse_change_code(input: &str) -> IResult<&str, (i8, Option<usize>)> {
+    let (input, _) = space0(input)?;
+    let (input, ic) = parse_int(input)?;
+    let (input, rev) = opt(preceded(parse_sep, parse_int))(input)?;
+    let (input, _) = parse_end_of_line(input)?;
+    Ok((input, (ic, rev)))
+}
+
+// Parses a record, along with the length of the input at its first field.
+fn located<T: RawRecord>(input: &str) -> IResult<&str, (usize, T)> {
+    let at = input.trim_start_matches([' ', '\t']).len();
+    let (input, record) = T::parse_raw(input, 33)?;
+    Ok((input, (at, record)))
+}
+
+// Two-winding records have no winding 3 fields, so that the neutral impedance
+// is read into `rg3` and `xg3`.
+fn located_transformer(input: &str) -> IResult<&str, (usize, Transformer)> {
+    let (input, (at, mut transformer)) = located::<Transformer>(input)?;
+    if transformer.k == 0 {
+        transformer.rnutrl = transformer.rg3.take();
+        transformer.xnutrl = transformer.xg3.take();
+    }
+    Ok((input, (at, transformer)))
+}
+
+// Equipment IDs are compared without padding, e.g. `'1 '` matches `1`.
+fn id(id: &str) -> String {
+    id.trim().to_string()
+}
+
+// The index of the first item with each key.
+fn index_by<T, K: Hash + Eq>(items: &[T], keys: impl Fn(&T) -> Vec<K>) -> HashMap<K, usize> {
+    let mut index = HashMap::new();
+    for (k, item) in items.iter().enumerate() {
+        for key in keys(item) {
+            index.entry(key).or_insert(k);
+        }
+    }
+    index
+}
+
+// Links each record to the equipment with the same key.
+fn link<T, K: Hash + Eq>(
+    input: &str,
+    section: &'static str,
+    records: Vec<(usize, T)>,
+    index: &HashMap<K, usize>,
+    key: impl Fn(&T) -> K,
+) -> Result<Vec<Linked<T>>, ParseError> {
+    records
+        .into_iter()
+        .map(|(at, record)| match index.get(&key(&record)) {
+            Some(&index) => Ok(Linked { index, record }),
+            None => Err(unknown_equipment(input, section, at)),
+        })
+        .collect()
+}
+
+fn unknown_equipment(input: &str, section: &'static str, at: usize) -> ParseError {
+    let mut err = RawError::new(&input[input.len() - at..], ErrorKind::Verify);
+    err.error = ParseErrorKind::UnknownEquipment;
+    err.section = Some(section);
+    ParseError::new(input, err)
+}
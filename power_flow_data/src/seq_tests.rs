@@ -0,0 +1,104 @@
+use crate::parsing::{parse_raw_case, ParseOptions};
+use crate::parsing_tests::CASE_V33;
+use crate::seq::{parse_seq, Generator, Linked, Transformer};
+use crate::ParseErrorKind;
+use arrayvec::ArrayString;
+
+const SEQ_V33: &str = "0,   33     / PSS(R)E-33.5    WED, JUN 23 2021  13:05
+  101,'1 ', 0.00000, 0.20000, 0.30000, 1.50000, 0.00000, 0.20000, 0.00000, 0.10000
+  152,'1 ', 0.00000, 0.25000
+0 / END OF GENERATOR SEQUENCE DATA, BEGIN LOAD SEQUENCE DATA
+  152,'1 ',  1200.000,   700.000,1,   600.000,   350.000
+0 / END OF LOAD SEQUENCE DATA, BEGIN ZERO SEQ. NON-TRANSFORMER BRANCH DATA
+  152,  151,'1 ', 0.00780, 0.13800, 2.10000
+0 / END OF ZERO SEQ. NON-TRANSFORMER BRANCH DATA, BEGIN ZERO SEQ. MUTUAL DATA
+  151,  152,'1 ',  152,  151,'1 ', 0.00100, 0.01000
+0 / END OF ZERO SEQ. MUTUAL DATA, BEGIN ZERO SEQ. TRANSFORMER DATA
+  101,  151,    0,'T1',1,1,2, 0.00000, 0.00000, 0.00000, 0.01000, 0.00000, 0.00000, 0.00000, 0.00000
+0 / END OF ZERO SEQ. TRANSFORMER DATA, BEGIN ZERO SEQ. SWITCHED SHUNT DATA
+  152,   50.00,   50.00
+0 / END OF ZERO SEQ. SWITCHED SHUNT DATA, BEGIN ZERO SEQ. FIXED SHUNT DATA
+  151,'1 ',     0.000,   150.000
+0 / END OF ZERO SEQ. FIXED SHUNT DATA, BEGIN INDUCTION MACHINE DATA
+0 / END OF INDUCTION MACHINE DATA
+Q
+";
+
+#[test]
+fn test_parse_seq() {
+    let network = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+    let seq = parse_seq(SEQ_V33, &network).unwrap();
+
+    assert_eq!(seq.rev, Some(33));
+    assert_eq!(
+        seq.generators[1],
+        Linked {
+            index: 1,
+            record: Generator {
+                i: 152,
+                id: ArrayString::from("1 ").unwrap(),
+                zrpos: 0.0,
+                zxppdv: 0.25,
+                ..Default::default()
+            },
+        }
+    );
+    assert_eq!(seq.generators[0].record.zxzero, Some(0.1));
+    assert_eq!(seq.loads[0].record.qzero, Some(350.0));
+    assert_eq!(seq.branches[0].index, 0);
+    assert_eq!(seq.branches[0].record.bchz, Some(2.1));
+    assert_eq!(seq.mutual_couplings[0].index, [0, 0]);
+    assert_eq!(seq.switched_shunts[0].record.bz2, Some(50.0));
+    assert_eq!(seq.fixed_shunts[0].record.bszero, 150.0);
+
+    let transformer = &seq.transformers[0];
+    assert_eq!(transformer.index, 0);
+    assert_eq!(
+        transformer.record,
+        Transformer {
+            i: 101,
+            j: 151,
+            k: 0,
+            ckt: ArrayString::from("T1").unwrap(),
+            cz0: 1,
+            czg: 1,
+            cc: 2,
+            x01: 0.01,
+            rg2: Some(0.0),
+            xg2: Some(0.0),
+            r02: Some(0.0),
+            x02: Some(0.0),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn test_parse_seq_transformer_neutral() {
+    let network = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+    let seq = SEQ_V33.replace(
+        "0.00000, 0.00000, 0.00000, 0.00000\n",
+        "0.00000, 0.00000, 0.00000, 0.00000, 0.50000, 1.50000\n",
+    );
+
+    let seq = parse_seq(&seq, &network).unwrap();
+    let transformer = &seq.transformers[0].record;
+    assert_eq!(transformer.rnutrl, Some(0.5));
+    assert_eq!(transformer.xnutrl, Some(1.5));
+    assert_eq!(transformer.rg3, None);
+    assert_eq!(transformer.xg3, None);
+}
+
+#[test]
+fn test_parse_seq_unknown_equipment() {
+    let network = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+    let seq = SEQ_V33.replace("  152,'1 ',  1200.000", "  152,'2 ',  1200.000");
+
+    let err = parse_seq(&seq, &network).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::UnknownEquipment);
+    assert_eq!((err.line, err.column), (5, 3));
+    assert_eq!(
+        err.to_string(),
+        "line 5, column 3: no equipment in the case for record in LOAD SEQUENCE DATA at \"152\""
+    );
+}
@@ -1,9 +1,16 @@
-use nom::IResult;
+use crate::error::IResult;
 
 pub use power_flow_data_derive::RawRecord;
 
 pub trait RawRecord {
-    fn parse_raw(input: &str) -> IResult<&str, Self>
+    fn parse_raw(input: &str, rev: usize) -> IResult<&str, Self>
+    where
+        Self: Sized;
+}
+
+/// A value that `#[derive(RawRecord)]` parsers read as one field of a record.
+pub trait RawField {
+    fn parse_field(input: &str) -> IResult<&str, Self>
     where
         Self: Sized;
 }
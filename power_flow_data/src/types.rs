@@ -2,13 +2,14 @@ use arrayvec::ArrayString;
 
 use crate::traits::RawRecord;
 
-// TODO: should the various bits of free text / comments / timestamps be in this struct?
-// Data can look like:
-// 0,   100.00          / PSS/E-30.3    WED, SEP 15 2021  21:04
-//    SE SNAPSHOT 09-15-2021 PEAK CASE 18:00
-//    FULL COPY OF ETC.
-
 /// Case identification data.
+///
+/// Data can look like:
+/// ```text
+/// 0,   100.00          / PSS/E-30.3    WED, SEP 15 2021  21:04
+///    SE SNAPSHOT 09-15-2021 PEAK CASE 18:00
+///    FULL COPY OF ETC.
+/// ```
 #[derive(PartialEq, Debug, Clone)]
 pub struct CaseID {
     /// IC Change code:
@@ -32,6 +33,12 @@ pub struct CaseID {
 
     /// System base frequency in Hertz.
     pub basfrq: Option<f64>,
+
+    /// Free text following a `/` on the first line, e.g. the program and time that wrote the case.
+    pub comment: Option<String>,
+
+    /// The two lines of case title that follow the first line.
+    pub titles: [String; 2],
 }
 
 impl Default for CaseID {
@@ -43,6 +50,8 @@ impl Default for CaseID {
             xfrrat: Default::default(),
             nxfrat: Default::default(),
             basfrq: Default::default(),
+            comment: None,
+            titles: Default::default(),
         }
     }
 }
@@ -75,12 +84,15 @@ pub type ZoneNum = i16;
 pub type OwnerNum = i16;
 pub type LineNum = i16;
 
+/// A record of any data section, as read by [crate::reader::RawReader].
+#[derive(PartialEq, Clone, Debug)]
 pub enum Records {
     Bus(Bus),
     Load(Load),
     FixedShunt(FixedShunt),
     Generator(Generator),
     Branch(Branch),
+    SystemSwitchingDevice(SystemSwitchingDevice),
     Transformer(Transformer),
     AreaInterchange(AreaInterchange),
     TwoTerminalDCLine(TwoTerminalDCLine),
@@ -93,6 +105,8 @@ pub enum Records {
     InterAreaTransfer(InterAreaTransfer),
     Owner(Owner),
     FACTSDevice(FACTSDevice),
+    SystemWide(SystemWideRecord),
+    Substation(Substation),
 }
 
 /// Network bus data record (in PSSE v33 format).
@@ -173,9 +187,10 @@ impl Default for Bus {
 /// data record. If multiple loads are to be represented at a bus, they must be individually
 /// identified in a load data record for the bus with a different load identifier.
 /// Each load at a bus can be a mixture of loads with different characteristics.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, RawRecord)]
 pub struct Load {
     /// Buses number, or extended buses name enclosed in single quotes.
+    #[bus]
     pub i: BusNum,
 
     /// One- or two-character uppercase non-blank alphanumeric load identifier used to distinguish among multiple loads at bus "I".
@@ -218,11 +233,33 @@ pub struct Load {
 
     /// Load scaling flag of one for a scalable load and zero for a fixed load.
     /// `scale` = 1 by default.
+    #[rev33]
     pub scale: Option<bool>,
 
     /// Interruptible load flag of one for an interruptible load for zero for a non interruptible load.
     /// `intrpt`=0 by default.
+    #[rev33]
     pub intrpt: Option<bool>,
+
+    /// Distributed generation active power component; entered in MW (v34+).
+    /// `dgenp` = 0.0 by default.
+    #[rev34]
+    pub dgenp: Option<f64>,
+
+    /// Distributed generation reactive power component; entered in Mvar (v34+).
+    /// `dgenq` = 0.0 by default.
+    #[rev34]
+    pub dgenq: Option<f64>,
+
+    /// Distributed generation operation mode of one for on and zero for off (v34+).
+    /// `dgenm` = 0 by default.
+    #[rev34]
+    pub dgenm: Option<i8>,
+
+    /// Load type, a name of up to twelve characters (v35+).
+    /// `loadtype` is blank by default.
+    #[rev35]
+    pub loadtype: Option<ArrayString<15>>,
 }
 
 impl Default for Load {
@@ -242,6 +279,10 @@ impl Default for Load {
             owner: 1,
             scale: Default::default(),
             intrpt: Default::default(),
+            dgenp: None,
+            dgenq: None,
+            dgenm: None,
+            loadtype: None,
         }
     }
 }
@@ -258,9 +299,12 @@ impl Default for Load {
 ///
 /// !!! compat "Not present in v30 files"
 ///     v30 files do not have `FixedShunts`; refer to [Bus] and [SwitchedShunt].
-#[derive(Clone)]
+///     When a v30 case is parsed, any nonzero bus shunt admittance is returned
+///     as a `FixedShunt` with `id` = 1.
+#[derive(PartialEq, Clone, Debug, RawRecord)]
 pub struct FixedShunt {
     /// Bus number, or extended bus name enclosed in single quotes. No default.
+    #[bus]
     pub i: BusNum,
 
     /// One- or two-character uppercase non-blank alphanumeric shunt identifier used to
@@ -303,9 +347,10 @@ impl Default for FixedShunt {
 /// Each network bus to be represented as a generator or plant bus in PSS/E must be specified
 /// in a generator data record. In particular, each bus specified in the bus data input with a
 /// type code of two (2) or three (3) must have a generator data record entered for it.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug, RawRecord)]
 pub struct Generator {
     /// Bus number, or extended bus name enclosed in single quotes.
+    #[bus]
     pub i: BusNum,
 
     /// One- or two-character uppercase non blank alphanumeric machine identifier used to distinguish among multiple machines at bus "I".
@@ -339,8 +384,14 @@ pub struct Generator {
     /// If bus IREG is other than a type 1 or 2 bus, bus "I" regulates its own voltage to the value specified by VS.
     /// IREG is entered as zero if the plant is to regulate its own voltage and must be zero for a type three (swing) bus.
     /// IREG = 0 by default.
+    #[bus]
     pub ireg: BusNum,
 
+    /// Node number of bus `ireg` when bus `ireg` is part of a substation (v34+).
+    /// `nreg` = 0 by default.
+    #[rev34]
+    pub nreg: Option<i32>,
+
     /// Total MVA base of the units represented by this machine; entered in MVA.
     /// This quantity is not needed in normal power flow and equivalent onstruction work,
     /// but is required for switching studies, fault analysis, and dynamic simulation.
@@ -390,6 +441,15 @@ pub struct Generator {
     /// Minimum generator active power output; entered in MW. PB = -9999.0 by default.
     pub pb: f64,
 
+    /// Base loaded flag (v34+):
+    /// * 0 for a unit that can be scaled up and down;
+    /// * 1 for a unit that can only be scaled down;
+    /// * 2 for a unit that can be neither scaled up nor down.
+    ///
+    /// `baslod` = 0 by default.
+    #[rev34]
+    pub baslod: Option<i8>,
+
     /// Owner number (1 through the maximum number of owners at the current size level).
     /// Each machine may have up to four owners. See [Owner].
     /// By default, `o1` is the owner to which bus `i` is assigned and `o2`, `o3`, and `o4` are
@@ -417,11 +477,14 @@ pub struct Generator {
     ///   active power output and `wpf`; when `wpf` is positive, the machine’s reactive power has
     ///   the same sign as its active power; when `wpf` is negative, the machine’s reactive power
     ///   has the opposite sign of its active power.
+    ///
     /// `wmod` = 0 by default.
+    #[rev33]
     pub wmod: Option<i8>, // 0, 1, 2, or 3
 
     /// Power factor used in calculating reactive power limits or output when `wmod` is 2 or 3.
     /// `wpf` = 1.0 by default.
+    #[rev33]
     pub wpf: Option<f64>,
 }
 
@@ -436,6 +499,7 @@ impl Default for Generator {
             qb: -9999.0,
             vs: 1.0,
             ireg: 0,
+            nreg: None,
             mbase: Default::default(), // sbase
             zr: 0.0,
             zx: 1.0,
@@ -446,6 +510,7 @@ impl Default for Generator {
             rmpct: 100.0,
             pt: 9999.0,
             pb: -9999.0,
+            baslod: None,
             o1: 0,
             f1: None,
             o2: None,
@@ -471,14 +536,16 @@ impl Default for Generator {
 /// !!! note "Transformers"
 ///     Branches to be modeled as transformers are not specified in this data category;
 ///     rather, they are specified in the [Transformer] data category.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug, RawRecord)]
 pub struct Branch {
     /// Branch "from bus" number, or extended bus name enclosed in single quotes.
+    #[bus]
     pub i: BusNum,
 
     /// Branch "to bus" number, or extended bus name enclosed in single quotes.
     /// "J" is entered as a negative number, or with a minus sign before the first character of the extended bus name,
     /// to designate it as the metered end; otherwise, bus "I" is assumed to be the metered end.
+    #[bus]
     pub j: BusNum,
 
     /// One- or two-character uppercase nonblank alphanumeric branch circuit identifier;
@@ -496,6 +563,11 @@ pub struct Branch {
     /// Total branch charging susceptance; entered in pu. B = 0.0 by default.
     pub b: f64,
 
+    /// Alphanumeric identifier of up to 40 characters assigned to the branch (v34+).
+    /// `name` is blank by default.
+    #[rev34]
+    pub name: Option<ArrayString<40>>,
+
     /// First loading rating; entered in MVA.
     /// If RATEA is set to 0.0, the default value, this branch will not be included in any examination of circuit loading.
     ///
@@ -511,6 +583,12 @@ pub struct Branch {
     /// Third loading rating; entered in MVA. RATEC = 0.0 by default.
     pub rate_c: f64,
 
+    /// Fourth to twelfth loading ratings, RATE4 to RATE12; entered in MVA (v34+).
+    /// From v34, `rate_a`, `rate_b` and `rate_c` hold RATE1 to RATE3.
+    /// Each rating is 0.0 by default.
+    #[rev34]
+    pub rates: Option<[f64; 9]>,
+
     /// Complex admittance of the line shunt at the bus "I" end of the branch; entered in pu.
     /// BI is negative for a line connected reactor and positive for line connected capacitor.
     /// GI + jBI = 0.0 by default.
@@ -539,6 +617,7 @@ pub struct Branch {
     /// * ≤1 to designate bus `i` as the metered end.
     /// * ≥2 to designate bus `j` as the metered end.
     /// `met` = 1 by default.
+    #[rev33]
     pub met: i8,
 
     /// Line length; entered in user-selected units. LEN = 0.0 by default.
@@ -570,9 +649,11 @@ impl Default for Branch {
             r: Default::default(),
             x: Default::default(),
             b: 0.0,
+            name: None,
             rate_a: Default::default(),
             rate_b: 0.0,
             rate_c: 0.0,
+            rates: None,
             gi: 0.0,
             bi: 0.0,
             gj: 0.0,
@@ -592,6 +673,89 @@ impl Default for Branch {
     }
 }
 
+/// A system switching device (v34+): a breaker or switch between two buses, modelled as a
+/// low-impedance branch.
+///
+/// Data can look like:
+/// ```text
+///   151,  152,'1 ', 0.00010,  1200.00,  1100.00,  1000.00, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,1,1,1,2,'BRK 151-152'
+/// ```
+#[derive(PartialEq, Clone, Debug, RawRecord)]
+pub struct SystemSwitchingDevice {
+    /// From bus number, or extended bus name enclosed in single quotes.
+    #[bus]
+    pub i: BusNum,
+
+    /// To bus number, or extended bus name enclosed in single quotes.
+    #[bus]
+    pub j: BusNum,
+
+    /// Circuit identifier of up to two characters. `ckt` = 1 by default.
+    pub ckt: ArrayString<3>,
+
+    /// Switching device reactance; entered in pu. `x` = 0.0001 by default.
+    pub x: f64,
+
+    /// First loading rating; entered in MVA. `rate1` = 0.0 by default.
+    pub rate1: f64,
+
+    /// Second loading rating; entered in MVA. `rate2` = 0.0 by default.
+    pub rate2: f64,
+
+    /// Third loading rating; entered in MVA. `rate3` = 0.0 by default.
+    pub rate3: f64,
+
+    /// Fourth to twelfth loading ratings, RATE4 to RATE12; entered in MVA.
+    /// Each rating is 0.0 by default.
+    pub rates: [f64; 9],
+
+    /// Status of one for closed and zero for open. `stat` = 1 by default.
+    pub stat: Stat,
+
+    /// Normal service status of one for normally closed and zero for normally open.
+    /// `nstat` = 1 by default.
+    pub nstat: Stat,
+
+    /// Metered end flag.
+    /// * ≤1 to designate bus `i` as the metered end.
+    /// * ≥2 to designate bus `j` as the metered end.
+    ///
+    /// `metered` = 1 by default.
+    pub metered: i8,
+
+    /// Switching device type:
+    /// * 1 for a generic connector;
+    /// * 2 for a circuit breaker;
+    /// * 3 for a disconnect switch.
+    ///
+    /// `stype` = 1 by default.
+    pub stype: i8,
+
+    /// Alphanumeric identifier of up to 40 characters assigned to the switching device.
+    /// `name` is blank by default.
+    pub name: ArrayString<40>,
+}
+
+impl Default for SystemSwitchingDevice {
+    fn default() -> Self {
+        Self {
+            i: Default::default(),
+            j: Default::default(),
+            ckt: ArrayString::from("1").unwrap(),
+            x: 0.0001,
+            rate1: 0.0,
+            rate2: 0.0,
+            rate3: 0.0,
+            rates: [0.0; 9],
+            stat: IN_SERVICE,
+            nstat: IN_SERVICE,
+            metered: 1,
+            stype: 1,
+            name: Default::default(),
+        }
+    }
+}
+
 /// Each AC transformer to be represented in PSS/E is introduced through transformer data records
 /// that specify all the data required to model transformers in power flow calculations, with
 /// one exception.
@@ -605,7 +769,7 @@ impl Default for Branch {
 /// The data records for the two-winding transformer are common to the three-winding transformer;
 /// the data block for two-winding transformers is a subset of the data required for three-winding
 /// transformers.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct Transformer {
     // first row //
     /// The bus number, or extended bus name enclosed in single quotes, of the bus to which the
@@ -700,6 +864,12 @@ pub struct Transformer {
     /// `vecgrp` is 12 blanks by default.
     pub vecgrp: Option<ArrayString<15>>,
 
+    /// Impedance adjustment code (v35+), selecting whether impedance correction tables adjust
+    /// the winding impedances with the tap ratio or phase shift angle (0) or are applied to
+    /// the impedances of both windings (1).
+    /// `zcod` = 0 by default.
+    pub zcod: Option<i8>,
+
     // second row //
     /// The measured impedance of the transformer between the buses to which its first and second
     /// windings are connected (see also `x1_2`).
@@ -808,6 +978,10 @@ pub struct Transformer {
     /// The first winding’s third rating entered in MVA (not current expressed in MVA).
     pub ratc1: f64,
 
+    /// Winding 1's fourth to twelfth ratings, RATE1-4 to RATE1-12 (v34+).
+    /// `rata1`, `ratb1` and `ratc1` hold the first three.
+    pub rates1: Option<[f64; 9]>,
+
     /// The transformer control mode for automatic adjustments of the winding one tap or
     /// phase shift angle during power flow solutions:
     /// * 0 for no control (fixed tap and phase shift);
@@ -834,6 +1008,10 @@ pub struct Transformer {
     /// `cont1` = 0 by default.
     pub cont1: BusNum,
 
+    /// Node number of bus `cont1` when bus `cont1` is part of a substation (v34+).
+    /// `node1` = 0 by default.
+    pub node1: Option<i32>,
+
     /// `rma1` is the upper limit (and `rmi1` the lower limit) of either:
     /// * Off-nominal turns ratio in pu of winding one bus base voltage when `|cod1|` is 1 or 2 and `cw` is 1;
     ///   `rma1` = 1.1 and `rmi1` = 0.9 by default.
@@ -917,6 +1095,10 @@ pub struct Transformer {
     /// _Ignored for a two-winding transformer._
     pub ratc2: Option<f64>,
 
+    /// Winding 2's fourth to twelfth ratings, RATE2-4 to RATE2-12 (v34+).
+    /// `rata2`, `ratb2` and `ratc2` hold the first three.
+    pub rates2: Option<[f64; 9]>,
+
     /// The transformer control mode for automatic adjustments of the winding two tap or phase
     /// shift angle during power flow solutions:
     /// * 0 for no control (fixed tap and phase shift);
@@ -945,6 +1127,10 @@ pub struct Transformer {
     /// _Ignored for a two-winding transformer._
     pub cont2: Option<BusNum>,
 
+    /// Node number of bus `cont2` when bus `cont2` is part of a substation (v34+).
+    /// `node2` = 0 by default.
+    pub node2: Option<i32>,
+
     /// `rma2` is the upper limit (and `rmi2` the lower limit) of either:
     /// * Off-nominal turns ratio in pu of winding two bus base voltage when `|cod2|` is 1 or 2 and `cw` is 1;
     ///     `rma2` = 1.1 and `rmi2` = 0.9 by default.
@@ -1040,6 +1226,10 @@ pub struct Transformer {
     /// _Ignored for a two-winding transformer._
     pub ratc3: Option<f64>,
 
+    /// Winding 3's fourth to twelfth ratings, RATE3-4 to RATE3-12 (v34+).
+    /// `rata3`, `ratb3` and `ratc3` hold the first three.
+    pub rates3: Option<[f64; 9]>,
+
     /// The transformer control mode for automatic adjustments of the winding three tap or phase
     /// shift angle during power flow solutions:
     /// * 0 for no control (fixed tap and phase shift);
@@ -1068,6 +1258,10 @@ pub struct Transformer {
     /// _Ignored for a two-winding transformer._
     pub cont3: Option<BusNum>,
 
+    /// Node number of bus `cont3` when bus `cont3` is part of a substation (v34+).
+    /// `node3` = 0 by default.
+    pub node3: Option<i32>,
+
     /// `rma3` is the upper limit (and `rmi3` the lower limit) of either:
     /// * Off-nominal turns ratio in pu of winding three bus base voltage when `|cod3|` is 1 or 2 and `cw` is 1;
     ///   `rma3` = 1.1 and `rmi3` = 0.9 by default.
@@ -1152,6 +1346,7 @@ impl Default for Transformer {
             o4: None,
             f4: None,
             vecgrp: None,
+            zcod: None,
             r1_2: 0.0,
             x1_2: Default::default(),
             sbase1_2: 0.0, // sbase
@@ -1169,8 +1364,10 @@ impl Default for Transformer {
             rata1: Default::default(),
             ratb1: 0.0,
             ratc1: 0.0,
+            rates1: None,
             cod1: 0,
             cont1: 0,
+            node1: None,
             rma1: 1.1,
             rmi1: 0.9,
             vma1: 1.1,
@@ -1186,8 +1383,10 @@ impl Default for Transformer {
             rata2: None,
             ratb2: None,
             ratc2: None,
+            rates2: None,
             cod2: None,
             cont2: None,
+            node2: None,
             rma2: None,
             rmi2: None,
             vma2: None,
@@ -1203,8 +1402,10 @@ impl Default for Transformer {
             rata3: None,
             ratb3: None,
             ratc3: None,
+            rates3: None,
             cod3: None,
             cont3: None,
+            node3: None,
             rma3: None,
             rmi3: None,
             vma3: None,
@@ -1222,7 +1423,7 @@ impl Default for Transformer {
 /// specific area. This does not imply that the power is destined to be transferred to or from
 /// any other specific area. To specify transfers between specific pairs of areas see
 /// `InterAreaTransfers`.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug, RawRecord)]
 pub struct AreaInterchange {
     /// Area number (1 through the maximum number of areas at the current size level)
     pub i: AreaNum,
@@ -1232,6 +1433,7 @@ pub struct AreaInterchange {
     /// area. Any area containing a system swing bus (type three) must have either that swing
     /// bus or a bus number of zero specified for its area slack bus number.
     /// `isw` = 0 by default.
+    #[bus]
     pub isw: BusNum,
 
     /// Desired net interchange leaving the area (export); entered in MW.
@@ -1248,6 +1450,18 @@ pub struct AreaInterchange {
     pub arname: ArrayString<15>,
 }
 
+impl Default for AreaInterchange {
+    fn default() -> Self {
+        Self {
+            i: Default::default(),
+            isw: 0,
+            pdes: 0.0,
+            ptol: 10.0,
+            arname: Default::default(),
+        }
+    }
+}
+
 /// The two-terminal DC transmission line model is used to simulate either a point-to-point
 /// system with rectifier and inverter separated by a bipolar or mono-polar transmission system
 /// or a Back-to-Back system where the rectifier and inverter are physically located at the same
@@ -1260,7 +1474,7 @@ pub struct AreaInterchange {
 ///
 /// The steady-state model comprising this data enables not only power flow analysis but also
 /// establishes the initial steady-state for dynamic analysis.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct TwoTerminalDCLine {
     /// The non-blank alphanumeric identifier assigned to this DC line.
     /// Each two-terminal DC line must have a unique `name.
@@ -1385,6 +1599,10 @@ pub struct TwoTerminalDCLine {
     /// `icr` = 0 by default.
     pub icr: BusNum,
 
+    /// Node number of bus `icr` when bus `icr` is part of a substation (v34+).
+    /// `ndr` = 0 by default.
+    pub ndr: Option<i32>,
+
     /// Winding one side "from bus" number, or extended bus name enclosed in single quotes,
     /// of a two-winding transformer.
     /// `ifr` = 0 by default.
@@ -1452,6 +1670,10 @@ pub struct TwoTerminalDCLine {
     /// Inverter firing angle measuring bus number, or extended bus name enclosed in single quotes.
     pub ici: BusNum,
 
+    /// Node number of bus `ici` when bus `ici` is part of a substation (v34+).
+    /// `ndi` = 0 by default.
+    pub ndi: Option<i32>,
+
     /// Winding one side "from bus" number, or extended bus name enclosed in single quotes,
     /// of a two-winding transformer.
     pub ifi: BusNum,
@@ -1496,6 +1718,7 @@ impl Default for TwoTerminalDCLine {
             tmnr: 0.51,
             stpr: 0.00625,
             icr: 0,
+            ndr: None,
             ifr: 0,
             itr: 0,
             idr: ArrayString::from("1").unwrap(),
@@ -1513,6 +1736,7 @@ impl Default for TwoTerminalDCLine {
             tmni: 0.51,
             stpi: 0.00625,
             ici: 0,
+            ndi: None,
             ifi: 0,
             iti: 0,
             idi: ArrayString::from("1").unwrap(),
@@ -1525,7 +1749,7 @@ impl Default for TwoTerminalDCLine {
 ///
 /// Defines line quantities and control parameters, and the converter buses (converter 1 and
 /// converter 2), along with their data quantities and control parameters.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct VSCDCLine {
     // First line of data //
     /// The non-blank alphanumeric identifier assigned to this VSC DC line.
@@ -1669,6 +1893,10 @@ pub struct VSCDCLine {
     /// `remot` = 0 by default.
     pub remot1: BusNum,
 
+    /// Node number of bus `remot1` when bus `remot1` is part of a substation (v34+).
+    /// `nreg1` = 0 by default.
+    pub nreg1: Option<i32>,
+
     /// Percent of the total Mvar required to hold the voltage at the bus controlled by bus `ibus`
     /// that are to be contributed by this VSC; `rmpct` must be positive.
     /// `rmpct` is needed only if `remot` specifies a valid remote bus and there is more than one
@@ -1722,9 +1950,64 @@ pub struct VSCDCLine {
     /// Bus number to be regulated by converter 2 to the value specified by `acset2`.
     pub remot2: BusNum,
 
+    /// Node number of bus `remot2` when bus `remot2` is part of a substation (v34+).
+    /// `nreg2` = 0 by default.
+    pub nreg2: Option<i32>,
+
+    /// Percent of the total Mvar required to hold the voltage at the bus controlled by converter 2.
     pub rmpct2: f64,
 }
 
+impl Default for VSCDCLine {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            mdc: 1,
+            rdc: Default::default(),
+            o1: 1,
+            f1: 1.0,
+            o2: 0,
+            f2: 1.0,
+            o3: 0,
+            f3: 1.0,
+            o4: 0,
+            f4: 1.0,
+            ibus1: Default::default(),
+            type1: Default::default(),
+            mode1: 1,
+            docet1: Default::default(),
+            acset1: 1.0,
+            aloss1: 0.0,
+            bloss1: 0.0,
+            minloss1: 0.0,
+            smax1: 0.0,
+            imax1: 0.0,
+            pwf1: 1.0,
+            maxq1: 9999.0,
+            minq1: -9999.0,
+            remot1: 0,
+            nreg1: None,
+            rmpct1: 100.0,
+            ibus2: Default::default(),
+            type2: Default::default(),
+            mode2: 1,
+            docet2: Default::default(),
+            acset2: 1.0,
+            aloss2: 0.0,
+            bloss2: 0.0,
+            minloss2: 0.0,
+            smax2: 0.0,
+            imax2: 0.0,
+            pwf2: 1.0,
+            maxq2: 9999.0,
+            minq2: -9999.0,
+            remot2: 0,
+            nreg2: None,
+            rmpct2: 100.0,
+        }
+    }
+}
+
 /// Represents switched shunt devices, in the form of capacitors and/or reactors on a network bus.
 ///
 /// The switched shunt elements at a bus may consist entirely of blocks of shunt reactors
@@ -1735,11 +2018,16 @@ pub struct VSCDCLine {
 /// a switched shunt data record specified for it. The switched shunts are represented with up to
 /// eight blocks of admittance, each one of which consists of up to nine steps of the specified
 /// block admittance.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct SwitchedShunt {
     /// Bus number, or extended bus name enclosed in single quotes.
     pub i: BusNum,
 
+    /// One- or two-character uppercase non-blank alphanumeric switched shunt identifier used to
+    /// distinguish among multiple switched shunts at bus `i` (v35+).
+    /// `id` = 1 by default.
+    pub id: Option<ArrayString<3>>,
+
     /// Control mode:
     /// * 0 - fixed
     /// * 1 - discrete adjustment, controlling voltage locally or at bus `swrem`
@@ -1790,6 +2078,10 @@ pub struct SwitchedShunt {
     /// `swrem` = 0 by default.
     pub swrem: BusNum,
 
+    /// Node number of bus `swrem` when bus `swrem` is part of a substation (v34+).
+    /// `nreg` = 0 by default.
+    pub nreg: Option<i32>,
+
     /// Percent of the total Mvar required to hold the voltage at the bus controlled by bus `I`
     /// that are to be contributed by this switched shunt; `rmpct` must be positive.
     ///
@@ -1810,6 +2102,10 @@ pub struct SwitchedShunt {
     /// `binit` = 0.0 by default.
     pub binit: f64,
 
+    /// Initial status of block i of one for in-service and zero for out-of-service (v35+).
+    /// `si` = 1 by default.
+    pub s1: Option<Stat>,
+
     /// Number of steps for block i.
     /// The first zero value of N_i or B_i is interpreted as the end of the switched shunt blocks
     /// for bus I.
@@ -1819,22 +2115,72 @@ pub struct SwitchedShunt {
     /// Admittance increment for each of N_i steps in block i; entered in Mvar at unity voltage.
     /// `bi` = 0.0 by default.
     pub b1: f64,
+    pub s2: Option<Stat>,
     pub n2: i32,
     pub b2: f64,
+    pub s3: Option<Stat>,
     pub n3: i32,
     pub b3: f64,
+    pub s4: Option<Stat>,
     pub n4: i32,
     pub b4: f64,
+    pub s5: Option<Stat>,
     pub n5: i32,
     pub b5: f64,
+    pub s6: Option<Stat>,
     pub n6: i32,
     pub b6: f64,
+    pub s7: Option<Stat>,
     pub n7: i32,
     pub b7: f64,
+    pub s8: Option<Stat>,
     pub n8: i32,
     pub b8: f64,
 }
 
+impl Default for SwitchedShunt {
+    fn default() -> Self {
+        Self {
+            i: Default::default(),
+            id: None,
+            modsw: 1,
+            adjm: false,
+            stat: IN_SERVICE,
+            vswhi: 1.0,
+            vswlo: 1.0,
+            swrem: 0,
+            nreg: None,
+            rmpct: 100.0,
+            rmidnt: Default::default(),
+            binit: 0.0,
+            s1: None,
+            n1: 0,
+            b1: 0.0,
+            s2: None,
+            n2: 0,
+            b2: 0.0,
+            s3: None,
+            n3: 0,
+            b3: 0.0,
+            s4: None,
+            n4: 0,
+            b4: 0.0,
+            s5: None,
+            n5: 0,
+            b5: 0.0,
+            s6: None,
+            n6: 0,
+            b6: 0.0,
+            s7: None,
+            n7: 0,
+            b7: 0.0,
+            s8: None,
+            n8: 0,
+            b8: 0.0,
+        }
+    }
+}
+
 /// Transformer impedance corrections are used to model a change of transformer impedance
 /// as off-nominal turns ratio or phase shift angle is adjusted.
 ///
@@ -1854,7 +2200,7 @@ pub struct SwitchedShunt {
 /// the impedance of each transformer winding assigned to the record is treated as a function of
 /// phase shift angle. Otherwise, the impedances of the transformer windings assigned to the record
 /// are made sensitive to off-nominal turns ratio.
-#[derive(Clone)]
+#[derive(Default, PartialEq, Clone, Debug)]
 pub struct ImpedanceCorrection {
     /// Impedance correction record number.
     pub i: i16,
@@ -1887,6 +2233,9 @@ pub struct ImpedanceCorrection {
     pub f10: f64,
     pub t11: f64,
     pub f11: f64,
+
+    /// Imaginary parts of the scaling factors `f1` to `f11`, which hold the real parts (v34+).
+    pub f_im: Option<[f64; 11]>,
 }
 
 // `MultiTerminalDCLines` records are a bit special...
@@ -1899,7 +2248,7 @@ pub struct ImpedanceCorrection {
 // dedicated `MultiTerminalDCLine` object. And each `MultiTerminalDCLine` is a bit like a
 // `Network`, with a `DCLineID` (`CaseID`) and 3 `Records` (`ACConverters, `DCBuses`, `DCLinks`)
 
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct DCLineID {
     /// The non-blank alphanumeric identifier assigned to this DC line.
     /// Each multi-terminal DC line must have a unique `name.
@@ -1944,7 +2293,7 @@ pub struct DCLineID {
     pub vconvn: BusNum,
 }
 
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct ACConverter {
     /// AC converter bus number, or extended bus name enclosed in single quotes.
     /// No default.
@@ -2012,7 +2361,7 @@ pub struct ACConverter {
     pub cnvcod: i8,
 }
 
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct DCBus {
     /// DC bus number (1 to `NDCBS`).
     /// The DC buses are used internally within each multi-terminal DC line and must be numbered
@@ -2041,14 +2390,17 @@ pub struct DCBus {
     /// `name` is twelve blanks by default.
     pub name: ArrayString<15>,
 
-    pub idc2: BusNum,
-
     /// Second DC bus to which converter `ib` is connected, or zero if the converter is connected directly to ground.
     /// * For voltage controlling converters, this is the DC bus with the lower DC voltage magnitude
     ///   and `setvl` specifies the voltage difference between buses `idc` and `idc2`.
     /// * For rectifiers, DC buses should be specified such that power flows from bus `idc2` to bus `idc`.
     /// * For inverters, DC buses should be specified such that power flows from bus `idc` to bus `idc2`.
     /// `idc2` is ignored on those dc bus records that have `ib` specified as zero. `idc2` = 0 by default.
+    pub idc2: BusNum,
+
+    /// Resistance to ground at DC bus `idc`; entered in ohms.
+    /// During solutions, this resistance is applied only at those DC buses specified as `idc2`
+    /// on one or more DC bus records. `rgrnd` = 0.0 by default.
     pub rgrnd: f64,
 
     /// Owner number (1 through the maximum number of owners at the current size level).
@@ -2056,7 +2408,7 @@ pub struct DCBus {
     pub owner: OwnerNum,
 }
 
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct DCLink {
     /// Branch "from bus" DC bus number.
     pub idc: BusNum,
@@ -2071,6 +2423,12 @@ pub struct DCLink {
     /// `dcckt` = "1" by default.
     pub dcckt: ArrayString<1>,
 
+    /// Metered end flag.
+    /// * ≤1 to designate bus `idc` as the metered end.
+    /// * ≥2 to designate bus `jdc` as the metered end.
+    /// `met` = 1 by default.
+    pub met: i8,
+
     /// DC link resistance, entered in ohms. No default.
     pub rdc: f64,
 
@@ -2121,19 +2479,19 @@ pub struct DCLink {
 ///  * each converter (see [ACConverter])
 ///  * each DC bus (see [DCBus])
 ///  * each DC link (see [DCLink])
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct MultiTerminalDCLine {
     /// High-level data about this line.
     pub line_id: DCLineID,
 
-    /// `line.nconv` converter records.
-    pub converter: ACConverter,
+    /// `line_id.nconv` converter records.
+    pub converters: Vec<ACConverter>,
 
-    /// `line.ndcbs` DC bus records.
-    pub bus: DCBus,
+    /// `line_id.ndcbs` DC bus records.
+    pub buses: Vec<DCBus>,
 
-    /// `line.ndcln` DC link records.
-    pub link: DCLink,
+    /// `line_id.ndcln` DC link records.
+    pub links: Vec<DCLink>,
 }
 
 /// Multi-section line group.
@@ -2180,7 +2538,7 @@ pub struct MultiTerminalDCLine {
 /// A FACTS control device may not be connected to a multi-section line dummy bus.
 /// * The status of line sections and type codes of dummy buses are set such that the multi-section
 /// line is treated as a single entity with regards to its service status.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct MultiSectionLineGroup {
     /// "From bus" number, or extended bus name enclosed in single quotes.
     pub i: BusNum,
@@ -2221,7 +2579,7 @@ pub struct MultiSectionLineGroup {
 /// Specifically, the zone number is entered as part of the data records for the [buses](Bus) and [loads](Load).
 /// The use of zones enables the user to develop reports and to check results on the basis of zones and,
 /// consequently be highly specific when reporting and interpreting analytical results.
-#[derive(Clone)]
+#[derive(Default, PartialEq, Clone, Debug, RawRecord)]
 pub struct Zone {
     /// Zone number (1 through the maximum number of zones at the current size level)
     pub i: ZoneNum,
@@ -2237,7 +2595,7 @@ pub struct Zone {
 /// Further, the user can schedule active power transfers between pairs of areas.
 ///
 /// See [AreaInterchange] for desired net interchange.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug, RawRecord)]
 pub struct InterAreaTransfer {
     /// "From area" number (1 through the maximum number of areas at the current size level).
     pub arfrom: AreaNum,
@@ -2256,10 +2614,21 @@ pub struct InterAreaTransfer {
     pub ptran: f64,
 }
 
+impl Default for InterAreaTransfer {
+    fn default() -> Self {
+        Self {
+            arfrom: Default::default(),
+            arto: Default::default(),
+            trid: ArrayString::from("1").unwrap(),
+            ptran: 0.0,
+        }
+    }
+}
+
 /// PSS/E allows the user to identify which organization or utility actually owns a facility,
 /// a piece of equipment, or a load. Major network elements can have up to four different owners.
 /// This facilitates interpretation of results and reporting of results on the basis of ownership.
-#[derive(Clone)]
+#[derive(Default, PartialEq, Clone, Debug, RawRecord)]
 pub struct Owner {
     /// Owner number (1 through the maximum number of owners at the current size level).
     pub i: OwnerNum,
@@ -2278,7 +2647,7 @@ pub struct Owner {
 /// the Static Synchronous Series Compensator (SSSC), combined devices such as the
 /// Unified Power Flow Controller (UPFC) and the Interline Power Flow Controllers (IPFC),
 /// of which the latter are parallel series devices.
-#[derive(Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct FACTSDevice {
     /// The non-blank alphanumeric identifier assigned to this FACTS device.
     /// Each FACTS device must have a unique `name.
@@ -2388,6 +2757,10 @@ pub struct FACTSDevice {
     /// bus and must be zero if the sending end bus is a type 3 (swing) bus. `remot` = 0 by default.
     pub remot: BusNum,
 
+    /// Node number of bus `remot` when bus `remot` is part of a substation (v34+).
+    /// `nreg` = 0 by default.
+    pub nreg: Option<i32>,
+
     /// The name of the FACTS device that is the IPFC master device when this FACTS device is
     /// the "slave" device of an IPFC (i.e., its `mode` is specified as 6 or 8).
     /// `mname` must be enclosed in single or double quotes if it contains any blanks or special
@@ -2395,6 +2768,183 @@ pub struct FACTSDevice {
     pub mname: ArrayString<15>,
 }
 
+impl Default for FACTSDevice {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            i: Default::default(),
+            j: 0,
+            mode: 1,
+            pdes: 0.0,
+            qdes: 0.0,
+            vset: 1.0,
+            shmx: 9999.0,
+            trmx: 9999.0,
+            vtmn: 0.9,
+            vtmx: 1.1,
+            vsmx: 1.0,
+            imx: 0.0,
+            linx: 0.05,
+            rmpct: 100.0,
+            owner: 1,
+            set1: 0.0,
+            set2: 0.0,
+            vsref: 0,
+            remot: 0,
+            nreg: None,
+            mname: Default::default(),
+        }
+    }
+}
+
+/// A record of the system-wide data section (v34+), which holds solution parameters and the
+/// names of the rating sets, for example:
+///
+/// ```text
+/// GENERAL, THRSHZ=0.0001, PQBRAK=0.7, BLOWUP=5.0
+/// RATING, 1, "RATE1 ", "RATING SET 1                    "
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct SystemWideRecord {
+    /// Record keyword, such as `GENERAL`, `GAUSS`, `NEWTON`, `ADJUST`, `TYSL`, `SOLVER` or `RATING`.
+    pub name: String,
+
+    /// The remaining fields of the record, verbatim (`KEY=VALUE` pairs, numbers and quoted strings).
+    pub fields: Vec<String>,
+}
+
+/// A substation of a node-breaker model (v35+).
+///
+/// Each substation record is followed by its node, switching device and equipment terminal
+/// data, each of which is terminated by a `0` record.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Substation {
+    /// Substation number (1 to 99999). No default.
+    pub is: i32,
+
+    /// Alphanumeric identifier of up to 40 characters assigned to substation `is`.
+    /// `name` is blank by default.
+    pub name: ArrayString<40>,
+
+    /// Substation latitude in degrees; positive north of the equator.
+    /// `lati` = 0.0 by default.
+    pub lati: f64,
+
+    /// Substation longitude in degrees; positive east of the prime meridian.
+    /// `long` = 0.0 by default.
+    pub long: f64,
+
+    /// Substation grounding DC resistance in ohms.
+    /// `srg` = 0.1 by default.
+    pub srg: f64,
+
+    /// Nodes of the substation.
+    pub nodes: Vec<SubstationNode>,
+
+    /// Switching devices between nodes of the substation.
+    pub switching_devices: Vec<SwitchingDevice>,
+
+    /// Connections of equipment terminals to nodes of the substation.
+    pub terminals: Vec<EquipmentTerminal>,
+}
+
+/// A node of a [Substation].
+#[derive(PartialEq, Clone, Debug)]
+pub struct SubstationNode {
+    /// Node number (1 to 999). No default.
+    pub ni: i32,
+
+    /// Alphanumeric identifier of up to 40 characters assigned to node `ni`.
+    /// `name` is blank by default.
+    pub name: ArrayString<40>,
+
+    /// Number of the bus to which the node is assigned. No default.
+    pub i: BusNum,
+
+    /// Node status of one for in-service and zero for out-of-service.
+    /// `status` = 1 by default.
+    pub status: Stat,
+
+    /// Node voltage magnitude; entered in pu. `vm` = 1.0 by default.
+    pub vm: f64,
+
+    /// Node voltage phase angle; entered in degrees. `va` = 0.0 by default.
+    pub va: f64,
+}
+
+/// A switching device (breaker, switch or disconnect) between two nodes of a [Substation].
+#[derive(PartialEq, Clone, Debug)]
+pub struct SwitchingDevice {
+    /// From node number. No default.
+    pub ni: i32,
+
+    /// To node number. No default.
+    pub nj: i32,
+
+    /// Circuit identifier of up to two characters. `ckt` = 1 by default.
+    pub ckt: ArrayString<3>,
+
+    /// Alphanumeric identifier of up to 40 characters assigned to the switching device.
+    /// `name` is blank by default.
+    pub name: ArrayString<40>,
+
+    /// Switching device type:
+    /// * 1 for a generic connector;
+    /// * 2 for a circuit breaker;
+    /// * 3 for a disconnect switch.
+    ///
+    /// `typ` = 1 by default.
+    pub typ: i8,
+
+    /// Status of one for closed and zero for open. `status` = 1 by default.
+    pub status: Stat,
+
+    /// Normal service status of one for normally closed and zero for normally open.
+    /// `nstat` = 1 by default.
+    pub nstat: Stat,
+
+    /// Switching device reactance; entered in pu. `x` = 0.0001 by default.
+    pub x: f64,
+
+    /// First loading rating; entered in MVA. `rate1` = 0.0 by default.
+    pub rate1: f64,
+
+    /// Second loading rating; entered in MVA. `rate2` = 0.0 by default.
+    pub rate2: f64,
+
+    /// Third loading rating; entered in MVA. `rate3` = 0.0 by default.
+    pub rate3: f64,
+
+    /// Fourth to twelfth loading ratings; entered in MVA. Each rating is 0.0 by default.
+    pub rates: Option<[f64; 9]>,
+}
+
+/// The connection of an equipment terminal to a node of a [Substation].
+#[derive(PartialEq, Clone, Debug)]
+pub struct EquipmentTerminal {
+    /// Bus number of the equipment terminal. No default.
+    pub i: BusNum,
+
+    /// Node number to which the terminal is connected. No default.
+    pub ni: i32,
+
+    /// Equipment type:
+    /// `L` (load), `F` (fixed shunt), `S` (switched shunt), `M` (machine), `B` (branch),
+    /// `2` (two-winding transformer), `3` (three-winding transformer), `I` (induction machine),
+    /// `D` (two-terminal DC line), `V` (VSC DC line), `N` (multi-terminal DC line) or
+    /// `A` (FACTS device).
+    pub typ: ArrayString<1>,
+
+    /// Other bus of a branch or transformer; zero otherwise.
+    pub j: BusNum,
+
+    /// Third bus of a three-winding transformer; zero otherwise.
+    pub k: BusNum,
+
+    /// Equipment identifier or name.
+    pub id: ArrayString<15>,
+}
+
 /// Representation of a power network.
 ///
 /// The PSS/E data format comprises 16 data categories of network and equipment
@@ -2421,7 +2971,10 @@ pub struct FACTSDevice {
 /// 1. [InterAreaTransfer]
 /// 1. [Owner]
 /// 1. [FACTSDevice]
-#[derive(Default, Clone)]
+///
+/// From v34, cases also have [SystemWideRecord] and [SystemSwitchingDevice] data, and from v35
+/// [Substation] data.
+#[derive(Default, PartialEq, Clone, Debug)]
 pub struct Network {
     /// Version of the PSS/E data version given or detected when parsing.
     pub version: i8,
@@ -2429,6 +2982,9 @@ pub struct Network {
     /// Case identification data.
     pub caseid: CaseID,
 
+    /// System-wide data records (v34+).
+    pub system_wide: Vec<SystemWideRecord>,
+
     /// Bus records.
     pub buses: Vec<Bus>,
 
@@ -2444,6 +3000,9 @@ pub struct Network {
     /// Non-transformer Branch records.
     pub branches: Vec<Branch>,
 
+    /// System switching device records (v34+).
+    pub system_switching_devices: Vec<SystemSwitchingDevice>,
+
     /// Transformer records.
     pub transformers: Vec<Transformer>,
 
@@ -2479,4 +3038,7 @@ pub struct Network {
 
     /// FACTS device records.
     pub facts: Vec<FACTSDevice>,
+
+    /// Substation records (v35+).
+    pub substations: Vec<Substation>,
 }
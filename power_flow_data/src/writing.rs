@@ -0,0 +1,1202 @@
+use arrayvec::ArrayString;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::{
+    ACConverter, AreaInterchange, Branch, Bus, BusNum, CaseID, DCBus, DCLink, FACTSDevice,
+    FixedShunt, Generator, ImpedanceCorrection, InterAreaTransfer, Load, MultiSectionLineGroup,
+    MultiTerminalDCLine, Network, Owner, Substation, SwitchedShunt, SystemSwitchingDevice,
+    SystemWideRecord, Transformer, TwoTerminalDCLine, VSCDCLine, Zone, IN_SERVICE,
+};
+
+// PSS/E blank-pads names and identifiers to these widths.
+const NAME_WIDTH: usize = 12;
+pub(crate) const ID_WIDTH: usize = 2;
+
+// A value that can be written as a field of a RAW record.
+pub(crate) trait Field {
+    fn to_field(&self) -> String;
+
+    // Reals that are not finite, e.g. from a failed solution, cannot be read back.
+    fn is_finite(&self) -> bool {
+        true
+    }
+}
+
+// The error for a record with a real that is not finite.
+pub(crate) fn non_finite_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "cannot write a real that is not finite",
+    )
+}
+
+macro_rules! int_field {
+    ($($t:ty),*) => {
+        $(impl Field for $t {
+            fn to_field(&self) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+int_field!(i8, i16, i32, isize, usize);
+
+// Reals are written with the shortest representation that reads back exactly.
+impl Field for f64 {
+    fn to_field(&self) -> String {
+        let s = self.to_string();
+        if s.contains('.') {
+            s
+        } else {
+            s + ".0"
+        }
+    }
+
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+impl Field for bool {
+    fn to_field(&self) -> String {
+        u8::from(*self).to_string()
+    }
+}
+
+// The fourth to twelfth ratings of a v34 branch, winding or switching device.
+impl Field for [f64; 9] {
+    fn to_field(&self) -> String {
+        let rates: Vec<String> = self.iter().map(Field::to_field).collect();
+        rates.join(",")
+    }
+
+    fn is_finite(&self) -> bool {
+        self.iter().all(|rate| rate.is_finite())
+    }
+}
+
+impl<const CAP: usize> Field for ArrayString<CAP> {
+    fn to_field(&self) -> String {
+        format!("'{}'", self)
+    }
+}
+
+impl Field for String {
+    fn to_field(&self) -> String {
+        format!("'{}'", self)
+    }
+}
+
+// Blank-pads a name or identifier to the width PSS/E writes it with.
+fn pad<const CAP: usize>(s: &ArrayString<CAP>, width: usize) -> ArrayString<CAP> {
+    let mut padded = *s;
+    while padded.len() < width.min(CAP) {
+        padded.push(' ');
+    }
+    padded
+}
+
+// The fields of one line of a record. Optional fields that are left out are
+// only written, with their defaults, when a later field is present.
+#[derive(Default)]
+pub(crate) struct Line {
+    fields: Vec<(String, bool)>,
+    comment: Option<String>,
+    non_finite: bool,
+}
+
+impl Line {
+    pub(crate) fn field(&mut self, value: impl Field) -> &mut Self {
+        self.non_finite |= !value.is_finite();
+        self.fields.push((value.to_field(), true));
+        self
+    }
+
+    pub(crate) fn opt<T: Field>(&mut self, value: Option<T>, default: T) -> &mut Self {
+        let present = value.is_some();
+        let value = value.unwrap_or(default);
+        self.non_finite |= !value.is_finite();
+        self.fields.push((value.to_field(), present));
+        self
+    }
+
+    // Free text written after the fields, following a `/`.
+    pub(crate) fn comment(&mut self, text: Option<&str>) -> &mut Self {
+        self.comment = text.map(str::to_string);
+        self
+    }
+
+    pub(crate) fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        if self.non_finite {
+            return Err(non_finite_error());
+        }
+        let n = self
+            .fields
+            .iter()
+            .rposition(|(_, present)| *present)
+            .map_or(0, |k| k + 1);
+        let fields: Vec<&str> = self.fields[..n].iter().map(|(s, _)| s.as_str()).collect();
+        match &self.comment {
+            Some(comment) => writeln!(w, "{}     / {}", fields.join(","), comment),
+            None => writeln!(w, "{}", fields.join(",")),
+        }
+    }
+}
+
+// Writes the data sections of a case, separated by their `0 / END OF ...` terminators.
+pub(crate) struct Sections<'a, W: Write> {
+    w: &'a mut W,
+    current: Option<&'static str>,
+}
+
+impl<'a, W: Write> Sections<'a, W> {
+    pub(crate) fn new(w: &'a mut W) -> Self {
+        Self { w, current: None }
+    }
+
+    pub(crate) fn section<T, F>(
+        &mut self,
+        name: &'static str,
+        records: &[T],
+        mut f: F,
+    ) -> io::Result<()>
+    where
+        F: FnMut(&mut W, &T) -> io::Result<()>,
+    {
+        if let Some(prev) = self.current.replace(name) {
+            writeln!(self.w, "0 / END OF {} DATA, BEGIN {} DATA", prev, name)?;
+        }
+        for record in records {
+            f(self.w, record)?;
+        }
+        Ok(())
+    }
+
+    // Writes an empty section, for data that is not held in the `Network`.
+    pub(crate) fn empty(&mut self, name: &'static str) -> io::Result<()> {
+        self.section::<(), _>(name, &[], |_, _| Ok(()))
+    }
+
+    pub(crate) fn end(self) -> io::Result<()> {
+        if let Some(prev) = self.current {
+            writeln!(self.w, "0 / END OF {} DATA", prev)?;
+        }
+        writeln!(self.w, "Q")
+    }
+}
+
+fn write_raw_case_id<W: Write>(w: &mut W, caseid: &CaseID, rev: usize) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(caseid.ic).field(caseid.sbase).field(rev);
+    line.opt(caseid.xfrrat, 0)
+        .opt(caseid.nxfrat, 0)
+        .opt(caseid.basfrq, 60.0)
+        .comment(caseid.comment.as_deref());
+    line.write(w)?;
+
+    writeln!(w, "{}", caseid.titles[0])?;
+    writeln!(w, "{}", caseid.titles[1])
+}
+
+fn write_raw_system_wide_record<W: Write>(w: &mut W, record: &SystemWideRecord) -> io::Result<()> {
+    let mut fields = vec![record.name.as_str()];
+    fields.extend(record.fields.iter().map(String::as_str));
+    writeln!(w, "{}", fields.join(", "))
+}
+
+fn write_raw_bus<W: Write>(w: &mut W, bus: &Bus) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(bus.i)
+        .field(pad(&bus.name, NAME_WIDTH))
+        .field(bus.basekv)
+        .field(bus.ide)
+        .field(bus.area)
+        .field(bus.zone)
+        .field(bus.owner)
+        .field(bus.vm)
+        .field(bus.va)
+        .field(bus.nvhi)
+        .field(bus.nvlo)
+        .field(bus.evhi)
+        .field(bus.evlo);
+    line.write(w)
+}
+
+// v30 bus records carry the admittance of the in-service fixed shunts at the bus.
+fn write_raw_bus_v30<W: Write>(w: &mut W, bus: &Bus, shunt: (f64, f64)) -> io::Result<()> {
+    let (gl, bl) = shunt;
+    let mut line = Line::default();
+    line.field(bus.i)
+        .field(pad(&bus.name, NAME_WIDTH))
+        .field(bus.basekv)
+        .field(bus.ide)
+        .field(gl)
+        .field(bl)
+        .field(bus.area)
+        .field(bus.zone)
+        .field(bus.vm)
+        .field(bus.va)
+        .field(bus.owner);
+    line.write(w)
+}
+
+fn write_raw_load<W: Write>(w: &mut W, load: &Load, rev: usize) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(load.i)
+        .field(pad(&load.id, ID_WIDTH))
+        .field(load.status)
+        .field(load.area)
+        .field(load.zone)
+        .field(load.pl)
+        .field(load.ql)
+        .field(load.ip)
+        .field(load.iq)
+        .field(load.yp)
+        .field(load.yq)
+        .field(load.owner);
+    if rev > 30 {
+        line.opt(load.scale, true).opt(load.intrpt, false);
+    }
+    if rev >= 34 {
+        line.opt(load.dgenp, 0.0)
+            .opt(load.dgenq, 0.0)
+            .opt(load.dgenm, 0);
+    }
+    if rev >= 35 {
+        line.opt(load.loadtype, ArrayString::new());
+    }
+    line.write(w)
+}
+
+fn write_raw_fixed_shunt<W: Write>(w: &mut W, fixed_shunt: &FixedShunt) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(fixed_shunt.i)
+        .field(pad(&fixed_shunt.id, ID_WIDTH))
+        .field(fixed_shunt.status)
+        .field(fixed_shunt.gl)
+        .field(fixed_shunt.bl);
+    line.write(w)
+}
+
+fn write_raw_generator<W: Write>(w: &mut W, gen: &Generator, rev: usize) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(gen.i)
+        .field(pad(&gen.id, ID_WIDTH))
+        .field(gen.pg)
+        .field(gen.qg)
+        .field(gen.qt)
+        .field(gen.qb)
+        .field(gen.vs)
+        .field(gen.ireg);
+    if rev >= 34 {
+        line.field(gen.nreg.unwrap_or(0));
+    }
+    line.field(gen.mbase)
+        .field(gen.zr)
+        .field(gen.zx)
+        .field(gen.rt)
+        .field(gen.xt)
+        .field(gen.gtap)
+        .field(gen.stat)
+        .field(gen.rmpct)
+        .field(gen.pt)
+        .field(gen.pb);
+    if rev >= 34 {
+        line.field(gen.baslod.unwrap_or(0));
+    }
+    line.field(gen.o1)
+        .opt(gen.f1, 1.0)
+        .opt(gen.o2, 0)
+        .opt(gen.f2, 1.0)
+        .opt(gen.o3, 0)
+        .opt(gen.f3, 1.0)
+        .opt(gen.o4, 0)
+        .opt(gen.f4, 1.0);
+    if rev > 30 {
+        line.opt(gen.wmod, 0).opt(gen.wpf, 1.0);
+    }
+    line.write(w)
+}
+
+fn write_raw_branch<W: Write>(w: &mut W, branch: &Branch, rev: usize) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(branch.i)
+        .field(branch.j)
+        .field(pad(&branch.ckt, ID_WIDTH))
+        .field(branch.r)
+        .field(branch.x)
+        .field(branch.b);
+    if rev >= 34 {
+        line.field(branch.name.unwrap_or_default());
+    }
+    line.field(branch.rate_a)
+        .field(branch.rate_b)
+        .field(branch.rate_c);
+    if rev >= 34 {
+        line.field(branch.rates.unwrap_or_default());
+    }
+    line.field(branch.gi)
+        .field(branch.bi)
+        .field(branch.gj)
+        .field(branch.bj)
+        .field(branch.st);
+    if rev > 30 {
+        line.field(branch.met);
+    }
+    line.field(branch.len)
+        .field(branch.o1)
+        .field(branch.f1)
+        .opt(branch.o2, 0)
+        .opt(branch.f2, 1.0)
+        .opt(branch.o3, 0)
+        .opt(branch.f3, 1.0)
+        .opt(branch.o4, 0)
+        .opt(branch.f4, 1.0);
+    line.write(w)
+}
+
+fn write_raw_system_switching_device<W: Write>(
+    w: &mut W,
+    device: &SystemSwitchingDevice,
+) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(device.i)
+        .field(device.j)
+        .field(pad(&device.ckt, ID_WIDTH))
+        .field(device.x)
+        .field(device.rate1)
+        .field(device.rate2)
+        .field(device.rate3)
+        .field(device.rates)
+        .field(device.stat)
+        .field(device.nstat)
+        .field(device.metered)
+        .field(device.stype)
+        .field(device.name);
+    line.write(w)
+}
+
+// The fields of one winding, for the third, fourth or fifth line of a transformer record.
+struct Winding {
+    windv: f64,
+    nomv: f64,
+    ang: Option<f64>,
+    rata: Option<f64>,
+    ratb: Option<f64>,
+    ratc: Option<f64>,
+    rates: Option<[f64; 9]>,
+    cod: Option<i8>,
+    cont: Option<BusNum>,
+    node: Option<i32>,
+    rma: Option<f64>,
+    rmi: Option<f64>,
+    vma: Option<f64>,
+    vmi: Option<f64>,
+    ntp: Option<i16>,
+    tab: Option<isize>,
+    cr: Option<f64>,
+    cx: Option<f64>,
+    cnxa: Option<f64>,
+}
+
+fn write_raw_winding<W: Write>(w: &mut W, winding: &Winding, rev: usize) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(winding.windv)
+        .field(winding.nomv)
+        .opt(winding.ang, 0.0)
+        .opt(winding.rata, 0.0)
+        .opt(winding.ratb, 0.0)
+        .opt(winding.ratc, 0.0);
+    if rev >= 34 {
+        line.opt(winding.rates, [0.0; 9]);
+    }
+    line.opt(winding.cod, 0).opt(winding.cont, 0);
+    if rev >= 34 {
+        line.opt(winding.node, 0);
+    }
+    line.opt(winding.rma, 1.1)
+        .opt(winding.rmi, 0.9)
+        .opt(winding.vma, 1.1)
+        .opt(winding.vmi, 0.9)
+        .opt(winding.ntp, 33)
+        .opt(winding.tab, 0)
+        .opt(winding.cr, 0.0)
+        .opt(winding.cx, 0.0);
+    if rev > 30 {
+        line.opt(winding.cnxa, 0.0);
+    }
+    line.write(w)
+}
+
+// Two-winding transformers (`k` = 0) are written on four lines, and
+// three-winding transformers on five.
+fn write_raw_transformer<W: Write>(w: &mut W, t: &Transformer, rev: usize) -> io::Result<()> {
+    // first line //
+    let mut line = Line::default();
+    line.field(t.i)
+        .field(t.j)
+        .field(t.k)
+        .field(pad(&t.ckt, ID_WIDTH))
+        .field(t.cw)
+        .field(t.cz)
+        .field(t.cm)
+        .field(t.mag1)
+        .field(t.mag2)
+        .field(t.nmetr)
+        .field(pad(&t.name, NAME_WIDTH))
+        .field(t.stat)
+        .field(t.o1)
+        .field(t.f1)
+        .opt(t.o2, 0)
+        .opt(t.f2, 1.0)
+        .opt(t.o3, 0)
+        .opt(t.f3, 1.0)
+        .opt(t.o4, 0)
+        .opt(t.f4, 1.0);
+    if rev > 30 {
+        let blank = pad(&ArrayString::new(), NAME_WIDTH);
+        line.opt(t.vecgrp.map(|vecgrp| pad(&vecgrp, NAME_WIDTH)), blank);
+    }
+    if rev >= 35 {
+        line.opt(t.zcod, 0);
+    }
+    line.write(w)?;
+
+    // second line //
+    let mut line = Line::default();
+    line.field(t.r1_2)
+        .field(t.x1_2)
+        .field(t.sbase1_2)
+        .opt(t.r2_3, 0.0)
+        .opt(t.x2_3, 0.0)
+        .opt(t.sbase2_3, t.sbase1_2)
+        .opt(t.r3_1, 0.0)
+        .opt(t.x3_1, 0.0)
+        .opt(t.sbase3_1, t.sbase1_2)
+        .opt(t.vmstar, 1.0)
+        .opt(t.anstar, 0.0);
+    line.write(w)?;
+
+    // third line //
+    let w1 = Winding {
+        windv: t.windv1,
+        nomv: t.nomv1,
+        ang: Some(t.ang1),
+        rata: Some(t.rata1),
+        ratb: Some(t.ratb1),
+        ratc: Some(t.ratc1),
+        rates: t.rates1,
+        cod: Some(t.cod1),
+        cont: Some(t.cont1),
+        node: t.node1,
+        rma: Some(t.rma1),
+        rmi: Some(t.rmi1),
+        vma: Some(t.vma1),
+        vmi: Some(t.vmi1),
+        ntp: Some(t.ntp1),
+        tab: Some(t.tab1),
+        cr: Some(t.cr1),
+        cx: Some(t.cx1),
+        cnxa: t.cnxa1,
+    };
+    write_raw_winding(w, &w1, rev)?;
+
+    // fourth line //
+    let w2 = Winding {
+        windv: t.windv2,
+        nomv: t.nomv2,
+        ang: t.ang2,
+        rata: t.rata2,
+        ratb: t.ratb2,
+        ratc: t.ratc2,
+        rates: t.rates2,
+        cod: t.cod2,
+        cont: t.cont2,
+        node: t.node2,
+        rma: t.rma2,
+        rmi: t.rmi2,
+        vma: t.vma2,
+        vmi: t.vmi2,
+        ntp: t.ntp2,
+        tab: t.tab2,
+        cr: t.cr2,
+        cx: t.cx2,
+        cnxa: t.cnxa2,
+    };
+    write_raw_winding(w, &w2, rev)?;
+
+    // fifth line //
+    if t.k != 0 {
+        let w3 = Winding {
+            windv: t.windv3.unwrap_or(1.0),
+            nomv: t.nomv3.unwrap_or(0.0),
+            ang: t.ang3,
+            rata: t.rata3,
+            ratb: t.ratb3,
+            ratc: t.ratc3,
+            rates: t.rates3,
+            cod: t.cod3,
+            cont: t.cont3,
+            node: t.node3,
+            rma: t.rma3,
+            rmi: t.rmi3,
+            vma: t.vma3,
+            vmi: t.vmi3,
+            ntp: t.ntp3,
+            tab: t.tab3,
+            cr: t.cr3,
+            cx: t.cx3,
+            cnxa: t.cnxa3,
+        };
+        write_raw_winding(w, &w3, rev)?;
+    }
+
+    Ok(())
+}
+
+fn write_raw_area_interchange<W: Write>(w: &mut W, area: &AreaInterchange) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(area.i)
+        .field(area.isw)
+        .field(area.pdes)
+        .field(area.ptol)
+        .field(pad(&area.arname, NAME_WIDTH));
+    line.write(w)
+}
+
+fn write_raw_two_terminal_dc_line<W: Write>(
+    w: &mut W,
+    dc: &TwoTerminalDCLine,
+    rev: usize,
+) -> io::Result<()> {
+    // first line: control parameters //
+    let mut line = Line::default();
+    line.field(dc.name)
+        .field(dc.mdc)
+        .field(dc.rdc)
+        .field(dc.setvl)
+        .field(dc.vschd)
+        .field(dc.vcmod)
+        .field(dc.rcomp)
+        .field(dc.delti)
+        .field(dc.meter)
+        .field(dc.dcvmin)
+        .field(dc.cccitmx)
+        .field(dc.cccacc);
+    line.write(w)?;
+
+    // second line: rectifier //
+    let mut line = Line::default();
+    line.field(dc.ipr)
+        .field(dc.nbr)
+        .field(dc.alfmx)
+        .field(dc.alfmn)
+        .field(dc.rcr)
+        .field(dc.xcr)
+        .field(dc.ebasr)
+        .field(dc.trr)
+        .field(dc.tapr)
+        .field(dc.tmxr)
+        .field(dc.tmnr)
+        .field(dc.stpr)
+        .field(dc.icr);
+    if rev >= 34 {
+        line.field(dc.ndr.unwrap_or(0));
+    }
+    line.field(dc.ifr)
+        .field(dc.itr)
+        .field(pad(&dc.idr, ID_WIDTH))
+        .field(dc.xcapr);
+    line.write(w)?;
+
+    // third line: inverter //
+    let mut line = Line::default();
+    line.field(dc.ipi)
+        .field(dc.nbi)
+        .field(dc.gammx)
+        .field(dc.gammn)
+        .field(dc.rci)
+        .field(dc.xci)
+        .field(dc.ebasi)
+        .field(dc.tri)
+        .field(dc.tapi)
+        .field(dc.tmxi)
+        .field(dc.tmni)
+        .field(dc.stpi)
+        .field(dc.ici);
+    if rev >= 34 {
+        line.field(dc.ndi.unwrap_or(0));
+    }
+    line.field(dc.ifi)
+        .field(dc.iti)
+        .field(pad(&dc.idi, ID_WIDTH))
+        .field(dc.xcapi);
+    line.write(w)
+}
+
+fn write_raw_vsc_dc_line<W: Write>(w: &mut W, vsc: &VSCDCLine, rev: usize) -> io::Result<()> {
+    // first line //
+    let mut line = Line::default();
+    line.field(vsc.name)
+        .field(vsc.mdc)
+        .field(vsc.rdc)
+        .field(vsc.o1)
+        .field(vsc.f1)
+        .field(vsc.o2)
+        .field(vsc.f2)
+        .field(vsc.o3)
+        .field(vsc.f3)
+        .field(vsc.o4)
+        .field(vsc.f4);
+    line.write(w)?;
+
+    // second line: converter 1 //
+    let mut line = Line::default();
+    line.field(vsc.ibus1)
+        .field(vsc.type1)
+        .field(vsc.mode1)
+        .field(vsc.docet1)
+        .field(vsc.acset1)
+        .field(vsc.aloss1)
+        .field(vsc.bloss1)
+        .field(vsc.minloss1)
+        .field(vsc.smax1)
+        .field(vsc.imax1)
+        .field(vsc.pwf1)
+        .field(vsc.maxq1)
+        .field(vsc.minq1)
+        .field(vsc.remot1);
+    if rev >= 34 {
+        line.field(vsc.nreg1.unwrap_or(0));
+    }
+    line.field(vsc.rmpct1);
+    line.write(w)?;
+
+    // third line: converter 2 //
+    let mut line = Line::default();
+    line.field(vsc.ibus2)
+        .field(vsc.type2)
+        .field(vsc.mode2)
+        .field(vsc.docet2)
+        .field(vsc.acset2)
+        .field(vsc.aloss2)
+        .field(vsc.bloss2)
+        .field(vsc.minloss2)
+        .field(vsc.smax2)
+        .field(vsc.imax2)
+        .field(vsc.pwf2)
+        .field(vsc.maxq2)
+        .field(vsc.minq2)
+        .field(vsc.remot2);
+    if rev >= 34 {
+        line.field(vsc.nreg2.unwrap_or(0));
+    }
+    line.field(vsc.rmpct2);
+    line.write(w)
+}
+
+// Points after the last nonzero one are left out; at least two are written.
+fn write_raw_impedance_correction<W: Write>(w: &mut W, ic: &ImpedanceCorrection) -> io::Result<()> {
+    let points = impedance_correction_points(ic);
+    let n = points
+        .iter()
+        .rposition(|&(t, re, _)| t != 0.0 || re != 0.0)
+        .map_or(2, |k| (k + 1).max(2));
+
+    let mut line = Line::default();
+    line.field(ic.i);
+    for &(t, re, _) in &points[..n] {
+        line.field(t).field(re);
+    }
+    line.write(w)
+}
+
+// v34 tables have complex scaling factors, three points to a line, and end with
+// a `0.0, 0.0, 0.0` point.
+fn write_raw_impedance_correction_v34<W: Write>(
+    w: &mut W,
+    ic: &ImpedanceCorrection,
+) -> io::Result<()> {
+    let points = impedance_correction_points(ic);
+    let n = points
+        .iter()
+        .position(|&(t, re, im)| t == 0.0 && re == 0.0 && im == 0.0)
+        .unwrap_or(points.len());
+
+    let mut points = points[..n].to_vec();
+    points.push((0.0, 0.0, 0.0));
+
+    for (k, chunk) in points.chunks(3).enumerate() {
+        let mut line = Line::default();
+        if k == 0 {
+            line.field(ic.i);
+        }
+        for &(t, re, im) in chunk {
+            line.field(t).field(re).field(im);
+        }
+        line.write(w)?;
+    }
+
+    Ok(())
+}
+
+fn impedance_correction_points(ic: &ImpedanceCorrection) -> [(f64, f64, f64); 11] {
+    let t = [
+        ic.t1, ic.t2, ic.t3, ic.t4, ic.t5, ic.t6, ic.t7, ic.t8, ic.t9, ic.t10, ic.t11,
+    ];
+    let re = [
+        ic.f1, ic.f2, ic.f3, ic.f4, ic.f5, ic.f6, ic.f7, ic.f8, ic.f9, ic.f10, ic.f11,
+    ];
+    let im = ic.f_im.unwrap_or_default();
+    std::array::from_fn(|k| (t[k], re[k], im[k]))
+}
+
+// The first line gives the number of converter, DC bus and DC link lines that follow.
+fn write_raw_multi_terminal_dc_line<W: Write>(
+    w: &mut W,
+    dc: &MultiTerminalDCLine,
+    rev: usize,
+) -> io::Result<()> {
+    let id = &dc.line_id;
+    let mut line = Line::default();
+    line.field(id.name)
+        .field(dc.converters.len())
+        .field(dc.buses.len())
+        .field(dc.links.len())
+        .field(id.mdc)
+        .field(id.vconv)
+        .field(id.vcmod)
+        .field(id.vconvn);
+    line.write(w)?;
+
+    for converter in &dc.converters {
+        write_raw_ac_converter(w, converter)?;
+    }
+    for bus in &dc.buses {
+        write_raw_dc_bus(w, bus)?;
+    }
+    for link in &dc.links {
+        write_raw_dc_link(w, link, rev)?;
+    }
+
+    Ok(())
+}
+
+fn write_raw_ac_converter<W: Write>(w: &mut W, converter: &ACConverter) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(converter.ib)
+        .field(converter.n)
+        .field(converter.angmx)
+        .field(converter.angmn)
+        .field(converter.rc)
+        .field(converter.xc)
+        .field(converter.ebas)
+        .field(converter.tr)
+        .field(converter.tap)
+        .field(converter.tpmx)
+        .field(converter.tpmn)
+        .field(converter.tstp)
+        .field(converter.setvl)
+        .field(converter.dcpf)
+        .field(converter.marg)
+        .field(converter.cnvcod);
+    line.write(w)
+}
+
+fn write_raw_dc_bus<W: Write>(w: &mut W, bus: &DCBus) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(bus.idc)
+        .field(bus.ib)
+        .field(bus.ia)
+        .field(bus.zone)
+        .field(pad(&bus.name, NAME_WIDTH))
+        .field(bus.idc2)
+        .field(bus.rgrnd)
+        .field(bus.owner);
+    line.write(w)
+}
+
+fn write_raw_dc_link<W: Write>(w: &mut W, link: &DCLink, rev: usize) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(link.idc).field(link.jdc).field(link.dcckt);
+    if rev > 30 {
+        line.field(link.met);
+    }
+    line.field(link.rdc).field(link.ldc);
+    line.write(w)
+}
+
+fn write_raw_multi_section_line_group<W: Write>(
+    w: &mut W,
+    group: &MultiSectionLineGroup,
+    rev: usize,
+) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(group.i)
+        .field(group.j)
+        .field(pad(&group.id, ID_WIDTH));
+    if rev > 30 {
+        line.field(group.met);
+    }
+    line.field(group.dum1)
+        .opt(group.dum2, 0)
+        .opt(group.dum3, 0)
+        .opt(group.dum4, 0)
+        .opt(group.dum5, 0)
+        .opt(group.dum6, 0)
+        .opt(group.dum7, 0)
+        .opt(group.dum8, 0)
+        .opt(group.dum9, 0);
+    line.write(w)
+}
+
+fn write_raw_zone<W: Write>(w: &mut W, zone: &Zone) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(zone.i).field(pad(&zone.zoname, NAME_WIDTH));
+    line.write(w)
+}
+
+fn write_raw_inter_area_transfer<W: Write>(
+    w: &mut W,
+    transfer: &InterAreaTransfer,
+) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(transfer.arfrom)
+        .field(transfer.arto)
+        .field(transfer.trid)
+        .field(transfer.ptran);
+    line.write(w)
+}
+
+fn write_raw_owner<W: Write>(w: &mut W, owner: &Owner) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(owner.i).field(pad(&owner.owname, NAME_WIDTH));
+    line.write(w)
+}
+
+fn write_raw_facts_device<W: Write>(w: &mut W, facts: &FACTSDevice, rev: usize) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(facts.name)
+        .field(facts.i)
+        .field(facts.j)
+        .field(facts.mode)
+        .field(facts.pdes)
+        .field(facts.qdes)
+        .field(facts.vset)
+        .field(facts.shmx)
+        .field(facts.trmx)
+        .field(facts.vtmn)
+        .field(facts.vtmx)
+        .field(facts.vsmx)
+        .field(facts.imx)
+        .field(facts.linx)
+        .field(facts.rmpct)
+        .field(facts.owner)
+        .field(facts.set1)
+        .field(facts.set2)
+        .field(facts.vsref)
+        .field(facts.remot);
+    if rev >= 34 {
+        line.field(facts.nreg.unwrap_or(0));
+    }
+    line.field(pad(&facts.mname, NAME_WIDTH));
+    line.write(w)
+}
+
+// Blocks after the last one that is used are left out.
+fn write_raw_switched_shunt<W: Write>(
+    w: &mut W,
+    shunt: &SwitchedShunt,
+    rev: usize,
+) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(shunt.i);
+    if rev >= 35 {
+        let id = shunt.id.unwrap_or_else(|| ArrayString::from("1").unwrap());
+        line.field(pad(&id, ID_WIDTH));
+    }
+    line.field(shunt.modsw);
+    if rev > 30 {
+        line.field(shunt.adjm).field(shunt.stat);
+    }
+    line.field(shunt.vswhi)
+        .field(shunt.vswlo)
+        .field(shunt.swrem);
+    if rev >= 34 {
+        line.field(shunt.nreg.unwrap_or(0));
+    }
+    line.field(shunt.rmpct)
+        .field(pad(&shunt.rmidnt, NAME_WIDTH))
+        .field(shunt.binit);
+
+    let blocks = [
+        (shunt.s1, shunt.n1, shunt.b1),
+        (shunt.s2, shunt.n2, shunt.b2),
+        (shunt.s3, shunt.n3, shunt.b3),
+        (shunt.s4, shunt.n4, shunt.b4),
+        (shunt.s5, shunt.n5, shunt.b5),
+        (shunt.s6, shunt.n6, shunt.b6),
+        (shunt.s7, shunt.n7, shunt.b7),
+        (shunt.s8, shunt.n8, shunt.b8),
+    ];
+    let n = blocks
+        .iter()
+        .rposition(|&(s, n, b)| s.is_some() || n != 0 || b != 0.0)
+        .map_or(0, |k| k + 1);
+    for &(s, n, b) in &blocks[..n] {
+        if rev >= 35 {
+            line.field(s.unwrap_or(IN_SERVICE));
+        }
+        line.field(n).field(b);
+    }
+    line.write(w)
+}
+
+// Each substation record is followed by its node, switching device and equipment terminal data.
+fn write_raw_substation<W: Write>(w: &mut W, substation: &Substation) -> io::Result<()> {
+    let mut line = Line::default();
+    line.field(substation.is)
+        .field(substation.name)
+        .field(substation.lati)
+        .field(substation.long)
+        .field(substation.srg);
+    line.write(w)?;
+
+    for node in &substation.nodes {
+        let mut line = Line::default();
+        line.field(node.ni)
+            .field(node.name)
+            .field(node.i)
+            .field(node.status)
+            .field(node.vm)
+            .field(node.va);
+        line.write(w)?;
+    }
+    writeln!(
+        w,
+        "0 / END OF SUBSTATION NODE DATA, BEGIN SUBSTATION SWITCHING DEVICE DATA"
+    )?;
+
+    for device in &substation.switching_devices {
+        let mut line = Line::default();
+        line.field(device.ni)
+            .field(device.nj)
+            .field(pad(&device.ckt, ID_WIDTH))
+            .field(device.name)
+            .field(device.typ)
+            .field(device.status)
+            .field(device.nstat)
+            .field(device.x)
+            .field(device.rate1)
+            .field(device.rate2)
+            .field(device.rate3);
+        line.opt(device.rates, [0.0; 9]);
+        line.write(w)?;
+    }
+    writeln!(
+        w,
+        "0 / END OF SUBSTATION SWITCHING DEVICE DATA, BEGIN SUBSTATION EQUIPMENT TERMINAL DATA"
+    )?;
+
+    for terminal in &substation.terminals {
+        let mut line = Line::default();
+        line.field(terminal.i)
+            .field(terminal.ni)
+            .field(terminal.typ);
+        match terminal.typ.as_str() {
+            "B" | "2" => {
+                line.field(terminal.j);
+            }
+            "3" => {
+                line.field(terminal.j).field(terminal.k);
+            }
+            _ => {}
+        }
+        line.field(pad(&terminal.id, ID_WIDTH));
+        line.write(w)?;
+    }
+    writeln!(w, "0 / END OF SUBSTATION EQUIPMENT TERMINAL DATA")
+}
+
+/// Writes a [Network] as a PSS/E RAW case of revision `rev`.
+///
+/// Each data section is written in the record layout of `rev`, followed by its
+/// `0 / END OF ... DATA` terminator, and the case ends with the `Q` record.
+/// Fields that the layout adds to those the network was read with take their
+/// defaults. For revision 30 and earlier the fixed shunts at each bus are
+/// written as the bus shunt admittance.
+///
+/// Names and identifiers are blank-padded to the widths PSS/E writes them with.
+/// Reals are written exactly, so a case read with [crate::parsing::parse_raw_case]
+/// and written in its own revision reads back unchanged.
+pub fn write_raw(network: &Network, rev: usize, mut w: impl Write) -> io::Result<()> {
+    let w = &mut w;
+
+    write_raw_case_id(w, &network.caseid, rev)?;
+
+    if rev <= 30 {
+        write_raw_sections_v30(w, network)
+    } else {
+        write_raw_sections(w, network, rev)
+    }
+}
+
+// Cases from v31 onwards share the v33 section order. v34 cases start with system-wide
+// data, and v35 cases end with substation data.
+fn write_raw_sections<W: Write>(w: &mut W, network: &Network, rev: usize) -> io::Result<()> {
+    let mut sections = Sections::new(w);
+
+    if rev >= 34 {
+        sections.section("SYSTEM-WIDE", &network.system_wide, |w, record| {
+            write_raw_system_wide_record(w, record)
+        })?;
+    }
+    sections.section("BUS", &network.buses, |w, bus| write_raw_bus(w, bus))?;
+    sections.section("LOAD", &network.loads, |w, load| {
+        write_raw_load(w, load, rev)
+    })?;
+    sections.section("FIXED SHUNT", &network.fixed_shunts, |w, shunt| {
+        write_raw_fixed_shunt(w, shunt)
+    })?;
+    sections.section("GENERATOR", &network.generators, |w, gen| {
+        write_raw_generator(w, gen, rev)
+    })?;
+    sections.section("BRANCH", &network.branches, |w, branch| {
+        write_raw_branch(w, branch, rev)
+    })?;
+    if rev >= 34 {
+        sections.section(
+            "SYSTEM SWITCHING DEVICE",
+            &network.system_switching_devices,
+            |w, device| write_raw_system_switching_device(w, device),
+        )?;
+    }
+    sections.section("TRANSFORMER", &network.transformers, |w, t| {
+        write_raw_transformer(w, t, rev)
+    })?;
+    sections.section("AREA", &network.area_interchanges, |w, area| {
+        write_raw_area_interchange(w, area)
+    })?;
+    sections.section("TWO-TERMINAL DC", &network.two_terminal_dc, |w, dc| {
+        write_raw_two_terminal_dc_line(w, dc, rev)
+    })?;
+    sections.section("VSC DC LINE", &network.vsc_dc, |w, vsc| {
+        write_raw_vsc_dc_line(w, vsc, rev)
+    })?;
+    sections.section(
+        "IMPEDANCE CORRECTION",
+        &network.impedance_corrections,
+        |w, ic| {
+            if rev >= 34 {
+                write_raw_impedance_correction_v34(w, ic)
+            } else {
+                write_raw_impedance_correction(w, ic)
+            }
+        },
+    )?;
+    sections.section("MULTI-TERMINAL DC", &network.multi_terminal_dc, |w, dc| {
+        write_raw_multi_terminal_dc_line(w, dc, rev)
+    })?;
+    sections.section(
+        "MULTI-SECTION LINE",
+        &network.multi_section_lines,
+        |w, group| write_raw_multi_section_line_group(w, group, rev),
+    )?;
+    sections.section("ZONE", &network.zones, |w, zone| write_raw_zone(w, zone))?;
+    sections.section(
+        "INTER-AREA TRANSFER",
+        &network.area_transfers,
+        |w, transfer| write_raw_inter_area_transfer(w, transfer),
+    )?;
+    sections.section("OWNER", &network.owners, |w, owner| {
+        write_raw_owner(w, owner)
+    })?;
+    sections.section("FACTS DEVICE", &network.facts, |w, facts| {
+        write_raw_facts_device(w, facts, rev)
+    })?;
+    sections.section("SWITCHED SHUNT", &network.switched_shunts, |w, shunt| {
+        write_raw_switched_shunt(w, shunt, rev)
+    })?;
+    sections.empty("GNE")?;
+    sections.empty("INDUCTION MACHINE")?;
+    if rev >= 35 {
+        sections.section("SUBSTATION", &network.substations, |w, substation| {
+            write_raw_substation(w, substation)
+        })?;
+    }
+
+    sections.end()
+}
+
+// v30 cases have no fixed shunt, GNE device or induction machine sections, and
+// switched shunt data comes straight after the VSC DC lines.
+fn write_raw_sections_v30<W: Write>(w: &mut W, network: &Network) -> io::Result<()> {
+    let mut bus_shunts: HashMap<BusNum, (f64, f64)> = HashMap::new();
+    for shunt in &network.fixed_shunts {
+        if shunt.status == IN_SERVICE {
+            let (gl, bl) = bus_shunts.entry(shunt.i).or_default();
+            *gl += shunt.gl;
+            *bl += shunt.bl;
+        }
+    }
+
+    let mut sections = Sections::new(w);
+
+    sections.section("BUS", &network.buses, |w, bus| {
+        let shunt = bus_shunts.get(&bus.i).copied().unwrap_or_default();
+        write_raw_bus_v30(w, bus, shunt)
+    })?;
+    sections.section("LOAD", &network.loads, |w, load| {
+        write_raw_load(w, load, 30)
+    })?;
+    sections.section("GENERATOR", &network.generators, |w, gen| {
+        write_raw_generator(w, gen, 30)
+    })?;
+    sections.section("BRANCH", &network.branches, |w, branch| {
+        write_raw_branch(w, branch, 30)
+    })?;
+    sections.section("TRANSFORMER", &network.transformers, |w, t| {
+        write_raw_transformer(w, t, 30)
+    })?;
+    sections.section("AREA", &network.area_interchanges, |w, area| {
+        write_raw_area_interchange(w, area)
+    })?;
+    sections.section("TWO-TERMINAL DC", &network.two_terminal_dc, |w, dc| {
+        write_raw_two_terminal_dc_line(w, dc, 30)
+    })?;
+    sections.section("VSC DC LINE", &network.vsc_dc, |w, vsc| {
+        write_raw_vsc_dc_line(w, vsc, 30)
+    })?;
+    sections.section("SWITCHED SHUNT", &network.switched_shunts, |w, shunt| {
+        write_raw_switched_shunt(w, shunt, 30)
+    })?;
+    sections.section(
+        "IMPEDANCE CORRECTION",
+        &network.impedance_corrections,
+        |w, ic| write_raw_impedance_correction(w, ic),
+    )?;
+    sections.section("MULTI-TERMINAL DC", &network.multi_terminal_dc, |w, dc| {
+        write_raw_multi_terminal_dc_line(w, dc, 30)
+    })?;
+    sections.section(
+        "MULTI-SECTION LINE",
+        &network.multi_section_lines,
+        |w, group| write_raw_multi_section_line_group(w, group, 30),
+    )?;
+    sections.section("ZONE", &network.zones, |w, zone| write_raw_zone(w, zone))?;
+    sections.section(
+        "INTER-AREA TRANSFER",
+        &network.area_transfers,
+        |w, transfer| write_raw_inter_area_transfer(w, transfer),
+    )?;
+    sections.section("OWNER", &network.owners, |w, owner| {
+        write_raw_owner(w, owner)
+    })?;
+    sections.section("FACTS DEVICE", &network.facts, |w, facts| {
+        write_raw_facts_device(w, facts, 30)
+    })?;
+
+    sections.end()
+}
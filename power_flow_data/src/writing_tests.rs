@@ -0,0 +1,114 @@
+use crate::parsing::{parse_raw_case, ParseOptions};
+use crate::parsing_tests::{CASE_V30, CASE_V33, CASE_V35};
+use crate::writing::write_raw;
+
+fn round_trip(case: &str, rev: usize) -> String {
+    let network = parse_raw_case(case, ParseOptions::default()).unwrap().0;
+
+    let mut raw = Vec::new();
+    write_raw(&network, rev, &mut raw).unwrap();
+    let raw = String::from_utf8(raw).unwrap();
+
+    let written = parse_raw_case(&raw, ParseOptions::default()).unwrap().0;
+    assert_eq!(written, network, "{}", raw);
+    raw
+}
+
+#[test]
+fn test_write_raw_case() {
+    round_trip(CASE_V33, 33);
+}
+
+#[test]
+fn test_write_raw_case_v30() {
+    round_trip(CASE_V30, 30);
+}
+
+#[test]
+fn test_write_raw_case_v35() {
+    let raw = round_trip(CASE_V35, 35);
+    assert!(
+        raw.contains("0 / END OF BRANCH DATA, BEGIN SYSTEM SWITCHING DEVICE DATA\n151,152,'SW',")
+    );
+    assert!(raw.contains("0 / END OF SYSTEM SWITCHING DEVICE DATA, BEGIN TRANSFORMER DATA\n"));
+}
+
+#[test]
+fn test_write_raw_solved_case() {
+    let mut network = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+    network.buses[1].vm = 1.0123456789;
+    network.buses[1].va = -3.25;
+    network.generators[0].pg = 751.5;
+    network.generators[0].qg = 1e-9;
+
+    let mut raw = Vec::new();
+    write_raw(&network, 33, &mut raw).unwrap();
+    let raw = String::from_utf8(raw).unwrap();
+
+    assert!(raw.starts_with(
+        "0,100.0,33,0,1,60.0     / PSS(R)E-33.5    WED, JUN 23 2021  13:05\n TEST CASE\n TWO AREAS\n"
+    ));
+    assert!(raw.contains("\n151,'NUCPANT     ',500.0,1,1,1,1,1.0123456789,-3.25,"));
+    assert!(raw.contains("0 / END OF BUS DATA, BEGIN LOAD DATA\n"));
+    assert!(raw.ends_with("0 / END OF INDUCTION MACHINE DATA\nQ\n"));
+
+    let written = parse_raw_case(&raw, ParseOptions::default()).unwrap().0;
+    assert_eq!(written.buses[1].vm, 1.0123456789);
+    assert_eq!(written.generators[0].pg, 751.5);
+    assert_eq!(written.generators[0].qg, 1e-9);
+}
+
+#[test]
+fn test_write_raw_generator_v30() {
+    let mut network = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+    network.generators[0].wmod = Some(1);
+    network.generators[0].wpf = Some(0.9);
+
+    let mut raw = Vec::new();
+    write_raw(&network, 30, &mut raw).unwrap();
+    let raw = String::from_utf8(raw).unwrap();
+
+    let generators = raw
+        .split("BEGIN GENERATOR DATA\n")
+        .nth(1)
+        .and_then(|s| s.lines().next())
+        .unwrap();
+    assert_eq!(generators.split(',').count(), 26, "{}", generators);
+
+    let written = parse_raw_case(&raw, ParseOptions::default()).unwrap().0;
+    assert_eq!(written.generators[0].wmod, None);
+    assert_eq!(written.generators[0].wpf, None);
+}
+
+#[test]
+fn test_write_raw_transformer_v30() {
+    let mut network = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+    network.transformers[0].cnxa1 = Some(30.0);
+
+    let mut raw = Vec::new();
+    write_raw(&network, 30, &mut raw).unwrap();
+    let raw = String::from_utf8(raw).unwrap();
+
+    let winding = raw
+        .split("BEGIN TRANSFORMER DATA\n")
+        .nth(1)
+        .and_then(|s| s.lines().nth(2))
+        .unwrap();
+    assert_eq!(winding.split(',').count(), 16, "{}", winding);
+
+    let written = parse_raw_case(&raw, ParseOptions::default()).unwrap().0;
+    assert_eq!(written.transformers[0].cnxa1, None);
+}
+
+#[test]
+fn test_write_raw_non_finite() {
+    let mut network = parse_raw_case(CASE_V33, ParseOptions::default()).unwrap().0;
+    network.buses[1].vm = f64::NAN;
+
+    let err = write_raw(&network, 33, Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    network.buses[1].vm = 1.0;
+    network.generators[0].pg = f64::INFINITY;
+    assert!(write_raw(&network, 33, Vec::new()).is_err());
+}
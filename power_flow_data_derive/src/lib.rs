@@ -1,19 +1,79 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{
+    parse_macro_input, Attribute, DeriveInput, Fields, GenericArgument, PathArguments, Type,
+};
 
-#[proc_macro_derive(RawRecord, attributes(rev30, rev33, line2, line3, line4, line5))]
+/// Derives a `RawRecord` parser from the order and types of the fields of a struct.
+///
+/// Fields are separated by commas with optional whitespace, or by whitespace alone.
+/// `Option<T>` fields may be left out, along with their separator, and are `None`
+/// when they are. Fields other than the first may be left blank, e.g. `1,,3`, and are
+/// then `None` or take their value from the `Default` implementation of the struct.
+/// So do the fields left out at the end of a line, other than those that start one.
+///
+/// Field attributes:
+///  * `#[bus]` - the field refers to a bus, by number or extended bus name.
+///  * `#[line2]` to `#[line5]` - the field starts a new line of the record.
+///  * `#[rev30]` - the field is only in the v30 layout.
+///  * `#[rev33]` - the field is in the v33 layout, but not the v30 one.
+///  * `#[rev34]`, `#[rev35]` - the field was added in revision 34 or 35.
+///
+/// Fields that are not in the layout of the revision being read are `None`, or
+/// take their value from the `Default` implementation of the struct.
+#[proc_macro_derive(
+    RawRecord,
+    attributes(bus, rev30, rev33, rev34, rev35, line2, line3, line4, line5)
+)]
 pub fn derive_raw_record(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     let name = input.ident;
 
     let expanded = match input.data {
-        syn::Data::Struct(_data) => {
+        syn::Data::Struct(data) => {
+            let fields = match data.fields {
+                Fields::Named(fields) => fields.named,
+                _ => panic!("#[derive(RawRecord)] must be applied to a struct with named fields"),
+            };
+
+            let mut needs_default = false;
+            let mut steps = Vec::new();
+            let mut idents = Vec::new();
+            for (k, field) in fields.iter().enumerate() {
+                let ident = field.ident.as_ref().unwrap();
+                let rev = revision(&field.attrs);
+                let new_line = field.attrs.iter().any(|attr| {
+                    ["line2", "line3", "line4", "line5"]
+                        .iter()
+                        .any(|line| attr.path().is_ident(line))
+                });
+                needs_default |= option_type(&field.ty).is_none() && (k > 0 || rev.is_some());
+
+                let bus = field.attrs.iter().any(|attr| attr.path().is_ident("bus"));
+
+                steps.push(parse_field(ident, &field.ty, k == 0, new_line, bus, rev));
+                idents.push(ident);
+            }
+
+            let default = if needs_default {
+                quote! { let def = <Self as ::std::default::Default>::default(); }
+            } else {
+                quote! {}
+            };
+
             quote! {
-                impl RawRecord for #name {
-                    fn parse_raw(input: &str) -> nom::IResult<&str, Self> {
-                        Ok((input, Self::default()))
+                impl crate::traits::RawRecord for #name {
+                    #[allow(unused_variables)]
+                    fn parse_raw(input: &str, rev: usize) -> crate::error::IResult<&str, Self> {
+                        #default
+
+                        let (input, _) = nom::character::complete::space0(input)?;
+
+                        #(#steps)*
+
+                        Ok((input, Self { #(#idents),* }))
                     }
                 }
             }
@@ -25,3 +85,143 @@ pub fn derive_raw_record(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+// The condition on `rev` under which a field is read, from its revision attribute.
+fn revision(attrs: &[Attribute]) -> Option<TokenStream2> {
+    attrs.iter().find_map(|attr| {
+        let path = attr.path();
+        if path.is_ident("rev30") {
+            Some(quote! { rev <= 30 })
+        } else if path.is_ident("rev33") {
+            Some(quote! { rev > 30 })
+        } else if path.is_ident("rev34") {
+            Some(quote! { rev >= 34 })
+        } else if path.is_ident("rev35") {
+            Some(quote! { rev >= 35 })
+        } else {
+            None
+        }
+    })
+}
+
+// The `T` of an `Option<T>` field type.
+fn option_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+// The parser of a field value of type `ty`.
+fn field_parser(ty: &Type, bus: bool) -> TokenStream2 {
+    if bus {
+        quote! { crate::parsing::parse_bus_num }
+    } else {
+        quote! { <#ty as crate::traits::RawField>::parse_field }
+    }
+}
+
+// The statement that parses one field, with the separator or line break before it.
+fn parse_field(
+    ident: &syn::Ident,
+    ty: &Type,
+    first: bool,
+    new_line: bool,
+    bus: bool,
+    rev: Option<TokenStream2>,
+) -> TokenStream2 {
+    let sep = if new_line {
+        quote! {
+            nom::sequence::pair(
+                crate::parsing::parse_end_of_line,
+                nom::character::complete::space0,
+            )
+        }
+    } else {
+        quote! { crate::parsing::parse_sep }
+    };
+
+    let (parse, absent) = match option_type(ty) {
+        Some(inner) => {
+            let value = field_parser(inner, bus);
+            let parse = if first {
+                quote! { nom::combinator::opt(#value)(input)? }
+            } else {
+                quote! {
+                    nom::combinator::map(
+                        nom::combinator::opt(nom::sequence::preceded(
+                            #sep,
+                            nom::branch::alt((
+                                nom::combinator::map(#value, Some),
+                                nom::combinator::map(crate::parsing::parse_blank, |_| None),
+                            )),
+                        )),
+                        Option::flatten,
+                    )(input)?
+                }
+            };
+            (parse, quote! { None })
+        }
+        None => {
+            let name = ident.to_string();
+            let value = field_parser(ty, bus);
+            let default = quote! { |_| ::std::clone::Clone::clone(&def.#ident) };
+            let parse = if first {
+                quote! { nom::error::context(#name, #value)(input)? }
+            } else if new_line {
+                quote! {
+                    nom::error::context(
+                        #name,
+                        nom::sequence::preceded(
+                            #sep,
+                            nom::branch::alt((
+                                #value,
+                                nom::combinator::map(crate::parsing::parse_blank, #default),
+                            )),
+                        ),
+                    )(input)?
+                }
+            } else {
+                // Fields left out at the end of a line take their defaults too.
+                quote! {
+                    nom::error::context(
+                        #name,
+                        nom::branch::alt((
+                            nom::combinator::map(
+                                nom::combinator::peek(crate::parsing::parse_end_of_line),
+                                #default,
+                            ),
+                            nom::sequence::preceded(
+                                #sep,
+                                nom::branch::alt((
+                                    #value,
+                                    nom::combinator::map(crate::parsing::parse_blank, #default),
+                                )),
+                            ),
+                        )),
+                    )(input)?
+                }
+            };
+            (parse, quote! { def.#ident })
+        }
+    };
+
+    match rev {
+        Some(rev) => quote! {
+            let (input, #ident) = if #rev { #parse } else { (input, #absent) };
+        },
+        None => quote! {
+            let (input, #ident) = #parse;
+        },
+    }
+}